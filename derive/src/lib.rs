@@ -0,0 +1,209 @@
+//! Derive macros for [`bevy_tweening`].
+//!
+//! This crate is an implementation detail of [`bevy_tweening`], which
+//! re-exports its macros. It shouldn't be used directly.
+//!
+//! [`bevy_tweening`]: https://docs.rs/bevy_tweening
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Field, Ident, LitStr,
+    Member, Meta,
+};
+
+/// Interpolation method used by a generated lens.
+#[derive(Clone, Copy)]
+enum Interpolation {
+    /// Linear interpolation with `VectorSpace::lerp()`.
+    Lerp,
+    /// Spherical interpolation with an inherent `slerp()` method.
+    Slerp,
+    /// Color mixing with `Mix::mix()`.
+    Mix,
+    /// Jump from the start value to the end value once the animation
+    /// completes.
+    Step,
+}
+
+/// A lens to generate for a field annotated with `#[lens]`.
+struct FieldLens {
+    member: Member,
+    ty: syn::Type,
+    name: Ident,
+    interpolation: Interpolation,
+}
+
+/// Derive a lens for each field annotated with `#[lens]`.
+///
+/// See the documentation of `bevy_tweening::lens::Lens` for details.
+#[proc_macro_derive(Lens, attributes(lens))]
+pub fn derive_lens(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "#[derive(Lens)] doesn't support generic types",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "#[derive(Lens)] only supports structs",
+        ));
+    };
+
+    let target = &input.ident;
+    let mut lenses = vec![];
+    for (index, field) in data.fields.iter().enumerate() {
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("lens"))
+        {
+            lenses.push(parse_field(target, index, field, attr)?);
+        }
+    }
+    if lenses.is_empty() {
+        return Err(Error::new(
+            input.ident.span(),
+            "#[derive(Lens)] requires at least one field annotated with #[lens]",
+        ));
+    }
+
+    let vis = &input.vis;
+    let lenses = lenses.iter().map(|lens| {
+        let FieldLens {
+            member,
+            ty,
+            name,
+            interpolation,
+        } = lens;
+        let doc = match member {
+            Member::Named(ident) => {
+                format!("A lens to manipulate the `{ident}` field of a [`{target}`].")
+            }
+            Member::Unnamed(index) => format!(
+                "A lens to manipulate the field #{} of a [`{target}`].",
+                index.index
+            ),
+        };
+        let value = match interpolation {
+            Interpolation::Lerp => quote! {
+                ::bevy_tweening::__private::VectorSpace::lerp(self.start, self.end, ratio)
+            },
+            Interpolation::Slerp => quote! {
+                self.start.slerp(self.end, ratio)
+            },
+            Interpolation::Mix => quote! {
+                ::bevy_tweening::__private::Mix::mix(&self.start, &self.end, ratio)
+            },
+            Interpolation::Step => quote! {
+                if ratio < 1. {
+                    ::core::clone::Clone::clone(&self.start)
+                } else {
+                    ::core::clone::Clone::clone(&self.end)
+                }
+            },
+        };
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone)]
+            #vis struct #name {
+                /// Start value of the field.
+                pub start: #ty,
+                /// End value of the field.
+                pub end: #ty,
+            }
+
+            impl ::bevy_tweening::Lens<#target> for #name {
+                fn lerp(
+                    &mut self,
+                    mut target: ::bevy_tweening::__private::Mut<#target>,
+                    ratio: f32,
+                ) {
+                    target.#member = #value;
+                }
+            }
+
+            impl ::bevy_tweening::lens::CaptureStart<#target> for #name {
+                fn capture_start(&mut self, target: &#target) {
+                    self.start = ::core::clone::Clone::clone(&target.#member);
+                }
+            }
+        }
+    });
+    Ok(quote! { #(#lenses)* })
+}
+
+/// Parse a `#[lens]` attribute of a field.
+fn parse_field(
+    target: &Ident,
+    index: usize,
+    field: &Field,
+    attr: &Attribute,
+) -> syn::Result<FieldLens> {
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    };
+
+    let mut interpolation = Interpolation::Lerp;
+    let mut name = None;
+    if !matches!(attr.meta, Meta::Path(_)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("lerp") {
+                interpolation = Interpolation::Lerp;
+            } else if meta.path.is_ident("slerp") {
+                interpolation = Interpolation::Slerp;
+            } else if meta.path.is_ident("mix") {
+                interpolation = Interpolation::Mix;
+            } else if meta.path.is_ident("step") {
+                interpolation = Interpolation::Step;
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<LitStr>()?.parse::<Ident>()?);
+            } else {
+                return Err(meta.error(
+                    "unknown lens attribute, expected `lerp`, `slerp`, `mix`, `step`, or `name`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    let name = name.unwrap_or_else(|| {
+        let field_name = match &member {
+            Member::Named(ident) => to_pascal_case(&ident.to_string()),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        format_ident!("{}{}Lens", target, field_name)
+    });
+
+    Ok(FieldLens {
+        member,
+        ty: field.ty.clone(),
+        name,
+        interpolation,
+    })
+}
+
+/// Convert a `snake_case` field name to `PascalCase`.
+fn to_pascal_case(name: &str) -> String {
+    name.trim_start_matches("r#")
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -17,18 +17,18 @@ struct Follower;
 #[derive(Debug, Default, Clone, Copy)]
 enum AnimOption {
     OverwriteComponent,
-    #[default]
     CallSetTweenable,
+    #[default]
+    Retarget,
 }
 
-// Simple way to toggle between the two options. Don't do that in real code.
-impl std::ops::Not for AnimOption {
-    type Output = Self;
-
-    fn not(self) -> Self::Output {
+impl AnimOption {
+    // Simple way to cycle between the options. Don't do that in real code.
+    fn next(self) -> Self {
         match self {
             Self::OverwriteComponent => Self::CallSetTweenable,
-            Self::CallSetTweenable => Self::OverwriteComponent,
+            Self::CallSetTweenable => Self::Retarget,
+            Self::Retarget => Self::OverwriteComponent,
         }
     }
 }
@@ -105,7 +105,7 @@ fn setup(
 
 fn change_option(keyboard: Res<ButtonInput<KeyCode>>, mut q_anim: Single<&mut Anim>) {
     if keyboard.just_pressed(KeyCode::Space) {
-        q_anim.option = !q_anim.option;
+        q_anim.option = q_anim.option.next();
         println!("Anim option : {:?}", q_anim.option);
     }
 }
@@ -151,6 +151,15 @@ fn follow(
                         .set_tweenable(make_tween(target_transform.translation, pos.extend(0.)))
                         .unwrap();
                 }
+                // Option 3. Keep the same Tween, and use retarget() to change its end value in
+                // place. Contrary to the other options, which restart the easing each time, this
+                // preserves the current velocity of the follower, so the motion stays smooth.
+                AnimOption::Retarget => {
+                    q_anim
+                        .2
+                        .retarget::<Transform, TransformPositionLens>(pos.extend(0.))
+                        .unwrap();
+                }
             }
         }
     }
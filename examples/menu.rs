@@ -58,7 +58,8 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     let font = asset_server.load("fonts/FiraMono-Regular.ttf");
 
     // The menu "container" node, parent of all menu buttons
-    commands
+    let mut buttons = vec![];
+    let menu = commands
         .spawn((
             Name::new("menu"),
             Node {
@@ -79,24 +80,13 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         ))
         .with_children(|container| {
             // The individual menu buttons
-            let mut start_time_ms = 0;
             for (text, label) in [
                 ("Continue", ButtonLabel::Continue),
                 ("New Game", ButtonLabel::NewGame),
                 ("Settings", ButtonLabel::Settings),
                 ("Quit", ButtonLabel::Quit),
             ] {
-                let tween_scale = Tween::new(
-                    EaseFunction::BounceOut,
-                    Duration::from_secs(2),
-                    UiTransformScaleLens {
-                        start: Vec2::splat(0.01),
-                        end: Vec2::ONE,
-                    },
-                )
-                .with_cycle_completed_event(true);
-
-                let target = container
+                let button = container
                     .spawn((
                         Name::new(format!("button:{}", text)),
                         Button,
@@ -130,23 +120,33 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ))
                     .id();
 
-                let tweenable = if start_time_ms > 0 {
-                    let delay = Delay::new(Duration::from_millis(start_time_ms));
-                    delay.then(tween_scale).into_boxed()
-                } else {
-                    tween_scale.into_boxed()
-                };
-                container
-                    .spawn((
-                        InitialAnimMarker,
-                        TweenAnim::new(tweenable),
-                        AnimTarget::component::<UiTransform>(target),
-                    ))
-                    .observe(enable_interaction_after_initial_animation);
-
-                start_time_ms += 500;
+                buttons.push(button);
             }
-        });
+        })
+        .id();
+
+    // Animate the buttons appearing one after the other, each starting 500 ms after
+    // the previous one.
+    let stagger = Stagger::new(Duration::from_millis(500) * (buttons.len() as u32 - 1));
+    let anims = stagger.spawn::<UiTransform, _>(&mut commands, buttons, |_index, _button| {
+        Tween::new(
+            EaseFunction::BounceOut,
+            Duration::from_secs(2),
+            UiTransformScaleLens {
+                start: Vec2::splat(0.01),
+                end: Vec2::ONE,
+            },
+        )
+        .with_cycle_completed_event(true)
+    });
+    for &anim_entity in &anims {
+        commands
+            .entity(anim_entity)
+            .insert(InitialAnimMarker)
+            .observe(enable_interaction_after_initial_animation);
+    }
+    // Parent the animations to the menu, so they're despawned with it
+    commands.entity(menu).add_children(&anims);
 }
 
 fn enable_interaction_after_initial_animation(
@@ -0,0 +1,432 @@
+use std::time::Duration;
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+    reflect::{FromType, TypeRegistry, Typed},
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    lens::{ReflectPathLens, ReflectValue},
+    BoxedTweenable, Delay, EaseMethod, PlaybackDirection, RepeatCount, RepeatStrategy, Sequence,
+    Tween, TweenAnim, TweenState, TweeningError,
+};
+
+/// An animation described as data.
+///
+/// A tween asset describes a tree of [`Tween`], [`Sequence`], and [`Delay`]
+/// nodes, which can be instantiated into a [`TweenAnim`] with
+/// [`TweenAnim::from_asset()`]. Tweens animate a field of a reflected type,
+/// designated by a reflection path, like a [`ReflectPathLens`] does. The
+/// animated type must be registered in the type registry with the
+/// [`ReflectTweenTarget`] type data.
+///
+/// Tween assets are loaded from RON files with a `.tween.ron` extension by the
+/// [`TweenAssetLoader`], which the [`TweeningPlugin`] registers if the
+/// `AssetPlugin` is present. When a tween asset is modified, for example on
+/// hot reload, all [`TweenAnim`] instantiated from it are rebuilt, and restart
+/// from the beginning.
+///
+/// # Example
+///
+/// ```ron
+/// (
+///     root: Sequence([
+///         Tween((
+///             target: "Transform",
+///             path: "translation",
+///             start: Vec3((0., 0., 0.)),
+///             end: Vec3((100., 0., 0.)),
+///             duration: 1.5,
+///             ease_method: EaseFunction(QuadraticInOut),
+///         )),
+///         Delay(0.5),
+///         Tween((
+///             target: "Transform",
+///             path: "scale.x",
+///             start: F32(1.),
+///             end: F32(2.),
+///             duration: 1.,
+///             repeat_count: Finite(2),
+///             repeat_strategy: MirroredRepeat,
+///         )),
+///     ]),
+/// )
+/// ```
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct TweenAsset {
+    /// The root node of the animation.
+    pub root: TweenNode,
+}
+
+/// A node of a [`TweenAsset`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TweenNode {
+    /// A single [`Tween`].
+    Tween(TweenDesc),
+    /// A [`Sequence`] of nodes, played one after the other.
+    Sequence(Vec<TweenNode>),
+    /// A [`Delay`], in seconds.
+    Delay(f32),
+}
+
+/// Description of a [`Tween`] animating a reflected field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TweenDesc {
+    /// Type path of the animated component, resource, or asset.
+    ///
+    /// This can be either the short type path like `"Transform"`, or the full
+    /// type path like `"bevy_transform::components::transform::Transform"`
+    /// in case the short one is ambiguous.
+    pub target: String,
+    /// Reflection path of the animated field.
+    ///
+    /// See [`ReflectPathLens`] for details.
+    pub path: String,
+    /// Start value of the field.
+    pub start: ReflectValue,
+    /// End value of the field.
+    pub end: ReflectValue,
+    /// Duration of a single cycle, in seconds.
+    pub duration: f32,
+    /// Ease method. Defaults to a linear easing.
+    #[serde(default)]
+    pub ease_method: EaseMethod,
+    /// Repeat count. Defaults to a single cycle.
+    #[serde(default)]
+    pub repeat_count: RepeatCount,
+    /// Repeat strategy. Defaults to [`RepeatStrategy::Repeat`].
+    #[serde(default)]
+    pub repeat_strategy: RepeatStrategy,
+    /// Playback direction. Defaults to [`PlaybackDirection::Forward`].
+    #[serde(default)]
+    pub playback_direction: PlaybackDirection,
+}
+
+impl TweenAsset {
+    /// Build a tweenable from the description of the animation.
+    ///
+    /// The target type of each tween is looked up in the type registry, and
+    /// must have been registered with the [`ReflectTweenTarget`] type data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::UnregisteredTweenTarget`] if a target type is
+    /// not registered, [`TweeningError::InvalidTweenAsset`] if the asset
+    /// contains an empty sequence or a non-positive duration, or any error of
+    /// [`ReflectPathLens::new()`] if the reflection path is invalid.
+    pub fn build(&self, type_registry: &TypeRegistry) -> Result<BoxedTweenable, TweeningError> {
+        self.root.build(type_registry)
+    }
+}
+
+impl TweenNode {
+    fn build(&self, type_registry: &TypeRegistry) -> Result<BoxedTweenable, TweeningError> {
+        match self {
+            Self::Tween(desc) => {
+                let registration = type_registry
+                    .get_with_short_type_path(&desc.target)
+                    .or_else(|| type_registry.get_with_type_path(&desc.target));
+                let target = registration
+                    .and_then(|registration| registration.data::<ReflectTweenTarget>())
+                    .ok_or(TweeningError::UnregisteredTweenTarget)?;
+                Ok(Box::new((target.build)(desc)?))
+            }
+            Self::Sequence(nodes) => {
+                if nodes.is_empty() {
+                    return Err(TweeningError::InvalidTweenAsset);
+                }
+                let tweens = nodes
+                    .iter()
+                    .map(|node| node.build(type_registry))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Box::new(Sequence::new(tweens)))
+            }
+            Self::Delay(duration) => Ok(Box::new(Delay::new(duration_from_secs(*duration)?))),
+        }
+    }
+}
+
+fn duration_from_secs(secs: f32) -> Result<Duration, TweeningError> {
+    Duration::try_from_secs_f32(secs)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or(TweeningError::InvalidTweenAsset)
+}
+
+/// Type data allowing a reflected type to be animated by a [`TweenAsset`].
+///
+/// Register this type data for any component, resource, or asset type you
+/// want to animate from a [`TweenAsset`], either with
+/// `#[reflect(TweenTarget)]` or [`App::register_type_data()`]. The
+/// [`TweeningPlugin`] registers it for [`Transform`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// #[derive(Component, Reflect)]
+/// #[reflect(TweenTarget)]
+/// struct Health(f32);
+/// ```
+///
+/// [`TweeningPlugin`]: crate::TweeningPlugin
+#[derive(Clone, Copy)]
+pub struct ReflectTweenTarget {
+    build: fn(&TweenDesc) -> Result<Tween, TweeningError>,
+}
+
+impl<T: Reflect + Typed> FromType<T> for ReflectTweenTarget {
+    fn from_type() -> Self {
+        Self {
+            build: build_tween::<T>,
+        }
+    }
+}
+
+fn build_tween<T: Reflect + Typed>(desc: &TweenDesc) -> Result<Tween, TweeningError> {
+    let lens = ReflectPathLens::<T>::new(&desc.path, desc.start, desc.end)?;
+    let duration = duration_from_secs(desc.duration)?;
+    Ok(Tween::new(desc.ease_method.clone(), duration, lens)
+        .with_repeat(desc.repeat_count, desc.repeat_strategy)
+        .with_playback_direction(desc.playback_direction))
+}
+
+/// Asset loader for [`TweenAsset`] RON files.
+///
+/// The loader handles files with a `.tween.ron` extension.
+#[derive(Debug, Default, Clone, Copy, TypePath)]
+pub struct TweenAssetLoader;
+
+/// Error while loading a [`TweenAsset`].
+#[derive(Debug, Error)]
+pub enum TweenAssetLoaderError {
+    /// Failed to read the asset file.
+    #[error("Could not read tween asset: {0}")]
+    Io(#[from] std::io::Error),
+    /// Failed to parse the RON content of the asset.
+    #[error("Could not parse tween asset: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for TweenAssetLoader {
+    type Asset = TweenAsset;
+    type Settings = ();
+    type Error = TweenAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<TweenAsset, TweenAssetLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tween.ron"]
+    }
+}
+
+/// Rebuild the animations instantiated from a [`TweenAsset`] when that asset
+/// changes.
+pub(crate) fn tween_asset_reload_system(
+    mut asset_events: MessageReader<AssetEvent<TweenAsset>>,
+    assets: Res<Assets<TweenAsset>>,
+    type_registry: Res<AppTypeRegistry>,
+    mut q_anims: Query<(Entity, &mut TweenAnim)>,
+) {
+    for event in asset_events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        let Some(asset) = assets.get(*id) else {
+            continue;
+        };
+        let type_registry = type_registry.read();
+        for (entity, mut anim) in &mut q_anims {
+            if anim.source != Some(*id) {
+                continue;
+            }
+            match asset.build(&type_registry) {
+                Ok(tweenable) => {
+                    anim.tweenable = tweenable;
+                    anim.tween_state = TweenState::Active;
+                }
+                Err(err) => {
+                    bevy::log::error!(
+                        "Failed to reload animation on entity {entity:?} from tween asset {id:?}: {err:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::{test_utils::*, AnimTarget};
+
+    const ASSET: &str = r#"(
+        root: Sequence([
+            Tween((
+                target: "Transform",
+                path: "translation.x",
+                start: F32(0.),
+                end: F32(10.),
+                duration: 1.,
+                ease_method: CubicBezier((0.42, 0.), (0.58, 1.)),
+            )),
+            Delay(0.5),
+            Tween((
+                target: "bevy_transform::components::transform::Transform",
+                path: "scale",
+                start: Vec3((1., 1., 1.)),
+                end: Vec3((3., 3., 3.)),
+                duration: 1.,
+                ease_method: EaseFunction(QuadraticIn),
+                repeat_count: Finite(2),
+                repeat_strategy: MirroredRepeat,
+            )),
+        ]),
+    )"#;
+
+    fn type_registry() -> TypeRegistry {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<Transform>();
+        type_registry.register_type_data::<Transform, ReflectTweenTarget>();
+        type_registry
+    }
+
+    #[test]
+    fn parse() {
+        let asset: TweenAsset = ron::from_str(ASSET).unwrap();
+        let TweenNode::Sequence(nodes) = &asset.root else {
+            panic!("Expected a sequence.");
+        };
+        assert_eq!(nodes.len(), 3);
+        let TweenNode::Tween(desc) = &nodes[0] else {
+            panic!("Expected a tween.");
+        };
+        assert!(matches!(
+            desc.ease_method,
+            EaseMethod::CubicBezier(p1, p2) if p1 == Vec2::new(0.42, 0.) && p2 == Vec2::new(0.58, 1.)
+        ));
+        assert!(matches!(nodes[1], TweenNode::Delay(0.5)));
+        let TweenNode::Tween(desc) = &nodes[2] else {
+            panic!("Expected a tween.");
+        };
+        assert_eq!(desc.end, ReflectValue::Vec3(Vec3::splat(3.)));
+        assert_eq!(desc.repeat_count, RepeatCount::Finite(2));
+        assert_eq!(desc.repeat_strategy, RepeatStrategy::MirroredRepeat);
+        assert_eq!(desc.playback_direction, PlaybackDirection::Forward);
+
+        // Round-trip
+        let text = ron::to_string(&asset).unwrap();
+        let asset2 = ron::from_str::<TweenAsset>(&text).unwrap();
+        assert_eq!(ron::to_string(&asset2).unwrap(), text);
+    }
+
+    #[test]
+    fn build() {
+        let type_registry = type_registry();
+        let asset: TweenAsset = ron::from_str(ASSET).unwrap();
+        let tweenable = asset.build(&type_registry).unwrap();
+        assert_eq!(
+            tweenable.total_duration(),
+            crate::TotalDuration::Finite(Duration::from_millis(3500))
+        );
+
+        // Errors
+        let mut asset = asset;
+        let TweenNode::Sequence(nodes) = &mut asset.root else {
+            unreachable!();
+        };
+        let TweenNode::Tween(desc) = &mut nodes[0] else {
+            unreachable!();
+        };
+        desc.target = "Sprite".to_string();
+        assert!(matches!(
+            asset.build(&type_registry),
+            Err(TweeningError::UnregisteredTweenTarget)
+        ));
+        let empty = TweenAsset {
+            root: TweenNode::Sequence(vec![]),
+        };
+        assert!(matches!(
+            empty.build(&type_registry),
+            Err(TweeningError::InvalidTweenAsset)
+        ));
+        let zero_delay = TweenAsset {
+            root: TweenNode::Delay(0.),
+        };
+        assert!(matches!(
+            zero_delay.build(&type_registry),
+            Err(TweeningError::InvalidTweenAsset)
+        ));
+    }
+
+    #[test]
+    fn anim_from_asset() {
+        let mut env = TestEnv::<Transform>::empty();
+        let mut assets = Assets::<TweenAsset>::default();
+        let handle = assets.add(ron::from_str::<TweenAsset>(ASSET).unwrap());
+        let type_registry = type_registry();
+
+        // Not loaded
+        assert!(matches!(
+            TweenAnim::from_asset(&Handle::default(), &assets, &type_registry),
+            Err(TweeningError::InvalidAssetId(_))
+        ));
+
+        let anim = TweenAnim::from_asset(&handle, &assets, &type_registry).unwrap();
+        let target = env.world.spawn(Transform::default()).id();
+        let anim_entity = env
+            .world
+            .spawn((anim, AnimTarget::component::<Transform>(target)))
+            .id();
+        env.step_all(Duration::from_millis(500));
+        let tr = env.world.entity(target).get::<Transform>().unwrap();
+        assert_approx_eq!(tr.translation.x, 5., 1e-5);
+
+        // Hot reload restarts the animation with the new description
+        {
+            let mut asset = assets.get_mut(&handle).unwrap();
+            let TweenNode::Sequence(nodes) = &mut asset.root else {
+                unreachable!();
+            };
+            let TweenNode::Tween(desc) = &mut nodes[0] else {
+                unreachable!();
+            };
+            desc.end = ReflectValue::F32(-10.);
+        }
+        env.world.insert_resource(assets);
+        env.world.init_resource::<AppTypeRegistry>();
+        {
+            let app_type_registry = env.world.resource::<AppTypeRegistry>();
+            let mut app_type_registry = app_type_registry.write();
+            app_type_registry.register::<Transform>();
+            app_type_registry.register_type_data::<Transform, ReflectTweenTarget>();
+        }
+        env.world
+            .init_resource::<Messages<AssetEvent<TweenAsset>>>();
+        env.world
+            .write_message(AssetEvent::Modified { id: handle.id() });
+        env.world
+            .run_system_once(tween_asset_reload_system)
+            .unwrap();
+        let anim = env.world.entity(anim_entity).get::<TweenAnim>().unwrap();
+        assert_eq!(anim.tweenable().elapsed(), Duration::ZERO);
+        env.step_all(Duration::from_millis(500));
+        let tr = env.world.entity(target).get::<Transform>().unwrap();
+        assert_approx_eq!(tr.translation.x, -5., 1e-5);
+    }
+}
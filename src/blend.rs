@@ -0,0 +1,512 @@
+use std::any::{Any, TypeId};
+
+use bevy::{
+    ecs::change_detection::{MaybeLocation, MutUntyped, Tick},
+    platform::collections::HashMap,
+    prelude::*,
+};
+
+use crate::AnimTargetKind;
+
+/// Blend mode of an animation, when several animations target the same
+/// component, resource, or asset.
+///
+/// See [`TweenAnim::blend_mode`] for details.
+///
+/// [`TweenAnim::blend_mode`]: crate::TweenAnim::blend_mode
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Debug, Default, Clone, PartialEq, Hash)]
+pub enum BlendMode {
+    /// The animation replaces the value of the target, blending with the
+    /// underlying value by its weight.
+    #[default]
+    Override,
+    /// The animation is added on top of the value of the target, scaled by its
+    /// weight. The lens of an additive animation is applied to the
+    /// [`Blendable::additive_identity()`] value, and the result is added to the
+    /// target with [`Blendable::add_weighted()`].
+    Additive,
+}
+
+/// A target type whose animated values can be blended together.
+///
+/// Animations with a weight less than `1.0`, or with a [`BlendMode::Additive`]
+/// blend mode, are blended into their target instead of overwriting it. This
+/// requires the target type to implement this trait, and to be registered with
+/// [`TweenBlender::register_blendable()`]. This is implemented for
+/// [`Transform`], which is registered by default.
+///
+/// The methods of this trait operate on whole values of the target type. Since
+/// a lens usually only animates a subset of the fields of its target, each
+/// method should operate field by field, so that the fields not animated by a
+/// lens are left unchanged.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// #[derive(Clone, Component)]
+/// struct Wobble {
+///     angle: f32,
+/// }
+///
+/// impl Blendable for Wobble {
+///     fn additive_identity() -> Self {
+///         Self { angle: 0. }
+///     }
+///
+///     fn interpolate(&mut self, other: &Self, weight: f32) {
+///         self.angle = self.angle.lerp(other.angle, weight);
+///     }
+///
+///     fn add_weighted(&mut self, delta: &Self, weight: f32) {
+///         self.angle += delta.angle * weight;
+///     }
+///
+///     fn restore(&mut self, output: &Self, base: &Self) {
+///         if self.angle == output.angle {
+///             self.angle = base.angle;
+///         }
+///     }
+/// }
+///
+/// fn setup(mut blender: ResMut<TweenBlender>) {
+///     blender.register_blendable::<Wobble>();
+/// }
+/// ```
+pub trait Blendable: Clone + Send + Sync + 'static {
+    /// The neutral value of additive animations.
+    ///
+    /// The lens of an additive animation is applied to this value, and the
+    /// result is the delta added to the target. For example, for a
+    /// [`Transform`] this is the [`Transform::IDENTITY`].
+    fn additive_identity() -> Self;
+
+    /// Interpolate from the current value toward another one, by a weight in
+    /// `[0:1]`.
+    fn interpolate(&mut self, other: &Self, weight: f32);
+
+    /// Add a delta produced by an additive animation, scaled by a weight.
+    fn add_weighted(&mut self, delta: &Self, weight: f32);
+
+    /// Undo the blending of the previous frame.
+    ///
+    /// Blended values are computed each frame from the value the target had
+    /// before any blending, called the base value. Before blending again, the
+    /// fields which still hold the `output` of the previous frame's blending
+    /// are reset to their `base` value, while any field modified since, for
+    /// example by a non-blended animation, keeps its current value.
+    fn restore(&mut self, output: &Self, base: &Self);
+}
+
+impl Blendable for Transform {
+    fn additive_identity() -> Self {
+        Transform::IDENTITY
+    }
+
+    fn interpolate(&mut self, other: &Self, weight: f32) {
+        self.translation = self.translation.lerp(other.translation, weight);
+        self.rotation = self.rotation.slerp(other.rotation, weight);
+        self.scale = self.scale.lerp(other.scale, weight);
+    }
+
+    fn add_weighted(&mut self, delta: &Self, weight: f32) {
+        self.translation += delta.translation * weight;
+        self.rotation *= Quat::IDENTITY.slerp(delta.rotation, weight);
+        self.scale *= Vec3::ONE.lerp(delta.scale, weight);
+    }
+
+    fn restore(&mut self, output: &Self, base: &Self) {
+        if self.translation == output.translation {
+            self.translation = base.translation;
+        }
+        if self.rotation == output.rotation {
+            self.rotation = base.rotation;
+        }
+        if self.scale == output.scale {
+            self.scale = base.scale;
+        }
+    }
+}
+
+/// Type-erased value of a [`Blendable`] type.
+pub(crate) type BlendValue = dyn Any + Send + Sync + 'static;
+
+/// Function stepping an animation, applying it to an untyped target.
+type StepFn<'a> = dyn FnMut(MutUntyped) + 'a;
+
+/// Type-erased [`Blendable`] operations for a given target type.
+struct BlendFns {
+    read: fn(&MutUntyped) -> Box<BlendValue>,
+    identity: fn() -> Box<BlendValue>,
+    interpolate: fn(MutUntyped, &BlendValue, f32),
+    add_weighted: fn(MutUntyped, &BlendValue, f32),
+    restore: fn(MutUntyped, &BlendValue, &BlendValue),
+    as_mut_untyped: fn(&mut BlendValue, &mut StepFn<'_>),
+}
+
+impl BlendFns {
+    fn new<T: Blendable>() -> Self {
+        // SAFETY (for all functions below): the untyped pointers are always obtained
+        // from a target of the type T the functions were registered for, keyed by its
+        // TypeId.
+        #[allow(unsafe_code)]
+        Self {
+            read: |ptr: &MutUntyped| {
+                let value = unsafe { ptr.as_ref().deref::<T>() };
+                Box::new(value.clone())
+            },
+            identity: || Box::new(T::additive_identity()),
+            interpolate: |ptr: MutUntyped, other: &BlendValue, weight: f32| {
+                let mut target = unsafe { ptr.with_type::<T>() };
+                target.interpolate(other.downcast_ref::<T>().unwrap(), weight);
+            },
+            add_weighted: |ptr: MutUntyped, delta: &BlendValue, weight: f32| {
+                let mut target = unsafe { ptr.with_type::<T>() };
+                target.add_weighted(delta.downcast_ref::<T>().unwrap(), weight);
+            },
+            restore: |ptr: MutUntyped, output: &BlendValue, base: &BlendValue| {
+                let mut target = unsafe { ptr.with_type::<T>() };
+                target.restore(
+                    output.downcast_ref::<T>().unwrap(),
+                    base.downcast_ref::<T>().unwrap(),
+                );
+            },
+            as_mut_untyped: |value: &mut BlendValue, f: &mut StepFn<'_>| {
+                let value = value.downcast_mut::<T>().unwrap();
+                let mut added = Tick::new(0);
+                let mut last_changed = Tick::new(0);
+                let mut caller = MaybeLocation::caller();
+                let target = Mut::new(
+                    value,
+                    &mut added,
+                    &mut last_changed,
+                    Tick::new(0),
+                    Tick::new(0),
+                    caller.as_mut(),
+                );
+                f(target.into());
+            },
+        }
+    }
+}
+
+/// Blending state of a single target, kept from one step to the next.
+struct BlendRecord {
+    /// Generation of the last step which blended into this target.
+    generation: u64,
+    /// Value of the target before the blending of the last step.
+    base: Box<BlendValue>,
+    /// Value of the target after the blending of the last step.
+    output: Box<BlendValue>,
+}
+
+/// Blending weights of the override animations of a target on a layer.
+#[derive(Default, Clone, Copy)]
+struct LayerWeights {
+    /// Sum of the weights of all blended override animations.
+    total: f32,
+    /// Sum of the weights of the animations already blended this step.
+    accumulated: f32,
+}
+
+/// Resource blending together the animations targeting a same component,
+/// resource, or asset.
+///
+/// This resource contains the registered [`Blendable`] types, as well as the
+/// blending state of each animation target. Only the animations whose target
+/// type is registered can be blended. Other animations always overwrite their
+/// target, as if their weight was `1.0` and their blend mode
+/// [`BlendMode::Override`].
+///
+/// [`Transform`] is registered by default. To blend animations of other types,
+/// implement the [`Blendable`] trait, and register them with
+/// [`register_blendable()`].
+///
+/// [`register_blendable()`]: Self::register_blendable
+#[derive(Resource)]
+pub struct TweenBlender {
+    fns: HashMap<TypeId, BlendFns>,
+    records: HashMap<(TypeId, AnimTargetKind), BlendRecord>,
+    weights: HashMap<(TypeId, AnimTargetKind, i32), LayerWeights>,
+    generation: u64,
+}
+
+impl Default for TweenBlender {
+    fn default() -> Self {
+        let mut blender = Self {
+            fns: default(),
+            records: default(),
+            weights: default(),
+            generation: 0,
+        };
+        blender.register_blendable::<Transform>();
+        blender
+    }
+}
+
+impl TweenBlender {
+    /// Register a [`Blendable`] type, allowing to blend the animations
+    /// targeting it.
+    pub fn register_blendable<T: Blendable>(&mut self) {
+        self.fns.insert(TypeId::of::<T>(), BlendFns::new::<T>());
+    }
+
+    /// Check if a type was registered with [`register_blendable()`].
+    ///
+    /// [`register_blendable()`]: Self::register_blendable
+    #[must_use]
+    pub fn is_blendable(&self, type_id: TypeId) -> bool {
+        self.fns.contains_key(&type_id)
+    }
+
+    /// Start a new step, given the weights of the blended override animations
+    /// about to be stepped.
+    pub(crate) fn begin_step(
+        &mut self,
+        weights: impl Iterator<Item = (TypeId, AnimTargetKind, i32, f32)>,
+    ) {
+        self.generation += 1;
+        let generation = self.generation;
+        // Forget about targets not blended anymore
+        self.records
+            .retain(|_, record| record.generation + 1 >= generation);
+        self.weights.clear();
+        for (type_id, kind, layer, weight) in weights {
+            self.weights
+                .entry((type_id, kind, layer))
+                .or_default()
+                .total += weight;
+        }
+    }
+
+    /// Blend an animation into its target.
+    ///
+    /// The `step` closure steps the animation, applying its lens to a
+    /// temporary value which is then blended into the target, and saved into
+    /// `cache`. If the animation is not stepped, for example because it's
+    /// paused, the `cache` value of a previous step is blended again, if any,
+    /// so that the animation keeps contributing to the target.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn blend(
+        &mut self,
+        type_id: TypeId,
+        kind: AnimTargetKind,
+        layer: i32,
+        mode: BlendMode,
+        weight: f32,
+        mut target: MutUntyped,
+        cache: &mut Option<Box<BlendValue>>,
+        step: Option<&mut StepFn<'_>>,
+    ) {
+        let fns = &self.fns[&type_id];
+
+        // On the first blended animation of this step, restore the base value
+        let generation = self.generation;
+        if let Some(record) = self.records.get_mut(&(type_id, kind)) {
+            if record.generation + 1 == generation {
+                (fns.restore)(
+                    target.reborrow(),
+                    record.output.as_ref(),
+                    record.base.as_ref(),
+                );
+            }
+            if record.generation != generation {
+                record.generation = generation;
+                record.base = (fns.read)(&target);
+            }
+        } else {
+            let base = (fns.read)(&target);
+            let output = (fns.read)(&target);
+            self.records.insert(
+                (type_id, kind),
+                BlendRecord {
+                    generation,
+                    base,
+                    output,
+                },
+            );
+        }
+
+        // Apply the lens to a temporary value
+        if let Some(step) = step {
+            let mut value = match mode {
+                BlendMode::Override => (fns.read)(&target),
+                BlendMode::Additive => (fns.identity)(),
+            };
+            (fns.as_mut_untyped)(value.as_mut(), step);
+            *cache = Some(value);
+        }
+        let Some(value) = cache.as_deref() else {
+            return;
+        };
+
+        // Blend the value into the target
+        match mode {
+            BlendMode::Override => {
+                // Blended override animations form a weighted average. If their total
+                // weight is less than 1, the base value takes the remaining weight.
+                let weights = self.weights.entry((type_id, kind, layer)).or_default();
+                weights.accumulated += weight;
+                let sum = (1. - weights.total).max(0.) + weights.accumulated;
+                let factor = if sum > 0. { weight / sum } else { 0. };
+                (fns.interpolate)(target.reborrow(), value, factor);
+            }
+            BlendMode::Additive => {
+                (fns.add_weighted)(target.reborrow(), value, weight);
+            }
+        }
+
+        let record = self.records.get_mut(&(type_id, kind)).unwrap();
+        record.output = (fns.read)(&target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        lens::{TransformPositionLens, TransformRotateZLens},
+        test_utils::*,
+        AnimTarget, EaseFunction, Lens, Tween, TweenAnim,
+    };
+
+    fn make_tween<L: Lens<Transform> + Send + Sync + 'static>(lens: L) -> Tween {
+        Tween::new(EaseFunction::Linear, Duration::from_secs(1), lens)
+    }
+
+    fn constant(value: Vec3) -> Tween {
+        make_tween(TransformPositionLens {
+            start: value,
+            end: value,
+        })
+    }
+
+    fn make_env(anims: Vec<TweenAnim>) -> (TestEnv<Transform>, Vec<Entity>) {
+        let mut env = TestEnv::<Transform>::empty();
+        env.entity = env.world.spawn(Transform::default()).id();
+        let anims = anims
+            .into_iter()
+            .map(|anim| {
+                env.world
+                    .spawn((anim, AnimTarget::component::<Transform>(env.entity)))
+                    .id()
+            })
+            .collect();
+        (env, anims)
+    }
+
+    #[test]
+    fn transform_blendable() {
+        let mut transform = Transform::from_xyz(1., 2., 3.);
+        transform.interpolate(&Transform::from_xyz(3., 2., 1.), 0.5);
+        assert_approx_eq!(transform.translation, Vec3::splat(2.), 1e-5);
+
+        let delta = Transform::from_xyz(0., 2., 0.).with_rotation(Quat::from_rotation_z(1.));
+        transform.add_weighted(&delta, 0.5);
+        assert_approx_eq!(transform.translation, Vec3::new(2., 3., 2.), 1e-5);
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(0.5), 1e-5));
+        assert_eq!(transform.scale, Vec3::ONE);
+
+        // Only the fields still holding the output are restored
+        let output = transform;
+        transform.translation = Vec3::X;
+        transform.restore(&output, &Transform::IDENTITY);
+        assert_eq!(transform.translation, Vec3::X);
+        assert_eq!(transform.rotation, Quat::IDENTITY);
+    }
+
+    #[test]
+    fn additive() {
+        let bob = TweenAnim::new(make_tween(TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::ONE,
+        }));
+        let offset = TweenAnim::new(make_tween(TransformPositionLens {
+            start: Vec3::ZERO,
+            end: Vec3::Y,
+        }))
+        .with_blend_mode(BlendMode::Additive)
+        .with_weight(0.5);
+        let wobble = TweenAnim::new(make_tween(TransformRotateZLens { start: 0., end: 1. }))
+            .with_blend_mode(BlendMode::Additive);
+        let (mut env, _) = make_env(vec![offset, wobble, bob]);
+
+        // The additive animations are applied on top, even if spawned first, and don't
+        // accumulate over frames, even on fields no other animation overwrites.
+        env.step_all(Duration::from_millis(500));
+        let transform = *env.component();
+        assert_approx_eq!(transform.translation, Vec3::new(0.5, 0.75, 0.5), 1e-5);
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(0.5), 1e-5));
+        env.step_all(Duration::from_millis(250));
+        let transform = *env.component();
+        assert_approx_eq!(transform.translation, Vec3::new(0.75, 1.125, 0.75), 1e-5);
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(0.75), 1e-5));
+
+        // Completed additive animations keep contributing
+        env.step_all(Duration::from_millis(500));
+        env.step_all(Duration::from_millis(100));
+        let transform = *env.component();
+        assert_approx_eq!(transform.translation, Vec3::new(1., 1.5, 1.), 1e-5);
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(1.), 1e-5));
+    }
+
+    #[test]
+    fn override_weights() {
+        // A single partial animation blends with the value before blending
+        let (mut env, anims) = make_env(vec![TweenAnim::new(constant(Vec3::ONE)).with_weight(0.5)]);
+        env.step_all(Duration::from_millis(100));
+        assert_approx_eq!(env.component().translation, Vec3::splat(0.5), 1e-5);
+        env.step_all(Duration::from_millis(100));
+        assert_approx_eq!(env.component().translation, Vec3::splat(0.5), 1e-5);
+
+        // Partial animations are averaged by weight
+        env.world.spawn((
+            TweenAnim::new(constant(Vec3::splat(4.))).with_weight(0.25),
+            AnimTarget::component::<Transform>(env.entity),
+        ));
+        env.step_all(Duration::from_millis(100));
+        assert_approx_eq!(env.component().translation, Vec3::splat(1.5), 1e-5);
+
+        // A full weight animation overwrites the target, before partial ones blend
+        env.world.get_mut::<TweenAnim>(anims[0]).unwrap().weight = 1.;
+        env.step_all(Duration::from_millis(100));
+        assert_approx_eq!(env.component().translation, Vec3::splat(1.75), 1e-5);
+    }
+
+    #[test]
+    fn crossfade() {
+        let from = TweenAnim::new(constant(Vec3::ZERO));
+        let to = TweenAnim::new(constant(Vec3::ONE));
+        let (mut env, anims) = make_env(vec![from, to]);
+        env.step_all(Duration::ZERO);
+        assert_eq!(env.component().translation, Vec3::ONE);
+
+        let [mut from, mut to] = env.world.get_entity_mut([anims[0], anims[1]]).unwrap();
+        TweenAnim::crossfade(
+            &mut from.get_mut::<TweenAnim>().unwrap(),
+            &mut to.get_mut::<TweenAnim>().unwrap(),
+            Duration::from_millis(400),
+        );
+        env.step_all(Duration::from_millis(100));
+        assert_approx_eq!(env.component().translation, Vec3::splat(0.25), 1e-5);
+        env.step_all(Duration::from_millis(200));
+        assert_approx_eq!(env.component().translation, Vec3::splat(0.75), 1e-5);
+
+        // The faded out animation is destroyed
+        env.step_all(Duration::from_millis(100));
+        assert_approx_eq!(env.component().translation, Vec3::ONE, 1e-5);
+        assert!(env.world.get::<TweenAnim>(anims[0]).is_none());
+        let to = env.world.get::<TweenAnim>(anims[1]).unwrap();
+        assert_eq!(to.weight, 1.);
+    }
+}
@@ -31,11 +31,52 @@
 //! - [`TransformRotateZLens`]
 //! - [`TransformRotateAxisLens`]
 //!
+//! # Paths
+//!
+//! The [`TransformPathLens`] moves a [`Transform`] along a curve, like a
+//! Bezier or Catmull-Rom spline, or a polyline. It can optionally move at a
+//! constant speed, and rotate the entity to face the direction of motion.
+//!
+//! # Color gradients
+//!
+//! The [`ColorGradient`] describes a gradient with multiple color stops, like a
+//! green-yellow-red health bar. It's animated by the gradient lenses, like
+//! [`SpriteColorGradientLens`], which sample it instead of mixing between two
+//! colors. The same gradient can be reused across lens targets.
+//!
+//! # Generic lenses
+//!
+//! The [`FieldLens`] animates any field of a type, given an accessor function
+//! to that field, as long as the field type implements [`StableInterpolate`].
+//! The [`KeyframeLens`] does the same through a list of keyframes, each with
+//! its own ease method, to animate a multi-stop motion with a single tween.
+//!
+//! # Reflected fields
+//!
+//! The [`ReflectPathLens`] animates any field of a type implementing
+//! [`Reflect`], given a reflection path to that field like `"translation.x"`.
+//! This allows describing animations from data, without writing a new lens
+//! type. It supports fields of type [`f32`], [`Vec2`], [`Vec3`], [`Vec4`],
+//! [`Quat`], and [`Color`].
+//!
 //! [`rotation`]: https://docs.rs/bevy/0.19/bevy/transform/components/struct.Transform.html#structfield.rotation
 //! [`Transform`]: https://docs.rs/bevy/0.19/bevy/transform/components/struct.Transform.html
 //! [`Quat::slerp()`]: https://docs.rs/bevy/0.19/bevy/math/struct.Quat.html#method.slerp
 
-use bevy::prelude::*;
+use std::{
+    any::{Any, TypeId},
+    marker::PhantomData,
+};
+
+use bevy::{
+    math::{StableInterpolate, VectorSpace},
+    prelude::*,
+    reflect::{access::Access, ParsedPath, ReflectPath, TypeInfo, Typed},
+};
+
+use crate::{EaseMethod, TweeningError};
+
+pub use bevy_tweening_derive::Lens;
 
 /// A lens over a subset of a component.
 ///
@@ -65,6 +106,78 @@ use bevy::prelude::*;
 ///     }
 /// }
 /// ```
+///
+/// # Deriving
+///
+/// Lenses can be derived with `#[derive(Lens)]` for the fields of a struct
+/// annotated with `#[lens]`. For each of those fields, this generates a lens
+/// type with public `start` and `end` fields, named after the struct and the
+/// field, like `MyStructFieldNameLens`. The generated lens also implements
+/// [`CaptureStart`].
+///
+/// The `#[lens]` attribute accepts the interpolation method of the field:
+/// - `lerp` (default): linear interpolation, for types implementing
+///   [`VectorSpace`], like `f32` or [`Vec3`].
+/// - `slerp`: spherical interpolation, for types with a `slerp()` method, like
+///   [`Quat`] or [`Rot2`].
+/// - `mix`: color mixing, for types implementing [`Mix`], like [`Color`].
+/// - `step`: the field keeps its start value until the animation completes,
+///   then jumps to its end value. This works for any type implementing
+///   [`Clone`].
+///
+/// The name of the lens type can be overridden with `name = "..."`.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # use std::time::Duration;
+/// #[derive(Component, Lens)]
+/// struct Ship {
+///     #[lens]
+///     speed: f32,
+///     #[lens(slerp, name = "ShipHeadingLens")]
+///     heading: Quat,
+///     #[lens(mix)]
+///     hull_color: Color,
+///     #[lens(step)]
+///     boosting: bool,
+/// }
+///
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     Duration::from_secs(1),
+///     ShipSpeedLens {
+///         start: 0.,
+///         end: 10.,
+///     },
+/// );
+/// # let _ = ShipHeadingLens { start: Quat::IDENTITY, end: Quat::IDENTITY };
+/// # let _ = ShipHullColorLens { start: Color::WHITE, end: Color::BLACK };
+/// # let _ = ShipBoostingLens { start: false, end: true };
+/// ```
+///
+/// # Closures
+///
+/// Any closure taking the target and the ratio is also a lens, which allows
+/// writing one-off animations inline without declaring a lens type. See also
+/// [`Tween::from_fn()`], which doesn't require annotating the closure
+/// arguments.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # use std::time::Duration;
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     Duration::from_secs(1),
+///     |mut transform: Mut<Transform>, ratio: f32| {
+///         transform.translation.y = (ratio * std::f32::consts::PI).sin();
+///     },
+/// );
+/// ```
+///
+/// [`Mix`]: bevy::color::Mix
+/// [`Tween::from_fn()`]: crate::Tween::from_fn
 pub trait Lens<T> {
     /// Perform a linear interpolation (lerp) over the subset of fields of a
     /// component or asset the lens focuses on, based on the linear ratio
@@ -74,6 +187,131 @@ pub trait Lens<T> {
     fn lerp(&mut self, target: Mut<'_, T>, ratio: f32);
 }
 
+impl<T, F> Lens<T> for F
+where
+    F: FnMut(Mut<'_, T>, f32),
+{
+    fn lerp(&mut self, target: Mut<'_, T>, ratio: f32) {
+        self(target, ratio);
+    }
+}
+
+/// A lens linearly interpolating a value between a start and an end value.
+///
+/// This exposes the start and end values of the lens, which allows a [`Tween`]
+/// to change them while running, with [`Tween::retarget()`]. The lens must
+/// produce the value `start + (end - start) * ratio` for any `ratio`, including
+/// outside of `[0:1]`.
+///
+/// [`Tween`]: crate::Tween
+/// [`Tween::retarget()`]: crate::Tween::retarget
+pub trait LinearLens<T>: Lens<T> {
+    /// Type of the interpolated value.
+    type Value: VectorSpace<Scalar = f32> + Send + Sync;
+
+    /// Get the start and end values of the lens.
+    fn endpoints(&self) -> (Self::Value, Self::Value);
+
+    /// Set the start and end values of the lens.
+    fn set_endpoints(&mut self, start: Self::Value, end: Self::Value);
+}
+
+/// A lens able to capture its start value from the target it animates.
+///
+/// This allows animating a target from its current value, whatever it is, to
+/// a given end value, without knowing that start value when creating the
+/// animation. The start value is captured from the live target when the
+/// animation is first applied. See [`Tween::from_current()`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::lens::*;
+/// #[derive(Resource)]
+/// struct Volume(f32);
+///
+/// struct VolumeLens {
+///     start: f32,
+///     end: f32,
+/// }
+///
+/// impl Lens<Volume> for VolumeLens {
+///     fn lerp(&mut self, mut target: Mut<Volume>, ratio: f32) {
+///         target.0 = self.start.lerp(self.end, ratio);
+///     }
+/// }
+///
+/// impl CaptureStart<Volume> for VolumeLens {
+///     fn capture_start(&mut self, target: &Volume) {
+///         self.start = target.0;
+///     }
+/// }
+/// ```
+///
+/// [`Tween::from_current()`]: crate::Tween::from_current
+pub trait CaptureStart<T>: Lens<T> {
+    /// Set the start value of the lens from the current value of the target.
+    fn capture_start(&mut self, target: &T);
+
+    /// Get the captured start value, to save it in a snapshot.
+    ///
+    /// The default implementation returns `None`, in which case a snapshot of
+    /// a [`Tween`] which already captured its start value can't be restored.
+    ///
+    /// [`Tween`]: crate::Tween
+    fn saved_start(&self) -> Option<ReflectValue> {
+        None
+    }
+
+    /// Restore the start value saved by [`saved_start()`].
+    ///
+    /// Returns `false` if the value has the wrong type. The default
+    /// implementation always returns `false`.
+    ///
+    /// [`saved_start()`]: Self::saved_start
+    fn restore_start(&mut self, _start: &ReflectValue) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_capture_start {
+    ($lens:ty, $target:ty, |$t:ident| $start:expr) => {
+        impl CaptureStart<$target> for $lens {
+            fn capture_start(&mut self, $t: &$target) {
+                self.start = $start;
+            }
+
+            fn saved_start(&self) -> Option<ReflectValue> {
+                ReflectValue::from_any(&self.start)
+            }
+
+            fn restore_start(&mut self, start: &ReflectValue) -> bool {
+                let Some(start) = start.downcast() else {
+                    return false;
+                };
+                self.start = start;
+                true
+            }
+        }
+    };
+}
+
+macro_rules! impl_linear_lens {
+    ($lens:ty, $target:ty, $value:ty) => {
+        impl LinearLens<$target> for $lens {
+            type Value = $value;
+
+            fn endpoints(&self) -> ($value, $value) {
+                (self.start, self.end)
+            }
+
+            fn set_endpoints(&mut self, start: $value, end: $value) {
+                self.start = start;
+                self.end = end;
+            }
+        }
+    };
+}
+
 /// A lens to manipulate the [`color`] field of a section of a [`Text`]
 /// component.
 ///
@@ -94,6 +332,9 @@ impl Lens<TextColor> for TextColorLens {
     }
 }
 
+#[cfg(feature = "bevy_text")]
+impl_capture_start!(TextColorLens, TextColor, |target| target.0);
+
 /// A lens to manipulate the [`translation`] field of a [`Transform`] component.
 ///
 /// [`translation`]: https://docs.rs/bevy/0.19/bevy/transform/components/struct.Transform.html#structfield.translation
@@ -112,6 +353,10 @@ impl Lens<Transform> for TransformPositionLens {
     }
 }
 
+impl_linear_lens!(TransformPositionLens, Transform, Vec3);
+impl_capture_start!(TransformPositionLens, Transform, |target| target
+    .translation);
+
 /// A lens to manipulate the [`rotation`] field of a [`Transform`] component.
 ///
 /// This lens interpolates the [`rotation`] field of a [`Transform`] component
@@ -143,6 +388,8 @@ impl Lens<Transform> for TransformRotationLens {
     }
 }
 
+impl_capture_start!(TransformRotationLens, Transform, |target| target.rotation);
+
 /// A lens to rotate a [`Transform`] component around its local X axis.
 ///
 /// This lens interpolates the rotation angle of a [`Transform`] component from
@@ -170,6 +417,8 @@ impl Lens<Transform> for TransformRotateXLens {
     }
 }
 
+impl_linear_lens!(TransformRotateXLens, Transform, f32);
+
 /// A lens to rotate a [`Transform`] component around its local Y axis.
 ///
 /// This lens interpolates the rotation angle of a [`Transform`] component from
@@ -197,6 +446,8 @@ impl Lens<Transform> for TransformRotateYLens {
     }
 }
 
+impl_linear_lens!(TransformRotateYLens, Transform, f32);
+
 /// A lens to rotate a [`Transform`] component around its local Z axis.
 ///
 /// This lens interpolates the rotation angle of a [`Transform`] component from
@@ -224,6 +475,8 @@ impl Lens<Transform> for TransformRotateZLens {
     }
 }
 
+impl_linear_lens!(TransformRotateZLens, Transform, f32);
+
 /// A lens to rotate a [`Transform`] component around its local X axis
 /// additively.
 ///
@@ -356,6 +609,8 @@ impl Lens<Transform> for TransformRotateAxisLens {
     }
 }
 
+impl_linear_lens!(TransformRotateAxisLens, Transform, f32);
+
 /// A lens to manipulate the [`scale`] field of a [`Transform`] component.
 ///
 /// [`scale`]: https://docs.rs/bevy/0.19/bevy/transform/components/struct.Transform.html#structfield.scale
@@ -374,6 +629,192 @@ impl Lens<Transform> for TransformScaleLens {
     }
 }
 
+impl_linear_lens!(TransformScaleLens, Transform, Vec3);
+impl_capture_start!(TransformScaleLens, Transform, |target| target.scale);
+
+/// A lens to move a [`Transform`] component along a path.
+///
+/// The path is any [`Curve`] of [`Vec3`] with a bounded domain, like a
+/// [`CubicCurve`] built from a Bezier, Catmull-Rom, or B-spline generator, or a
+/// polyline through a list of points with [`from_polyline()`]. The lens maps
+/// the animation ratio to the domain of the curve, and sets the [`translation`]
+/// of the target to the point of the curve at that parameter.
+///
+/// By default the parameter of the curve is interpolated linearly, so the speed
+/// along the path varies with the spacing of its control points. Use
+/// [`with_constant_speed()`] to reparameterize the curve by arc length instead,
+/// and move at a constant speed. Use [`with_orientation()`] to also rotate the
+/// target to face the tangent of the path.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// # fn main() -> Result<(), TweeningError> {
+/// let curve = CubicCardinalSpline::new_catmull_rom([
+///     vec3(0., 0., 0.),
+///     vec3(5., 2., 0.),
+///     vec3(10., 0., 0.),
+///     vec3(15., 3., 0.),
+/// ])
+/// .to_curve()
+/// .unwrap();
+/// let lens = TransformPathLens::new(curve)?
+///     .with_constant_speed()
+///     .with_orientation(Dir3::NEG_Z, Dir3::Y);
+/// let tween = Tween::new(EaseFunction::Linear, Duration::from_secs(3), lens);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Transform`]: https://docs.rs/bevy/0.19/bevy/transform/components/struct.Transform.html
+/// [`translation`]: https://docs.rs/bevy/0.19/bevy/transform/components/struct.Transform.html#structfield.translation
+/// [`CubicCurve`]: bevy::math::cubic_splines::CubicCurve
+/// [`from_polyline()`]: Self::from_polyline
+/// [`with_constant_speed()`]: Self::with_constant_speed
+/// [`with_orientation()`]: Self::with_orientation
+pub struct TransformPathLens {
+    curve: Box<dyn Curve<Vec3> + Send + Sync>,
+    /// Cumulative arc length at evenly spaced parameters of the curve, if the
+    /// lens moves at constant speed.
+    arc_lengths: Vec<f32>,
+    /// Local forward axis and up axis, if the lens orients the target.
+    orientation: Option<(Dir3, Dir3)>,
+}
+
+impl TransformPathLens {
+    /// Create a new lens moving a transform along the given curve.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidPath`] if the domain of the curve is
+    /// unbounded.
+    pub fn new(curve: impl Curve<Vec3> + Send + Sync + 'static) -> Result<Self, TweeningError> {
+        if !curve.domain().is_bounded() {
+            return Err(TweeningError::InvalidPath);
+        }
+        Ok(Self {
+            curve: Box::new(curve),
+            arc_lengths: vec![],
+            orientation: None,
+        })
+    }
+
+    /// Create a new lens moving a transform along a polyline through the given
+    /// points.
+    ///
+    /// Without [`with_constant_speed()`], all segments of the polyline are
+    /// traversed in the same time, whatever their length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidPath`] if there are less than 2 points.
+    ///
+    /// [`with_constant_speed()`]: Self::with_constant_speed
+    pub fn from_polyline(points: impl IntoIterator<Item = Vec3>) -> Result<Self, TweeningError> {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let domain = Interval::new(0., points.len().saturating_sub(1) as f32)
+            .map_err(|_| TweeningError::InvalidPath)?;
+        let curve = SampleAutoCurve::new(domain, points).map_err(|_| TweeningError::InvalidPath)?;
+        Self::new(curve)
+    }
+
+    /// Move along the path at a constant speed.
+    ///
+    /// This reparameterizes the curve by arc length, so that the distance
+    /// traveled is proportional to the animation ratio. The arc length is
+    /// approximated once here, by sampling the curve.
+    #[must_use]
+    pub fn with_constant_speed(mut self) -> Self {
+        let domain = self.curve.domain();
+        let count = ((domain.length() * 32.).ceil() as usize).max(256);
+        let mut length = 0.;
+        let mut prev = self.curve.sample_clamped(domain.start());
+        self.arc_lengths = (0..=count)
+            .map(|i| {
+                let t = domain.start() + domain.length() * i as f32 / count as f32;
+                let point = self.curve.sample_clamped(t);
+                length += point.distance(prev);
+                prev = point;
+                length
+            })
+            .collect();
+        self
+    }
+
+    /// Rotate the target to face the tangent of the path.
+    ///
+    /// The local `forward` axis of the target is aligned with the tangent of
+    /// the path, in the direction of motion, while its local `up` axis is kept
+    /// as close as possible to the world `up` direction. For a 3D object
+    /// following Bevy's conventions, use [`Dir3::NEG_Z`] and [`Dir3::Y`]. For a
+    /// 2D sprite facing the X axis and moving in the XY plane, use [`Dir3::X`]
+    /// and [`Dir3::Z`].
+    ///
+    /// Where the tangent is undefined, like on a zero-length section of the
+    /// path, the rotation of the target is left unchanged.
+    #[must_use]
+    pub fn with_orientation(mut self, forward: Dir3, up: Dir3) -> Self {
+        self.orientation = Some((forward, up));
+        self
+    }
+
+    /// Get the total length of the path, if the lens moves at constant speed.
+    pub fn length(&self) -> Option<f32> {
+        self.arc_lengths.last().copied()
+    }
+
+    /// Map an animation ratio to a parameter of the curve.
+    fn param(&self, ratio: f32) -> f32 {
+        let domain = self.curve.domain();
+        let ratio = ratio.clamp(0., 1.);
+        let Some(&total) = self.arc_lengths.last() else {
+            return domain.start() + domain.length() * ratio;
+        };
+        if total <= 0. {
+            return domain.start();
+        }
+
+        // Find the samples surrounding the target length, and interpolate the
+        // parameter between them
+        let length = total * ratio;
+        let next = self
+            .arc_lengths
+            .partition_point(|&l| l < length)
+            .clamp(1, self.arc_lengths.len() - 1);
+        let (l0, l1) = (self.arc_lengths[next - 1], self.arc_lengths[next]);
+        let t = if l1 > l0 {
+            (length - l0) / (l1 - l0)
+        } else {
+            0.
+        };
+        let count = (self.arc_lengths.len() - 1) as f32;
+        domain.start() + domain.length() * ((next - 1) as f32 + t) / count
+    }
+}
+
+impl Lens<Transform> for TransformPathLens {
+    fn lerp(&mut self, mut target: Mut<Transform>, ratio: f32) {
+        let param = self.param(ratio);
+        let position = self.curve.sample_clamped(param);
+        target.translation = position;
+
+        if let Some((forward, up)) = self.orientation {
+            // Estimate the tangent with a finite difference, backward at the end
+            let domain = self.curve.domain();
+            let eps = domain.length() * 1e-3;
+            let tangent = if param + eps <= domain.end() {
+                self.curve.sample_clamped(param + eps) - position
+            } else {
+                position - self.curve.sample_clamped(param - eps)
+            };
+            if let Ok(tangent) = Dir3::new(tangent) {
+                target.align(forward, tangent, up, up);
+            }
+        }
+    }
+}
+
 /// A lens to manipulate the [`position`] field of a UI [`Node`] component.
 ///
 /// [`position`]: https://docs.rs/bevy/0.19/bevy/ui/struct.Node.html
@@ -412,6 +853,14 @@ impl Lens<Node> for UiPositionLens {
     }
 }
 
+#[cfg(feature = "bevy_ui")]
+impl_capture_start!(UiPositionLens, Node, |target| UiRect {
+    left: target.left,
+    right: target.right,
+    top: target.top,
+    bottom: target.bottom,
+});
+
 /// A lens to manipulate the [`scale`] field of a [`UiTransform`] component.
 ///
 /// [`scale`]: https://docs.rs/bevy/0.19/bevy/ui/ui_transform/struct.UiTransform.html#structfield.scale
@@ -432,6 +881,12 @@ impl Lens<UiTransform> for UiTransformScaleLens {
     }
 }
 
+#[cfg(feature = "bevy_ui")]
+impl_linear_lens!(UiTransformScaleLens, UiTransform, Vec2);
+
+#[cfg(feature = "bevy_ui")]
+impl_capture_start!(UiTransformScaleLens, UiTransform, |target| target.scale);
+
 /// A lens to manipulate the [`rotation`] field of a [`UiTransform`] component.
 ///
 /// [`rotation`]: https://docs.rs/bevy/0.19/bevy/ui/ui_transform/struct.UiTransform.html#structfield.rotation
@@ -452,6 +907,10 @@ impl Lens<UiTransform> for UiTransformRotationLens {
     }
 }
 
+#[cfg(feature = "bevy_ui")]
+impl_capture_start!(UiTransformRotationLens, UiTransform, |target| target
+    .rotation);
+
 /// A lens to manipulate the [`translation`] field of a [`UiTransform`]
 /// component.
 ///
@@ -474,6 +933,9 @@ impl Lens<UiTransform> for UiTransformTranslationPxLens {
     }
 }
 
+#[cfg(feature = "bevy_ui")]
+impl_linear_lens!(UiTransformTranslationPxLens, UiTransform, Vec2);
+
 /// Gamer
 #[cfg(feature = "bevy_ui")]
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -491,6 +953,9 @@ impl Lens<BackgroundColor> for UiBackgroundColorLens {
     }
 }
 
+#[cfg(feature = "bevy_ui")]
+impl_capture_start!(UiBackgroundColorLens, BackgroundColor, |target| target.0);
+
 /// A lens to manipulate the [`color`] field of a [`ColorMaterial`] asset.
 ///
 /// [`color`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.ColorMaterial.html#structfield.color
@@ -514,6 +979,9 @@ impl Lens<ColorMaterial> for ColorMaterialColorLens {
     }
 }
 
+#[cfg(feature = "bevy_sprite")]
+impl_capture_start!(ColorMaterialColorLens, ColorMaterial, |target| target.color);
+
 /// A lens to manipulate the [`color`] field of a [`Sprite`] asset.
 ///
 /// [`color`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.Sprite.html#structfield.color
@@ -535,6 +1003,569 @@ impl Lens<Sprite> for SpriteColorLens {
     }
 }
 
+#[cfg(feature = "bevy_sprite")]
+impl_capture_start!(SpriteColorLens, Sprite, |target| target.color);
+
+/// A color gradient with multiple stops.
+///
+/// Each stop is a color placed at a position in `[0:1]`. Sampling the gradient
+/// mixes the colors of the two stops surrounding the sampled position, and
+/// returns the color of the first or last stop before or after them. A
+/// gradient is used by the gradient lenses, like [`SpriteColorGradientLens`],
+/// and can be cloned to reuse it across lens targets.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # fn main() -> Result<(), TweeningError> {
+/// let health = ColorGradient::new([
+///     (0., Color::srgb(1., 0., 0.)),
+///     (0.5, Color::srgb(1., 1., 0.)),
+///     (1., Color::srgb(0., 1., 0.)),
+/// ])?;
+/// assert_eq!(health.sample(0.5), Color::srgb(1., 1., 0.));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorGradient {
+    stops: Vec<(f32, Color)>,
+}
+
+impl ColorGradient {
+    /// Create a new gradient from a list of `(position, color)` stops.
+    ///
+    /// Two consecutive stops can have the same position, to make the gradient
+    /// jump from one color to another.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidGradient`] if there's no stop, if a
+    /// position is outside `[0:1]`, or if the stops are not sorted by position.
+    pub fn new(stops: impl IntoIterator<Item = (f32, Color)>) -> Result<Self, TweeningError> {
+        let stops = stops.into_iter().collect::<Vec<_>>();
+        let in_range = stops.iter().all(|(pos, _)| (0. ..=1.).contains(pos));
+        let sorted = stops.windows(2).all(|w| w[0].0 <= w[1].0);
+        if stops.is_empty() || !in_range || !sorted {
+            return Err(TweeningError::InvalidGradient);
+        }
+        Ok(Self { stops })
+    }
+
+    /// Get the `(position, color)` stops of the gradient, sorted by position.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Sample the color of the gradient at the given position.
+    pub fn sample(&self, position: f32) -> Color {
+        // Index of the first stop strictly after the position
+        let next = self.stops.partition_point(|(pos, _)| *pos <= position);
+        if next == 0 {
+            return self.stops[0].1;
+        }
+        let Some(&(to_pos, to_color)) = self.stops.get(next) else {
+            return self.stops[next - 1].1;
+        };
+        let (from_pos, from_color) = self.stops[next - 1];
+        from_color.mix(&to_color, (position - from_pos) / (to_pos - from_pos))
+    }
+}
+
+/// A lens to manipulate the [`color`] field of a [`Sprite`] asset through a
+/// [`ColorGradient`].
+///
+/// [`color`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.Sprite.html#structfield.color
+/// [`Sprite`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.Sprite.html
+#[cfg(feature = "bevy_sprite")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpriteColorGradientLens {
+    /// Color gradient sampled by the animation ratio.
+    pub gradient: ColorGradient,
+}
+
+#[cfg(feature = "bevy_sprite")]
+impl Lens<Sprite> for SpriteColorGradientLens {
+    fn lerp(&mut self, mut target: Mut<Sprite>, ratio: f32) {
+        target.color = self.gradient.sample(ratio);
+    }
+}
+
+/// A lens to manipulate the [`color`] field of a [`ColorMaterial`] asset
+/// through a [`ColorGradient`].
+///
+/// [`color`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.ColorMaterial.html#structfield.color
+/// [`ColorMaterial`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.ColorMaterial.html
+#[cfg(feature = "bevy_sprite")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorMaterialColorGradientLens {
+    /// Color gradient sampled by the animation ratio.
+    pub gradient: ColorGradient,
+}
+
+#[cfg(feature = "bevy_sprite")]
+impl Lens<ColorMaterial> for ColorMaterialColorGradientLens {
+    fn lerp(&mut self, mut target: Mut<ColorMaterial>, ratio: f32) {
+        let color = self.gradient.sample(ratio);
+        if target.color != color {
+            target.color = color;
+        }
+    }
+}
+
+/// A lens to manipulate the color of a [`TextColor`] component through a
+/// [`ColorGradient`].
+///
+/// [`TextColor`]: https://docs.rs/bevy/0.19/bevy/text/struct.TextColor.html
+#[cfg(feature = "bevy_text")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextColorGradientLens {
+    /// Color gradient sampled by the animation ratio.
+    pub gradient: ColorGradient,
+}
+
+#[cfg(feature = "bevy_text")]
+impl Lens<TextColor> for TextColorGradientLens {
+    fn lerp(&mut self, mut target: Mut<TextColor>, ratio: f32) {
+        target.0 = self.gradient.sample(ratio);
+    }
+}
+
+/// A lens to manipulate the color of a UI [`BackgroundColor`] component
+/// through a [`ColorGradient`].
+///
+/// [`BackgroundColor`]: https://docs.rs/bevy/0.19/bevy/ui/struct.BackgroundColor.html
+#[cfg(feature = "bevy_ui")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UiBackgroundColorGradientLens {
+    /// Color gradient sampled by the animation ratio.
+    pub gradient: ColorGradient,
+}
+
+#[cfg(feature = "bevy_ui")]
+impl Lens<BackgroundColor> for UiBackgroundColorGradientLens {
+    fn lerp(&mut self, mut target: Mut<BackgroundColor>, ratio: f32) {
+        target.0 = self.gradient.sample(ratio);
+    }
+}
+
+/// A lens to manipulate any field of a type, given an accessor function.
+///
+/// This lens interpolates a field of type `V` of a component, resource, or
+/// asset of type `T`, from a `start` value to an `end` value, using
+/// [`StableInterpolate`]. This makes any interpolable Bevy math type
+/// animatable without writing a dedicated lens, like [`Vec3`], [`Quat`],
+/// [`Rot2`], [`Dir3`], or color types like [`Srgba`].
+///
+/// The field is designated by an accessor function returning a mutable
+/// reference to it. Non-capturing closures can be used as accessors.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let tween = Tween::new(
+///     EaseFunction::QuadraticInOut,
+///     Duration::from_secs(1),
+///     FieldLens::new(|t: &mut Transform| &mut t.scale, Vec3::ONE, Vec3::splat(2.)),
+/// );
+/// ```
+pub struct FieldLens<T, V> {
+    accessor: fn(&mut T) -> &mut V,
+    /// Start value of the field.
+    pub start: V,
+    /// End value of the field.
+    pub end: V,
+}
+
+impl<T, V: StableInterpolate> FieldLens<T, V> {
+    /// Create a new lens animating the field returned by `accessor`.
+    pub fn new(accessor: fn(&mut T) -> &mut V, start: V, end: V) -> Self {
+        Self {
+            accessor,
+            start,
+            end,
+        }
+    }
+}
+
+impl<T, V: StableInterpolate> Lens<T> for FieldLens<T, V> {
+    fn lerp(&mut self, mut target: Mut<T>, ratio: f32) {
+        *(self.accessor)(&mut target) = self.start.interpolate_stable(&self.end, ratio);
+    }
+}
+
+/// A single stop of a [`KeyframeLens`].
+#[derive(Debug, Clone)]
+pub struct Keyframe<V> {
+    /// Time of the keyframe, as a fraction in `[0:1]` of the animation cycle.
+    pub time: f32,
+    /// Value of the field at the keyframe.
+    pub value: V,
+    /// Ease method applied to the segment from this keyframe to the next one.
+    pub ease_method: EaseMethod,
+}
+
+/// A lens to manipulate any field of a type through a list of keyframes.
+///
+/// Like the [`FieldLens`], this lens animates a field of type `V` implementing
+/// [`StableInterpolate`], designated by an accessor function. But instead of
+/// interpolating between a start and an end value, it interpolates between
+/// several [`Keyframe`]s. Each keyframe is placed at a fraction of the cycle,
+/// and applies its own ease method to the segment leading to the next
+/// keyframe. The field keeps the value of the first keyframe before it, and
+/// the value of the last keyframe after it.
+///
+/// The ratio passed to the lens is already eased by the [`Tween`] itself, so
+/// the ease method, repeat, and playback direction of the tween apply to the
+/// entire keyframed motion. Use [`EaseFunction::Linear`] on the tween to only
+/// apply the per-segment easing.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// # fn main() -> Result<(), TweeningError> {
+/// // Jump up quickly, then fall back slowly with a bounce
+/// let lens = KeyframeLens::new(
+///     |t: &mut Transform| &mut t.translation,
+///     [
+///         (0., Vec3::ZERO, EaseFunction::QuadraticOut),
+///         (0.3, Vec3::Y * 2., EaseFunction::BounceOut),
+///         (1., Vec3::ZERO, EaseFunction::Linear),
+///     ],
+/// )?;
+/// let tween = Tween::new(EaseFunction::Linear, Duration::from_secs(1), lens);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Tween`]: crate::Tween
+pub struct KeyframeLens<T, V> {
+    accessor: fn(&mut T) -> &mut V,
+    keyframes: Vec<Keyframe<V>>,
+}
+
+impl<T, V: StableInterpolate> KeyframeLens<T, V> {
+    /// Create a new lens animating the field returned by `accessor` through the
+    /// given keyframes.
+    ///
+    /// Each keyframe is a tuple `(time, value, ease_method)`, with `time` the
+    /// fraction in `[0:1]` of the cycle at which the field reaches `value`, and
+    /// `ease_method` the ease method of the segment to the next keyframe. The
+    /// ease method of the last keyframe is unused. Two consecutive keyframes
+    /// can have the same time, to make the field jump from one value to
+    /// another.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidKeyframes`] if there's no keyframe, if a
+    /// time is outside `[0:1]`, or if the keyframes are not sorted by time.
+    pub fn new<E: Into<EaseMethod>>(
+        accessor: fn(&mut T) -> &mut V,
+        keyframes: impl IntoIterator<Item = (f32, V, E)>,
+    ) -> Result<Self, TweeningError> {
+        let keyframes = keyframes
+            .into_iter()
+            .map(|(time, value, ease_method)| Keyframe {
+                time,
+                value,
+                ease_method: ease_method.into(),
+            })
+            .collect::<Vec<_>>();
+        let in_range = keyframes
+            .iter()
+            .all(|keyframe| (0. ..=1.).contains(&keyframe.time));
+        let sorted = keyframes.windows(2).all(|w| w[0].time <= w[1].time);
+        if keyframes.is_empty() || !in_range || !sorted {
+            return Err(TweeningError::InvalidKeyframes);
+        }
+        Ok(Self {
+            accessor,
+            keyframes,
+        })
+    }
+
+    /// Get the keyframes of the lens, sorted by time.
+    pub fn keyframes(&self) -> &[Keyframe<V>] {
+        &self.keyframes
+    }
+
+    /// Sample the keyframes at the given cycle fraction.
+    fn sample(&self, ratio: f32) -> V {
+        // Index of the first keyframe strictly after the ratio
+        let next = self.keyframes.partition_point(|k| k.time <= ratio);
+        if next == 0 {
+            return self.keyframes[0].value.clone();
+        }
+        let Some(to) = self.keyframes.get(next) else {
+            return self.keyframes[next - 1].value.clone();
+        };
+        let from = &self.keyframes[next - 1];
+        let t = (ratio - from.time) / (to.time - from.time);
+        let t = from.ease_method.sample(t);
+        from.value.interpolate_stable(&to.value, t)
+    }
+}
+
+impl<T, V: StableInterpolate> Lens<T> for KeyframeLens<T, V> {
+    fn lerp(&mut self, mut target: Mut<T>, ratio: f32) {
+        *(self.accessor)(&mut target) = self.sample(ratio);
+    }
+}
+
+/// A value of a reflected field animated by a [`ReflectPathLens`].
+///
+/// This enumerates the field types a [`ReflectPathLens`] knows how to
+/// interpolate. It's also used to save the values of the lens of a [`Tween`]
+/// in a snapshot.
+///
+/// [`Tween`]: crate::Tween
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReflectValue {
+    /// A scalar, interpolated linearly.
+    F32(f32),
+    /// A 2D vector, interpolated linearly.
+    Vec2(Vec2),
+    /// A 3D vector, interpolated linearly.
+    Vec3(Vec3),
+    /// A 4D vector, interpolated linearly.
+    Vec4(Vec4),
+    /// A rotation, interpolated with [`Quat::slerp()`].
+    Quat(Quat),
+    /// A color, interpolated with [`Mix::mix()`].
+    Color(Color),
+}
+
+macro_rules! impl_reflect_value {
+    ($($variant:ident($ty:ty)),*) => {
+        $(
+            impl From<$ty> for ReflectValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+
+        impl ReflectValue {
+            /// Convert a value of any type into a [`ReflectValue`], if that type
+            /// is supported.
+            pub(crate) fn from_any(value: &dyn Any) -> Option<Self> {
+                $(if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(Self::$variant(*value));
+                })*
+                None
+            }
+
+            /// Convert the value back into a value of type `V`, if it's of that
+            /// type.
+            pub(crate) fn downcast<V: Clone + 'static>(&self) -> Option<V> {
+                let value: &dyn Any = match self {
+                    $(Self::$variant(value) => value,)*
+                };
+                value.downcast_ref::<V>().cloned()
+            }
+
+            /// Type ID of the field type this value applies to.
+            fn field_type_id(&self) -> TypeId {
+                match self {
+                    $(Self::$variant(_) => TypeId::of::<$ty>(),)*
+                }
+            }
+
+            /// Read a field into a value of the same variant as `self`.
+            fn read(&self, field: &dyn PartialReflect) -> Option<Self> {
+                match self {
+                    $(Self::$variant(_) => field
+                        .try_downcast_ref::<$ty>()
+                        .map(|value| Self::$variant(*value)),)*
+                }
+            }
+
+            /// Write the value into a field of the same type.
+            fn write(&self, field: &mut dyn PartialReflect) {
+                match self {
+                    $(Self::$variant(value) => {
+                        if let Some(field) = field.try_downcast_mut::<$ty>() {
+                            *field = *value;
+                        }
+                    })*
+                }
+            }
+        }
+    };
+}
+
+impl_reflect_value!(
+    F32(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Vec4(Vec4),
+    Quat(Quat),
+    Color(Color)
+);
+
+impl ReflectValue {
+    /// Interpolate between two values of the same variant.
+    ///
+    /// If the variants differ, `self` is returned unchanged.
+    fn interpolate(&self, end: &Self, ratio: f32) -> Self {
+        match (self, end) {
+            (Self::F32(start), Self::F32(end)) => Self::F32(FloatExt::lerp(*start, *end, ratio)),
+            (Self::Vec2(start), Self::Vec2(end)) => Self::Vec2(start.lerp(*end, ratio)),
+            (Self::Vec3(start), Self::Vec3(end)) => Self::Vec3(start.lerp(*end, ratio)),
+            (Self::Vec4(start), Self::Vec4(end)) => Self::Vec4(start.lerp(*end, ratio)),
+            (Self::Quat(start), Self::Quat(end)) => Self::Quat(start.slerp(*end, ratio)),
+            (Self::Color(start), Self::Color(end)) => Self::Color(start.mix(end, ratio)),
+            _ => *self,
+        }
+    }
+}
+
+/// A lens to manipulate any field of a reflected type, by reflection path.
+///
+/// The lens animates a single field of a component, resource, or asset of type
+/// `T`, designated by a reflection path like `"translation.x"` or `"color"`.
+/// See [`GetPath`] for the syntax of reflection paths. The field type must be
+/// one of the types enumerated by [`ReflectValue`].
+///
+/// Because the path is validated against the type information of `T` when the
+/// lens is created, the lens can be built from data, for example a path read
+/// from a configuration file, and still report invalid paths early.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// let lens =
+///     ReflectPathLens::<Transform>::new("translation.x", 0., 10.).expect("Invalid path.");
+/// let tween = Tween::new(EaseFunction::Linear, Duration::from_secs(1), lens);
+/// ```
+///
+/// [`GetPath`]: bevy::reflect::GetPath
+pub struct ReflectPathLens<T> {
+    path: ParsedPath,
+    start: ReflectValue,
+    end: ReflectValue,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T: Typed> ReflectPathLens<T> {
+    /// Create a new lens animating the field at the given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidReflectPath`] if the path can't be
+    /// parsed, or doesn't designate a field of `T`. Returns
+    /// [`TweeningError::InvalidReflectType`] if the type of the field doesn't
+    /// match the type of the `start` and `end` values.
+    pub fn new(
+        path: &str,
+        start: impl Into<ReflectValue>,
+        end: impl Into<ReflectValue>,
+    ) -> Result<Self, TweeningError> {
+        let path = ParsedPath::parse(path).map_err(|_| TweeningError::InvalidReflectPath)?;
+        let mut info = T::type_info();
+        for access in &path.0 {
+            info =
+                field_type_info(info, &access.access).ok_or(TweeningError::InvalidReflectPath)?;
+        }
+        let start = start.into();
+        let end = end.into();
+        let type_id = start.field_type_id();
+        if end.field_type_id() != type_id || info.type_id() != type_id {
+            return Err(TweeningError::InvalidReflectType);
+        }
+        Ok(Self {
+            path,
+            start,
+            end,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Start value of the field.
+    pub fn start(&self) -> ReflectValue {
+        self.start
+    }
+
+    /// End value of the field.
+    pub fn end(&self) -> ReflectValue {
+        self.end
+    }
+}
+
+/// Get the type information of the field of a type designated by a single
+/// path access.
+///
+/// For enums, the field is looked up in all variants, and the first variant
+/// with a matching field is used.
+fn field_type_info(info: &TypeInfo, access: &Access) -> Option<&'static TypeInfo> {
+    use bevy::reflect::enums::VariantInfo;
+    match (info, access) {
+        (TypeInfo::Struct(info), Access::Field(name)) => info.field(name)?.type_info(),
+        (TypeInfo::Struct(info), Access::FieldIndex(index)) => info.field_at(*index)?.type_info(),
+        (TypeInfo::TupleStruct(info), Access::TupleIndex(index)) => {
+            info.field_at(*index)?.type_info()
+        }
+        (TypeInfo::Tuple(info), Access::TupleIndex(index)) => info.field_at(*index)?.type_info(),
+        (TypeInfo::List(info), Access::ListIndex(_)) => info.item_info(),
+        (TypeInfo::Array(info), Access::ListIndex(_)) => info.item_info(),
+        (TypeInfo::Enum(info), access) => info.iter().find_map(|variant| match (variant, access) {
+            (VariantInfo::Struct(variant), Access::Field(name)) => variant.field(name)?.type_info(),
+            (VariantInfo::Struct(variant), Access::FieldIndex(index)) => {
+                variant.field_at(*index)?.type_info()
+            }
+            (VariantInfo::Tuple(variant), Access::TupleIndex(index)) => {
+                variant.field_at(*index)?.type_info()
+            }
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+impl<T: Reflect> Lens<T> for ReflectPathLens<T> {
+    fn lerp(&mut self, mut target: Mut<T>, ratio: f32) {
+        let value = self.start.interpolate(&self.end, ratio);
+        if let Ok(field) = self
+            .path
+            .reflect_element_mut(target.as_partial_reflect_mut())
+        {
+            value.write(field);
+        }
+    }
+}
+
+impl<T: Reflect> CaptureStart<T> for ReflectPathLens<T> {
+    fn capture_start(&mut self, target: &T) {
+        if let Some(start) = self
+            .path
+            .reflect_element(target.as_partial_reflect())
+            .ok()
+            .and_then(|field| self.start.read(field))
+        {
+            self.start = start;
+        }
+    }
+
+    fn saved_start(&self) -> Option<ReflectValue> {
+        Some(self.start)
+    }
+
+    fn restore_start(&mut self, start: &ReflectValue) -> bool {
+        if start.field_type_id() != self.start.field_type_id() {
+            return false;
+        }
+        self.start = *start;
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::f32::consts::TAU;
@@ -1267,4 +2298,324 @@ mod tests {
         }
         assert_eq!(sprite.color, Color::srgba(0.7, 0., 0.3, 1.0));
     }
+
+    #[test]
+    fn color_gradient() {
+        let red = Color::srgb(1., 0., 0.);
+        let yellow = Color::srgb(1., 1., 0.);
+        let green = Color::srgb(0., 1., 0.);
+        let blue = Color::srgb(0., 0., 1.);
+        let gradient = ColorGradient::new([(0.2, red), (0.6, yellow), (1., green)]).unwrap();
+        assert_eq!(gradient.stops().len(), 3);
+        assert_eq!(gradient.sample(0.), red);
+        assert_eq!(gradient.sample(0.2), red);
+        assert!(gradient
+            .sample(0.4)
+            .to_srgba()
+            .to_vec4()
+            .abs_diff_eq(vec4(1., 0.5, 0., 1.), 1e-5));
+        assert_eq!(gradient.sample(0.6), yellow);
+        assert!(gradient
+            .sample(0.9)
+            .to_srgba()
+            .to_vec4()
+            .abs_diff_eq(vec4(0.25, 1., 0., 1.), 1e-5));
+        assert_eq!(gradient.sample(1.2), green);
+
+        // Jump at the same position
+        let jump = ColorGradient::new([(0.5, red), (0.5, blue)]).unwrap();
+        assert_eq!(jump.sample(0.4), red);
+        assert_eq!(jump.sample(0.5), blue);
+
+        // Same gradient reused across targets
+        #[cfg(feature = "bevy_sprite")]
+        {
+            let mut lens = SpriteColorGradientLens {
+                gradient: gradient.clone(),
+            };
+            let mut sprite = Sprite::default();
+            lerp_with(&mut lens, &mut sprite, 0.6);
+            assert_eq!(sprite.color, yellow);
+        }
+        #[cfg(feature = "bevy_ui")]
+        {
+            let mut lens = UiBackgroundColorGradientLens {
+                gradient: gradient.clone(),
+            };
+            let mut background = BackgroundColor::default();
+            lerp_with(&mut lens, &mut background, 1.);
+            assert_eq!(background.0, green);
+        }
+
+        let empty: [(f32, Color); 0] = [];
+        assert!(matches!(
+            ColorGradient::new(empty),
+            Err(TweeningError::InvalidGradient)
+        ));
+        assert!(matches!(
+            ColorGradient::new([(0.5, Color::WHITE), (0.2, Color::BLACK)]),
+            Err(TweeningError::InvalidGradient)
+        ));
+        assert!(matches!(
+            ColorGradient::new([(-0.1, Color::WHITE)]),
+            Err(TweeningError::InvalidGradient)
+        ));
+    }
+
+    #[test]
+    fn reflect_path() {
+        // Invalid paths and types
+        assert!(matches!(
+            ReflectPathLens::<Transform>::new("translation..", 0., 1.),
+            Err(TweeningError::InvalidReflectPath)
+        ));
+        assert!(matches!(
+            ReflectPathLens::<Transform>::new("translation.w", 0., 1.),
+            Err(TweeningError::InvalidReflectPath)
+        ));
+        assert!(matches!(
+            ReflectPathLens::<Transform>::new("translation", 0., 1.),
+            Err(TweeningError::InvalidReflectType)
+        ));
+        assert!(matches!(
+            ReflectPathLens::<Transform>::new("translation.x", 0., Vec2::ONE),
+            Err(TweeningError::InvalidReflectType)
+        ));
+
+        let mut transform = Transform::default();
+
+        let mut lens = ReflectPathLens::<Transform>::new("translation.y", 2., 4.).unwrap();
+        lerp_with(&mut lens, &mut transform, 0.25);
+        assert_eq!(transform.translation, Vec3::new(0., 2.5, 0.));
+
+        let end = Quat::from_rotation_z(1.);
+        let mut lens = ReflectPathLens::<Transform>::new("rotation", Quat::IDENTITY, end).unwrap();
+        lerp_with(&mut lens, &mut transform, 1.);
+        assert!(transform.rotation.abs_diff_eq(end, 1e-5));
+        assert_eq!(transform.translation, Vec3::new(0., 2.5, 0.));
+
+        // Capture the start value from the target
+        let mut lens = ReflectPathLens::<Transform>::new("scale", Vec3::ZERO, Vec3::X).unwrap();
+        lens.capture_start(&transform);
+        assert_eq!(lens.start(), ReflectValue::Vec3(Vec3::ONE));
+        assert_eq!(lens.end(), ReflectValue::Vec3(Vec3::X));
+        lerp_with(&mut lens, &mut transform, 0.5);
+        assert_eq!(transform.scale, Vec3::new(1., 0.5, 0.5));
+    }
+
+    #[derive(Component, Lens)]
+    struct Derived {
+        #[lens]
+        value: f32,
+        #[lens(slerp)]
+        rotation: Quat,
+        #[lens(mix, name = "DerivedTintLens")]
+        color: Color,
+        #[lens(step)]
+        is_done: bool,
+    }
+
+    #[derive(Component, Lens)]
+    struct DerivedTuple(#[lens] Vec2);
+
+    fn lerp_with<T, L: Lens<T>>(lens: &mut L, value: &mut T, ratio: f32) {
+        let mut added = Tick::new(0);
+        let mut last_changed = Tick::new(0);
+        let mut caller = MaybeLocation::caller();
+        let target = Mut::new(
+            value,
+            &mut added,
+            &mut last_changed,
+            Tick::new(0),
+            Tick::new(0),
+            caller.as_mut(),
+        );
+        lens.lerp(target, ratio);
+    }
+
+    #[test]
+    fn derive_lens() {
+        let mut derived = Derived {
+            value: 0.,
+            rotation: Quat::IDENTITY,
+            color: Color::BLACK,
+            is_done: false,
+        };
+
+        let mut lens = DerivedValueLens { start: 1., end: 3. };
+        lerp_with(&mut lens, &mut derived, 0.25);
+        assert_eq!(derived.value, 1.5);
+
+        let end = Quat::from_rotation_y(2.);
+        let mut lens = DerivedRotationLens {
+            start: Quat::IDENTITY,
+            end,
+        };
+        lerp_with(&mut lens, &mut derived, 0.5);
+        assert!(derived
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_y(1.), 1e-5));
+
+        let mut lens = DerivedTintLens {
+            start: Color::BLACK,
+            end: Color::WHITE,
+        };
+        lerp_with(&mut lens, &mut derived, 1.);
+        assert_eq!(derived.color, Color::WHITE);
+
+        let mut lens = DerivedIsDoneLens {
+            start: false,
+            end: true,
+        };
+        lerp_with(&mut lens, &mut derived, 0.99);
+        assert!(!derived.is_done);
+        lerp_with(&mut lens, &mut derived, 1.);
+        assert!(derived.is_done);
+
+        // Capture the start value from the target
+        let mut lens = DerivedValueLens { start: 0., end: 0. };
+        lens.capture_start(&derived);
+        assert_eq!(lens.start, 1.5);
+
+        let mut derived = DerivedTuple(Vec2::ZERO);
+        let mut lens = DerivedTuple0Lens {
+            start: Vec2::ZERO,
+            end: Vec2::new(2., 4.),
+        };
+        lerp_with(&mut lens, &mut derived, 0.5);
+        assert_eq!(derived.0, Vec2::new(1., 2.));
+    }
+
+    #[test]
+    fn field_lens() {
+        let mut transform = Transform::default();
+
+        let mut lens = FieldLens::new(
+            |t: &mut Transform| &mut t.scale,
+            Vec3::ONE,
+            Vec3::new(2., 3., 5.),
+        );
+        lerp_with(&mut lens, &mut transform, 0.5);
+        assert_eq!(transform.scale, Vec3::new(1.5, 2., 3.));
+        assert_eq!(transform.translation, Vec3::ZERO);
+
+        let mut lens = FieldLens::new(
+            |t: &mut Transform| &mut t.rotation,
+            Quat::IDENTITY,
+            Quat::from_rotation_x(2.),
+        );
+        lerp_with(&mut lens, &mut transform, 0.25);
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_x(0.5), 1e-5));
+        assert_eq!(transform.scale, Vec3::new(1.5, 2., 3.));
+    }
+
+    #[test]
+    fn keyframe_lens() {
+        let mut transform = Transform::default();
+        let mut lens = KeyframeLens::new(
+            |t: &mut Transform| &mut t.translation,
+            [
+                (0.2, Vec3::ZERO, EaseMethod::CustomFunction(|t| t * t)),
+                (0.6, Vec3::X * 2., EaseMethod::default()),
+                (0.6, Vec3::Y, EaseMethod::default()),
+                (1., Vec3::Y * 3., EaseMethod::default()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(lens.keyframes().len(), 4);
+
+        // Before the first keyframe
+        lerp_with(&mut lens, &mut transform, 0.);
+        assert_eq!(transform.translation, Vec3::ZERO);
+        // Per-segment easing
+        lerp_with(&mut lens, &mut transform, 0.4);
+        assert!(transform.translation.abs_diff_eq(Vec3::X * 0.5, 1e-5));
+        // Jump at the same time
+        lerp_with(&mut lens, &mut transform, 0.6);
+        assert!(transform.translation.abs_diff_eq(Vec3::Y, 1e-5));
+        lerp_with(&mut lens, &mut transform, 0.8);
+        assert!(transform.translation.abs_diff_eq(Vec3::Y * 2., 1e-5));
+        // After the last keyframe
+        lerp_with(&mut lens, &mut transform, 1.2);
+        assert_eq!(transform.translation, Vec3::Y * 3.);
+
+        let empty: [(f32, Vec3, EaseFunction); 0] = [];
+        assert!(matches!(
+            KeyframeLens::new(|t: &mut Transform| &mut t.translation, empty),
+            Err(TweeningError::InvalidKeyframes)
+        ));
+        assert!(matches!(
+            KeyframeLens::new(
+                |t: &mut Transform| &mut t.scale,
+                [
+                    (0.5, Vec3::ONE, EaseFunction::Linear),
+                    (0.2, Vec3::ONE, EaseFunction::Linear)
+                ]
+            ),
+            Err(TweeningError::InvalidKeyframes)
+        ));
+        assert!(matches!(
+            KeyframeLens::new(
+                |t: &mut Transform| &mut t.scale,
+                [(1.5, Vec3::ONE, EaseFunction::Linear)]
+            ),
+            Err(TweeningError::InvalidKeyframes)
+        ));
+    }
+
+    #[test]
+    fn transform_path() {
+        let mut transform = Transform::default();
+        let points = [Vec3::ZERO, Vec3::X, vec3(1., 3., 0.)];
+
+        // Uniform parameter, each segment takes half the time
+        let mut lens = TransformPathLens::from_polyline(points).unwrap();
+        assert!(lens.length().is_none());
+        lerp_with(&mut lens, &mut transform, 0.25);
+        assert!(transform.translation.abs_diff_eq(vec3(0.5, 0., 0.), 1e-5));
+        lerp_with(&mut lens, &mut transform, 0.75);
+        assert!(transform.translation.abs_diff_eq(vec3(1., 1.5, 0.), 1e-5));
+        assert_eq!(transform.rotation, Quat::IDENTITY);
+
+        // Constant speed, oriented in the XY plane
+        let mut lens = TransformPathLens::from_polyline(points)
+            .unwrap()
+            .with_constant_speed()
+            .with_orientation(Dir3::X, Dir3::Z);
+        assert!((lens.length().unwrap() - 4.).abs() < 1e-5);
+        lerp_with(&mut lens, &mut transform, 0.125);
+        assert!(transform.translation.abs_diff_eq(vec3(0.5, 0., 0.), 1e-5));
+        assert!(transform.rotation.abs_diff_eq(Quat::IDENTITY, 1e-5));
+        lerp_with(&mut lens, &mut transform, 0.5);
+        assert!(transform.translation.abs_diff_eq(vec3(1., 1., 0.), 1e-5));
+        assert!((transform.rotation * Vec3::X).abs_diff_eq(Vec3::Y, 1e-5));
+        assert!((transform.rotation * Vec3::Z).abs_diff_eq(Vec3::Z, 1e-5));
+        lerp_with(&mut lens, &mut transform, 1.);
+        assert!(transform.translation.abs_diff_eq(vec3(1., 3., 0.), 1e-5));
+        assert!((transform.rotation * Vec3::X).abs_diff_eq(Vec3::Y, 1e-5));
+
+        // Cubic curve with uneven speed along a straight line
+        let curve = CubicBezier::new([[Vec3::ZERO, Vec3::ZERO, Vec3::X, Vec3::X * 4.]])
+            .to_curve()
+            .unwrap();
+        let mut lens = TransformPathLens::new(curve)
+            .unwrap()
+            .with_constant_speed()
+            .with_orientation(Dir3::NEG_Z, Dir3::Y);
+        lerp_with(&mut lens, &mut transform, 0.5);
+        assert!(transform.translation.abs_diff_eq(Vec3::X * 2., 1e-3));
+        assert!((transform.rotation * Vec3::NEG_Z).abs_diff_eq(Vec3::X, 1e-5));
+        assert!((transform.rotation * Vec3::Y).abs_diff_eq(Vec3::Y, 1e-5));
+
+        assert!(matches!(
+            TransformPathLens::from_polyline([Vec3::ONE]),
+            Err(TweeningError::InvalidPath)
+        ));
+        assert!(matches!(
+            TransformPathLens::new(FunctionCurve::new(Interval::EVERYWHERE, Vec3::splat)),
+            Err(TweeningError::InvalidPath)
+        ));
+    }
 }
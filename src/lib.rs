@@ -155,12 +155,40 @@
 //! - [`Tween`] - A simple tween (easing) animation between two values.
 //! - [`Sequence`] - A series of tweenables executing in series, one after the
 //!   other.
+//! - [`Parallel`] - A collection of tweenables executing in parallel, all at
+//!   the same time.
+//! - [`Timeline`] - A collection of tweenables each starting at its own time
+//!   offset, possibly overlapping.
+//! - [`Repeat`] - A wrapper looping another tweenable, like a [`Sequence`].
+//! - [`TimeWarp`] - A wrapper remapping the time of another tweenable through a
+//!   function, like an ease curve over a whole [`Sequence`].
+//! - [`Spring`] - A physics-based damped spring animation, settling at its
+//!   target instead of having a fixed duration.
+//! - [`Decay`] - A physics-based inertia animation, decelerating from an initial
+//!   velocity until it stops, like after a fling gesture.
 //! - [`Delay`] - A time delay. This doesn't animate anything.
+//! - [`WaitUntil`] - A wait until a runtime condition is met, like some user
+//!   input. This doesn't animate anything.
 //!
-//! To execute multiple animations in parallel (like the `Tracks` tweenable used
-//! to do in older versions of 🍃 Bevy Tweening; it's now removed), simply
-//! enqueue each animation independently. This require careful selection of
-//! individual timings though if you want to synchronize those animations.
+//! To execute multiple animations in parallel on the same target, use a
+//! [`Parallel`] tweenable (this replaces the `Tracks` tweenable of older
+//! versions of 🍃 Bevy Tweening). To animate different targets at the same
+//! time, simply enqueue each animation independently. This require careful
+//! selection of individual timings though if you want to synchronize those
+//! animations.
+//!
+//! To animate many targets with the same animation, each starting with a small
+//! time offset, use a [`Stagger`] to spawn all those animations at once.
+//!
+//! A [`Sequence`] can animate different targets one after the other, for
+//! example a [`Transform`] then a [`Sprite`]. See the [`AnimTarget`]
+//! documentation for details on how each target is resolved.
+//!
+//! Several [`TweenAnim`] animating the same target can be blended together by
+//! weight, either overriding or adding to each other, and can crossfade from
+//! one to another. See [the `TweenAnim` documentation] for details.
+//!
+//! [the `TweenAnim` documentation]: crate::TweenAnim#blending
 //!
 //! ## Chaining animations
 //!
@@ -200,7 +228,8 @@
 //! repeating the first animation. You're responsible for creating sequences
 //! that make sense. In general, only use infinite tweenable animations alone or
 //! as the last element of a sequence (for example, move to position and then
-//! rotate forever on self).
+//! rotate forever on self). The exception is [`WaitUntil`], whose duration is
+//! infinite only until its condition is met.
 //!
 //! # `TweenAnim`
 //!
@@ -287,53 +316,87 @@
 //! [`Entity`]: https://docs.rs/bevy/0.19/bevy/ecs/entity/struct.Entity.html
 //! [`ColorMaterial`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.ColorMaterial.html
 //! [`Transform`]: https://docs.rs/bevy/0.19/bevy/transform/components/struct.Transform.html
+//! [`Sprite`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.Sprite.html
 //! [`TransformPositionLens`]: crate::lens::TransformPositionLens
 //! [`move_to()`]: crate::EntityCommandsTweeningExtensions::move_to
 
 use std::{
-    any::TypeId,
+    any::{Any, TypeId},
+    borrow::Cow,
+    marker::PhantomData,
     ops::{Deref, DerefMut},
+    sync::Arc,
     time::Duration,
 };
 
+#[cfg(feature = "tween_asset")]
+pub use asset::{
+    ReflectTweenTarget, TweenAsset, TweenAssetLoader, TweenAssetLoaderError, TweenDesc, TweenNode,
+};
 use bevy::{
     asset::UntypedAssetId,
     ecs::{
         change_detection::{MaybeLocation, MutUntyped, Tick},
         component::{ComponentId, Components, Mutable},
     },
+    math::{
+        cubic_splines::CubicSegment,
+        curve::{FunctionCurve, Interval, SampleAutoCurve, UnevenSampleAutoCurve},
+    },
     platform::collections::HashMap,
     prelude::*,
 };
+pub use blend::{BlendMode, Blendable, TweenBlender};
 pub use lens::Lens;
 use lens::{
-    TransformRotateAdditiveXLens, TransformRotateAdditiveYLens, TransformRotateAdditiveZLens,
+    LinearLens, TransformRotateAdditiveXLens, TransformRotateAdditiveYLens,
+    TransformRotateAdditiveZLens,
 };
 pub use plugin::{AnimationSystem, TweeningPlugin};
+pub use snapshot::{AnimSnapshot, LensSnapshot, SimulationSnapshot, TweenableSnapshot};
+pub use stagger::{Stagger, StaggerFrom};
 use thiserror::Error;
 pub use tweenable::{
-    BoxedTweenable, CycleCompletedEvent, Delay, IntoBoxedTweenable, Sequence, TotalDuration, Tween,
-    TweenState, Tweenable,
+    BoxedTweenable, CycleCompletedEvent, Decay, Delay, IntoBoxedTweenable, Marker, Parallel,
+    Repeat, Sequence, Spring, TimeWarp, Timeline, TimelinePosition, TotalDuration, Tween,
+    TweenState, Tweenable, WaitUntil,
 };
 
 use crate::{
+    blend::BlendValue,
     lens::{TransformPositionLens, TransformScaleLens},
     tweenable::TweenConfig,
 };
 
+#[cfg(feature = "tween_asset")]
+mod asset;
+mod blend;
 pub mod lens;
 mod plugin;
+mod snapshot;
+mod stagger;
 mod tweenable;
 
 #[cfg(test)]
 mod test_utils;
 
+// Allow the derive macros to refer to this crate by name from within it.
+extern crate self as bevy_tweening;
+
+/// Items used by the code generated by the derive macros. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use bevy::{color::Mix, math::VectorSpace, prelude::Mut};
+}
+
 /// How many times to repeat a tweenable animation.
 ///
 /// See also [`RepeatStrategy`].
 ///
 /// Default: `Finite(1)`
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RepeatCount {
     /// Run the animation an exact number of times.
     ///
@@ -385,7 +448,9 @@ impl RepeatCount {
 /// the tweenable animation.
 ///
 /// Default: `Repeat`.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RepeatStrategy {
     /// Reset the cycle back to its starting position.
     ///
@@ -411,7 +476,9 @@ pub enum RepeatStrategy {
 /// Playback state of a [`TweenAnim`].
 ///
 /// Default: `Playing`.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlaybackState {
     /// The animation is playing. This is the default state.
     #[default]
@@ -443,7 +510,9 @@ impl std::ops::Not for PlaybackState {
 /// while the "shape" of the animation is controlled independently.
 ///
 /// Default: `EaseFunction::Linear`.
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Reflect)]
+#[reflect(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EaseMethod {
     /// Follow [`EaseFunction`].
     EaseFunction(EaseFunction),
@@ -455,22 +524,153 @@ pub enum EaseMethod {
     /// easing factor, typically also in `[0:1]`. Note that values outside this
     /// unit range may not work well with some animations; for example if
     /// animating a color, a negative red values have no meaning.
-    CustomFunction(fn(f32) -> f32),
+    ///
+    /// This variant can't be serialized. The function itself is opaque to
+    /// reflection; creating this variant from a reflected value with
+    /// [`FromReflect`] produces a linear function.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    CustomFunction(#[reflect(ignore, default = "EaseMethod::linear")] fn(f32) -> f32),
+    /// Follow a CSS-style cubic Bézier curve, given by its two control points
+    /// `(x1, y1)` and `(x2, y2)`. See [`cubic_bezier()`] for details.
+    ///
+    /// Unlike [`Curve`], this variant is fully described by its control
+    /// points, so can be serialized and reflected.
+    ///
+    /// [`cubic_bezier()`]: Self::cubic_bezier
+    /// [`Curve`]: Self::Curve
+    CubicBezier(Vec2, Vec2),
+    /// Follow a curve. The curve is sampled with the cycle ratio, in `[0:1]`,
+    /// clamped to the domain of the curve, and must return the easing factor,
+    /// like for [`CustomFunction`]. Unlike a plain function, the curve can hold
+    /// some state, like a table of samples.
+    ///
+    /// This variant can't be serialized; trying to serialize it returns an
+    /// error. The curve itself is opaque to reflection; creating this variant
+    /// from a reflected value with [`FromReflect`] produces a linear curve.
+    /// Prefer [`CubicBezier`] for cubic Bézier easing.
+    ///
+    /// [`CustomFunction`]: Self::CustomFunction
+    /// [`CubicBezier`]: Self::CubicBezier
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Curve(
+        #[reflect(ignore, default = "EaseMethod::linear_curve")] Arc<dyn Curve<f32> + Send + Sync>,
+    ),
 }
 
 impl EaseMethod {
+    /// Create an ease method following a CSS-style cubic Bézier curve.
+    ///
+    /// This is equivalent to the CSS `cubic-bezier(x1, y1, x2, y2)` easing
+    /// function. The curve starts at `(0, 0)` and ends at `(1, 1)`, and its
+    /// shape is controlled by the two control points `(x1, y1)` and `(x2, y2)`.
+    /// The `x` coordinates are typically in `[0:1]`, whereas the `y` ones can
+    /// be outside this range to overshoot. See [`CubicSegment::new_bezier_easing()`]
+    /// for details.
+    ///
+    /// ```
+    /// # use bevy_tweening::EaseMethod;
+    /// // CSS "ease-in-out"
+    /// let ease = EaseMethod::cubic_bezier(0.42, 0., 0.58, 1.);
+    /// ```
+    ///
+    /// This creates an [`EaseMethod::CubicBezier`].
+    #[must_use]
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self::CubicBezier(Vec2::new(x1, y1), Vec2::new(x2, y2))
+    }
+
+    /// Create an ease method interpolating linearly between evenly spaced
+    /// samples.
+    ///
+    /// The samples are spread over the `[0:1]` range of the cycle ratio, the
+    /// first one at `0` and the last one at `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidEaseSamples`] if there are less than 2
+    /// samples.
+    pub fn from_samples(samples: impl IntoIterator<Item = f32>) -> Result<Self, TweeningError> {
+        let curve = SampleAutoCurve::new(Interval::UNIT, samples)
+            .map_err(|_| TweeningError::InvalidEaseSamples)?;
+        Ok(Self::from_curve(curve))
+    }
+
+    /// Create an ease method interpolating linearly between unevenly spaced
+    /// samples.
+    ///
+    /// Each sample is a pair `(ratio, value)` giving the easing factor `value`
+    /// at the cycle ratio `ratio`. The samples don't need to be sorted. Before
+    /// the first sample and after the last one, the easing factor is constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidEaseSamples`] if there are less than 2
+    /// samples with a finite ratio.
+    pub fn from_timed_samples(
+        samples: impl IntoIterator<Item = (f32, f32)>,
+    ) -> Result<Self, TweeningError> {
+        let curve =
+            UnevenSampleAutoCurve::new(samples).map_err(|_| TweeningError::InvalidEaseSamples)?;
+        Ok(Self::from_curve(curve))
+    }
+
+    /// Create an ease method following an arbitrary curve.
+    ///
+    /// See [`EaseMethod::Curve`] for details.
+    #[must_use]
+    pub fn from_curve(curve: impl Curve<f32> + Send + Sync + 'static) -> Self {
+        Self::Curve(Arc::new(curve))
+    }
+
+    /// Create an ease method from a closure.
+    ///
+    /// Unlike [`EaseMethod::CustomFunction`], the closure can capture some
+    /// state.
+    #[must_use]
+    pub fn from_fn(f: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        Self::from_curve(FunctionCurve::new(Interval::UNIT, f))
+    }
+
+    /// Identity function, used as default for [`EaseMethod::CustomFunction`].
+    fn linear() -> fn(f32) -> f32 {
+        |x| x
+    }
+
+    /// Identity curve, used as default for [`EaseMethod::Curve`].
+    fn linear_curve() -> Arc<dyn Curve<f32> + Send + Sync> {
+        Arc::new(FunctionCurve::new(Interval::UNIT, |x| x))
+    }
+
     #[must_use]
-    fn sample(self, x: f32) -> f32 {
+    fn sample(&self, x: f32) -> f32 {
         match self {
-            Self::EaseFunction(function) => EasingCurve::new(0.0, 1.0, function).sample(x).unwrap(),
+            Self::EaseFunction(function) => {
+                EasingCurve::new(0.0, 1.0, *function).sample(x).unwrap()
+            }
             Self::Discrete(limit) => {
-                if x > limit {
+                if x > *limit {
                     1.
                 } else {
                     0.
                 }
             }
             Self::CustomFunction(function) => function(x),
+            Self::CubicBezier(p1, p2) => CubicSegment::new_bezier_easing(*p1, *p2).ease(x),
+            Self::Curve(curve) => curve.sample_clamped(x),
+        }
+    }
+}
+
+impl std::fmt::Debug for EaseMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EaseFunction(function) => f.debug_tuple("EaseFunction").field(function).finish(),
+            Self::Discrete(limit) => f.debug_tuple("Discrete").field(limit).finish(),
+            Self::CustomFunction(function) => {
+                f.debug_tuple("CustomFunction").field(function).finish()
+            }
+            Self::CubicBezier(p1, p2) => f.debug_tuple("CubicBezier").field(p1).field(p2).finish(),
+            Self::Curve(_) => f.debug_tuple("Curve").finish_non_exhaustive(),
         }
     }
 }
@@ -502,7 +702,9 @@ impl From<EaseFunction> for EaseMethod {
 /// not supported and will panic.
 ///
 /// Default: `Forward`.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlaybackDirection {
     /// Animation playing from start to end.
     #[default]
@@ -890,6 +1092,42 @@ pub trait EntityCommandsTweeningExtensions<'a> {
         duration: Duration,
         ease_method: impl Into<EaseMethod>,
     ) -> AnimatedEntityCommands<'a, impl TweenCommand>;
+
+    /// Queue a new tween animation applying a closure to a component of the
+    /// current entity.
+    ///
+    /// The entity must have a component of type `T`. The closure receives that
+    /// component and the eased ratio, and can mutate the component in any way.
+    /// This allows one-off animations without declaring a lens type.
+    ///
+    /// This function is a fire-and-forget convenience helper, and doesn't give
+    /// access to the [`Entity`] created. To retrieve the entity and control
+    /// the animation playback, you should spawn a [`TweenAnim`] component
+    /// manually, with a tween created by [`Tween::from_fn()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::{prelude::*, ecs::world::CommandQueue};
+    /// # use bevy_tweening::*;
+    /// # use std::time::Duration;
+    /// # let mut queue = CommandQueue::default();
+    /// # let mut world = World::default();
+    /// # let mut commands = Commands::new(&mut queue, &mut world);
+    /// commands.spawn(Transform::default()).tween_with(
+    ///     Duration::from_secs(1),
+    ///     EaseFunction::QuadraticIn,
+    ///     |mut transform: Mut<Transform>, ratio| {
+    ///         transform.translation.y = (ratio * std::f32::consts::PI).sin();
+    ///     },
+    /// );
+    /// ```
+    fn tween_with<T: Component<Mutability = Mutable>>(
+        self,
+        duration: Duration,
+        ease_method: impl Into<EaseMethod>,
+        f: impl FnMut(Mut<T>, f32) + Send + Sync + 'static,
+    ) -> AnimatedEntityCommands<'a, impl TweenCommand>;
 }
 
 /// Helper trait to abstract a tweening animation command.
@@ -908,7 +1146,7 @@ pub trait TweenCommand: EntityCommand {
 }
 
 /// Animation command to move an entity to a target position.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct MoveToCommand {
     end: Vec3,
     config: TweenConfig,
@@ -944,7 +1182,7 @@ impl TweenCommand for MoveToCommand {
 }
 
 /// Animation command to move an entity from a source position.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct MoveFromCommand {
     start: Vec3,
     config: TweenConfig,
@@ -980,7 +1218,7 @@ impl TweenCommand for MoveFromCommand {
 }
 
 /// Animation command to scale an entity to a target size.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct ScaleToCommand {
     end: Vec3,
     config: TweenConfig,
@@ -1016,7 +1254,7 @@ impl TweenCommand for ScaleToCommand {
 }
 
 /// Animation command to scale an entity from a source size.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct ScaleFromCommand {
     start: Vec3,
     config: TweenConfig,
@@ -1052,7 +1290,7 @@ impl TweenCommand for ScaleFromCommand {
 }
 
 /// Animation command to rotate an entity around its X axis.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct RotateXCommand {
     config: TweenConfig,
 }
@@ -1089,7 +1327,7 @@ impl TweenCommand for RotateXCommand {
 }
 
 /// Animation command to rotate an entity around its Y axis.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct RotateYCommand {
     config: TweenConfig,
 }
@@ -1126,7 +1364,7 @@ impl TweenCommand for RotateYCommand {
 }
 
 /// Animation command to rotate an entity around its Z axis.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct RotateZCommand {
     config: TweenConfig,
 }
@@ -1163,7 +1401,7 @@ impl TweenCommand for RotateZCommand {
 }
 
 /// Animation command to rotate an entity around its X axis by a given angle.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct RotateXByCommand {
     angle: f32,
     config: TweenConfig,
@@ -1200,7 +1438,7 @@ impl TweenCommand for RotateXByCommand {
 }
 
 /// Animation command to rotate an entity around its Y axis by a given angle.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct RotateYByCommand {
     angle: f32,
     config: TweenConfig,
@@ -1237,7 +1475,7 @@ impl TweenCommand for RotateYByCommand {
 }
 
 /// Animation command to rotate an entity around its Z axis by a given angle.
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub(crate) struct RotateZByCommand {
     angle: f32,
     config: TweenConfig,
@@ -1273,6 +1511,46 @@ impl TweenCommand for RotateZByCommand {
     }
 }
 
+/// Animation command to apply a closure to a component of an entity.
+pub(crate) struct TweenWithCommand<T, F> {
+    f: F,
+    config: TweenConfig,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T, F> EntityCommand for TweenWithCommand<T, F>
+where
+    T: Component<Mutability = Mutable>,
+    F: FnMut(Mut<T>, f32) + Send + Sync + 'static,
+{
+    type Out = ();
+    fn apply(self, mut entity: EntityWorldMut) {
+        if entity.contains::<T>() {
+            let tween = Tween::from_config(self.config, self.f);
+            let anim_target = AnimTarget::component::<T>(entity.id());
+            entity.world_scope(|world| {
+                world.spawn((TweenAnim::new(tween), anim_target));
+            });
+        }
+    }
+}
+
+impl<T, F> TweenCommand for TweenWithCommand<T, F>
+where
+    T: Component<Mutability = Mutable>,
+    F: FnMut(Mut<T>, f32) + Send + Sync + 'static,
+{
+    #[inline]
+    fn config(&self) -> &TweenConfig {
+        &self.config
+    }
+
+    #[inline]
+    fn config_mut(&mut self) -> &mut TweenConfig {
+        &mut self.config
+    }
+}
+
 /// Wrapper over an [`EntityCommands`] which stores an animation command.
 ///
 /// The wrapper acts as, and dereferences to, a regular [`EntityCommands`] as
@@ -1515,6 +1793,16 @@ impl<'a, C: TweenCommand> EntityCommandsTweeningExtensions<'a> for AnimatedEntit
     ) -> AnimatedEntityCommands<'a, impl TweenCommand> {
         self.into_inner().rotate_z_by(angle, duration, ease_method)
     }
+
+    #[inline]
+    fn tween_with<T: Component<Mutability = Mutable>>(
+        self,
+        duration: Duration,
+        ease_method: impl Into<EaseMethod>,
+        f: impl FnMut(Mut<T>, f32) + Send + Sync + 'static,
+    ) -> AnimatedEntityCommands<'a, impl TweenCommand> {
+        self.into_inner().tween_with(duration, ease_method, f)
+    }
 }
 
 impl<'a, C: TweenCommand> Deref for AnimatedEntityCommands<'a, C> {
@@ -1720,6 +2008,27 @@ impl<'a> EntityCommandsTweeningExtensions<'a> for EntityCommands<'a> {
             },
         )
     }
+
+    #[inline]
+    fn tween_with<T: Component<Mutability = Mutable>>(
+        self,
+        duration: Duration,
+        ease_method: impl Into<EaseMethod>,
+        f: impl FnMut(Mut<T>, f32) + Send + Sync + 'static,
+    ) -> AnimatedEntityCommands<'a, impl TweenCommand> {
+        AnimatedEntityCommands::new(
+            self,
+            TweenWithCommand {
+                f,
+                config: TweenConfig {
+                    ease_method: ease_method.into(),
+                    cycle_duration: duration,
+                    ..default()
+                },
+                _phantom: PhantomData,
+            },
+        )
+    }
 }
 
 /// Event raised when a [`TweenAnim`] completed.
@@ -1742,6 +2051,25 @@ pub struct AnimCompletedEvent {
     pub target: AnimTargetKind,
 }
 
+/// Event raised when the playback of a [`TweenAnim`] crosses a [`Marker`].
+///
+/// Markers are crossed in either playback direction, so this event is also
+/// raised when playing backward. Note that seeking with
+/// [`Tweenable::set_elapsed()`] or [`TweenAnim::seek_to_marker()`] doesn't
+/// cross any marker.
+#[derive(Debug, Clone, EntityEvent, Message)]
+pub struct MarkerEvent {
+    /// The entity owning the [`TweenAnim`] whose playback crossed the marker.
+    #[event_target]
+    pub anim_entity: Entity,
+    /// The animation target, at the end of the step which crossed the marker.
+    pub target: AnimTargetKind,
+    /// The name of the marker crossed.
+    pub name: Cow<'static, str>,
+    /// The playback direction in which the marker was crossed.
+    pub direction: PlaybackDirection,
+}
+
 /// Errors returned by various animation functions.
 #[derive(Debug, Error, Clone, Copy)]
 pub enum TweeningError {
@@ -1793,6 +2121,54 @@ pub enum TweeningError {
     /// Cannot change asset type.
     #[error("Cannot change asset type: was component_id={0:?}, now component_id={1:?}")]
     MismatchingAssetResourceId(ComponentId, ComponentId),
+    /// The [`AnimTarget`] doesn't contain any target for the given type.
+    #[error("No animation target found for type {0:?}.")]
+    MissingTarget(TypeId),
+    /// The tweenable doesn't contain any [`Marker`] with the requested name.
+    #[error("No marker found with the requested name.")]
+    MissingMarker,
+    /// The tweenable is not a [`Tween`] with a lens of the requested type.
+    #[error("The animation is not a Tween with a lens of the requested type.")]
+    MismatchingLens,
+    /// The reflection path can't be parsed, or doesn't designate a field of the
+    /// animated type.
+    #[error("Invalid reflection path.")]
+    InvalidReflectPath,
+    /// The field designated by a reflection path has a type which can't be
+    /// animated, or which doesn't match the type of the animated values.
+    #[error("Unsupported or mismatching type for the reflected field.")]
+    InvalidReflectType,
+    /// The target type of a tween in a `TweenAsset` is not registered in the
+    /// type registry with the `ReflectTweenTarget` type data.
+    #[error("Tween asset target type is not registered with ReflectTweenTarget.")]
+    UnregisteredTweenTarget,
+    /// A `TweenAsset` contains an empty sequence or a non-positive duration.
+    #[error("Invalid tween asset description.")]
+    InvalidTweenAsset,
+    /// The samples of an ease method are invalid, for example because there
+    /// are less than 2 of them.
+    #[error("Invalid ease method samples.")]
+    InvalidEaseSamples,
+    /// The keyframes of a keyframe lens are invalid, for example because they
+    /// are not sorted by time.
+    #[error("Invalid keyframes.")]
+    InvalidKeyframes,
+    /// The path of a path lens is invalid, for example because it has less than
+    /// 2 points.
+    #[error("Invalid path.")]
+    InvalidPath,
+    /// The stops of a color gradient are invalid, for example because they are
+    /// not sorted by position.
+    #[error("Invalid color gradient.")]
+    InvalidGradient,
+    /// The animation snapshot doesn't match the structure of the tweenable it's
+    /// restored onto.
+    #[error("Animation snapshot doesn't match the structure of the tweenable.")]
+    SnapshotMismatch,
+    /// The animation snapshot contains the state of a [`Tween`] lens which
+    /// can't be restored. See [`LensSnapshot`] for details.
+    #[error("Animation snapshot contains a lens state which can't be restored.")]
+    UnsupportedSnapshot,
 }
 
 type RegisterAction = dyn Fn(&Components, &mut TweenResolver) + Send + Sync + 'static;
@@ -1801,7 +2177,8 @@ type RegisterAction = dyn Fn(&Components, &mut TweenResolver) + Send + Sync + 's
 ///
 /// This type holds the minimum amount of data to reference ananimation target,
 /// aside from the actual type of the target.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Debug, Clone, PartialEq, Hash)]
 pub enum AnimTargetKind {
     /// Component animation target.
     Component {
@@ -1823,13 +2200,67 @@ pub enum AnimTargetKind {
 ///
 /// References an object used as the target of the animation stored in the
 /// [`TweenAnim`] component on the same entity.
-#[derive(Component)]
+///
+/// # Multi-target animations
+///
+/// A [`Sequence`] can animate several targets of different types one after the
+/// other. When the [`TweenAnim`] needs to access a target of another type, it
+/// resolves it from this component:
+/// - if the type is the type of the primary target, that primary target is
+///   used;
+/// - otherwise, if an additional target of that type was added with
+///   [`with_resource()`] or [`with_asset()`], that target is used;
+/// - otherwise, if the primary target is a component, then the component of
+///   that new type on the same entity is used.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # fn make_tween<T>() -> Tween { unimplemented!() }
+/// #[derive(Asset, TypePath)]
+/// struct MyMaterial {
+///     color: Color,
+/// }
+///
+/// fn setup(mut commands: Commands, mut materials: ResMut<Assets<MyMaterial>>) {
+///     let material = materials.add(MyMaterial { color: Color::WHITE });
+///     // Move the entity, then change the color of its material
+///     let seq = make_tween::<Transform>().then(make_tween::<MyMaterial>());
+///     let entity = commands.spawn(Transform::default()).id();
+///     commands.spawn((
+///         TweenAnim::new(seq),
+///         AnimTarget::component::<Transform>(entity).with_asset(material.id()),
+///     ));
+/// }
+/// ```
+///
+/// # Reflection
+///
+/// Only the [`kind`] of the primary target is exposed to reflection. The type
+/// of the targets can't be changed through reflection, so this component can't
+/// be created from a reflected value. For that reason, it doesn't register
+/// `ReflectComponent`, and is not saved to or loaded from a scene.
+///
+/// [`with_resource()`]: Self::with_resource
+/// [`with_asset()`]: Self::with_asset
+/// [`kind`]: Self::kind
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
 pub struct AnimTarget {
     /// Target kind and additional data to identify it.
     pub kind: AnimTargetKind,
 
-    /// Self-registering action for assets and resources.
-    pub(crate) register_action: Option<Box<RegisterAction>>,
+    /// Type of the primary target.
+    #[reflect(ignore)]
+    pub(crate) type_id: TypeId,
+
+    /// Additional targets, for multi-target animations, keyed by their type.
+    #[reflect(ignore)]
+    pub(crate) extra_targets: Vec<(TypeId, AnimTargetKind)>,
+
+    /// Self-registering actions for assets and resources.
+    #[reflect(ignore)]
+    pub(crate) register_actions: Vec<Box<RegisterAction>>,
 }
 
 impl AnimTarget {
@@ -1837,20 +2268,21 @@ impl AnimTarget {
     pub fn component<C: Component<Mutability = Mutable>>(entity: Entity) -> Self {
         Self {
             kind: AnimTargetKind::Component { entity },
+            type_id: TypeId::of::<C>(),
+            extra_targets: vec![],
             // Components have a complete typeless API, don't need any extra registration for type
             // erasure.
-            register_action: None,
+            register_actions: vec![],
         }
     }
 
     /// Create a target mutating the given resource.
     pub fn resource<R: Resource>() -> Self {
-        let register_action = |components: &Components, resolver: &mut TweenResolver| {
-            resolver.register_resource_resolver_for::<R>(components);
-        };
         Self {
             kind: AnimTargetKind::Resource,
-            register_action: Some(Box::new(register_action)),
+            type_id: TypeId::of::<R>(),
+            extra_targets: vec![],
+            register_actions: vec![Self::resource_register_action::<R>()],
         }
     }
 
@@ -1858,24 +2290,95 @@ impl AnimTarget {
     ///
     /// The asset is identified by its type, and its [`AssetId`].
     pub fn asset<A: Asset>(asset_id: impl Into<AssetId<A>>) -> Self {
-        let register_action = |components: &Components, resolver: &mut TweenResolver| {
-            resolver.register_asset_resolver_for::<A>(components);
-        };
         Self {
-            kind: AnimTargetKind::Asset {
-                asset_id: asset_id.into().untyped(),
-                assets_type_id: TypeId::of::<Assets<A>>(),
-            },
-            register_action: Some(Box::new(register_action)),
+            kind: Self::asset_kind(asset_id),
+            type_id: TypeId::of::<A>(),
+            extra_targets: vec![],
+            register_actions: vec![Self::asset_register_action::<A>()],
+        }
+    }
+
+    /// Add a resource as an additional target.
+    ///
+    /// This allows a multi-target animation to mutate the resource `R` when the
+    /// tweenable currently playing animates that type. If a target of that
+    /// same type was already added, it's replaced.
+    pub fn with_resource<R: Resource>(mut self) -> Self {
+        self.add_extra_target(
+            TypeId::of::<R>(),
+            AnimTargetKind::Resource,
+            Self::resource_register_action::<R>(),
+        );
+        self
+    }
+
+    /// Add an asset as an additional target.
+    ///
+    /// This allows a multi-target animation to mutate the asset `A` with the
+    /// given [`AssetId`] when the tweenable currently playing animates that
+    /// type. If a target of that same type was already added, it's replaced.
+    pub fn with_asset<A: Asset>(mut self, asset_id: impl Into<AssetId<A>>) -> Self {
+        self.add_extra_target(
+            TypeId::of::<A>(),
+            Self::asset_kind(asset_id),
+            Self::asset_register_action::<A>(),
+        );
+        self
+    }
+
+    /// Get the target kind for a tweenable animating the given type.
+    pub(crate) fn kind_for(&self, type_id: TypeId) -> Result<AnimTargetKind, TweeningError> {
+        if type_id == self.type_id {
+            return Ok(self.kind);
+        }
+        if let Some((_, kind)) = self.extra_targets.iter().find(|(id, _)| *id == type_id) {
+            return Ok(*kind);
+        }
+        match self.kind {
+            AnimTargetKind::Component { .. } => Ok(self.kind),
+            _ => Err(TweeningError::MissingTarget(type_id)),
         }
     }
 
     /// Register any resolver for this target.
     pub(crate) fn register(&self, components: &Components, resolver: &mut TweenResolver) {
-        if let Some(register_action) = self.register_action.as_ref() {
+        for register_action in &self.register_actions {
             register_action(components, resolver);
         }
     }
+
+    fn add_extra_target(
+        &mut self,
+        type_id: TypeId,
+        kind: AnimTargetKind,
+        register_action: Box<RegisterAction>,
+    ) {
+        if let Some(index) = self.extra_targets.iter().position(|(id, _)| *id == type_id) {
+            self.extra_targets[index].1 = kind;
+        } else {
+            self.extra_targets.push((type_id, kind));
+            self.register_actions.push(register_action);
+        }
+    }
+
+    fn asset_kind<A: Asset>(asset_id: impl Into<AssetId<A>>) -> AnimTargetKind {
+        AnimTargetKind::Asset {
+            asset_id: asset_id.into().untyped(),
+            assets_type_id: TypeId::of::<Assets<A>>(),
+        }
+    }
+
+    fn resource_register_action<R: Resource>() -> Box<RegisterAction> {
+        Box::new(|components: &Components, resolver: &mut TweenResolver| {
+            resolver.register_resource_resolver_for::<R>(components);
+        })
+    }
+
+    fn asset_register_action<A: Asset>() -> Box<RegisterAction> {
+        Box::new(|components: &Components, resolver: &mut TweenResolver| {
+            resolver.register_asset_resolver_for::<A>(components);
+        })
+    }
 }
 
 /// Animation controller instance.
@@ -1919,10 +2422,72 @@ impl AnimTarget {
 ///     ));
 /// }
 /// ```
-#[derive(Component)]
+///
+/// # Blending
+///
+/// By default, several animations targeting the same component, resource, or
+/// asset simply overwrite each other, in an unspecified order. Instead, they
+/// can be blended together, provided the target type implements [`Blendable`]
+/// and is registered with the [`TweenBlender`], like [`Transform`].
+///
+/// Animations are applied by increasing [`layer`], and blend with the result
+/// of the animations applied before them, according to their [`weight`] and
+/// [`blend_mode`]:
+/// - An override animation with a weight of `1.0` overwrites its target, as
+///   if there was no blending. It's applied before any other blended animation
+///   of the same layer.
+/// - The override animations with a weight less than `1.0` are averaged by
+///   weight. If their total weight is less than `1.0`, the remaining weight is
+///   given to the value the target had before blending.
+/// - Additive animations add their contribution on top, scaled by their
+///   weight. They're applied after all override animations of the same layer.
+///
+/// The weight can be animated with [`fade_weight()`], and [`crossfade()`]
+/// transitions smoothly from one animation to another.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// # fn make_bob() -> Tween { unimplemented!() }
+/// # fn make_wobble() -> Tween { unimplemented!() }
+/// fn setup(mut commands: Commands) {
+///     let entity = commands.spawn(Transform::default()).id();
+///     // Idle bob
+///     commands.spawn((TweenAnim::new(make_bob()), AnimTarget::component::<Transform>(entity)));
+///     // Wobble added on top, at half strength
+///     commands.spawn((
+///         TweenAnim::new(make_wobble())
+///             .with_blend_mode(BlendMode::Additive)
+///             .with_weight(0.5),
+///         AnimTarget::component::<Transform>(entity),
+///     ));
+/// }
+/// ```
+///
+/// # Reflection
+///
+/// The component is registered for reflection by the [`TweeningPlugin`], so
+/// its playback can be inspected and edited live, for example with an
+/// inspector. The tweenable itself is opaque to reflection; instead, its
+/// playback direction and elapsed time are mirrored into reflected fields,
+/// which are updated each time the animation is stepped. Any change made to
+/// those fields through reflection is applied to the tweenable before it's
+/// stepped again. Because the tweenable can't be reflected, this component
+/// can't be created from a reflected value. For that reason, it doesn't
+/// register `ReflectComponent`, and is not saved to or loaded from a scene.
+///
+/// [`layer`]: Self::layer
+/// [`weight`]: Self::weight
+/// [`blend_mode`]: Self::blend_mode
+/// [`fade_weight()`]: Self::fade_weight
+/// [`crossfade()`]: Self::crossfade
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
 pub struct TweenAnim {
     /// The animation itself. Note that the tweenable is stateful, so can't be
     /// shared with another [`TweenAnim`] instance.
+    #[reflect(ignore)]
     tweenable: BoxedTweenable,
     /// Control if the animation is played or not. Defaults to
     /// [`PlaybackState::Playing`].
@@ -1962,8 +2527,63 @@ pub struct TweenAnim {
     /// this wastes memory and may degrade performances if too many
     /// completed animations are kept around for no good reason.
     pub destroy_on_completion: bool,
+    /// Blending weight of the animation. Defaults to `1.0`.
+    ///
+    /// For [`BlendMode::Override`] animations, the weight is in `[0:1]`, and
+    /// the animation is only blended if its weight is less than `1.0`. For
+    /// [`BlendMode::Additive`] animations, the weight scales the contribution
+    /// of the animation, and can be greater than `1.0`. Negative values are
+    /// clamped to `0.` when the animation is stepped.
+    ///
+    /// See [the top-level `TweenAnim` documentation] for details.
+    ///
+    /// [the top-level `TweenAnim` documentation]: crate::TweenAnim#blending
+    pub weight: f32,
+    /// Blend mode of the animation. Defaults to [`BlendMode::Override`].
+    ///
+    /// See [the top-level `TweenAnim` documentation] for details.
+    ///
+    /// [the top-level `TweenAnim` documentation]: crate::TweenAnim#blending
+    pub blend_mode: BlendMode,
+    /// Blending layer of the animation. Defaults to `0`.
+    ///
+    /// Animations are applied by increasing layer, so animations of a higher
+    /// layer blend on top of the ones of lower layers.
+    ///
+    /// See [the top-level `TweenAnim` documentation] for details.
+    ///
+    /// [the top-level `TweenAnim` documentation]: crate::TweenAnim#blending
+    pub layer: i32,
     /// Current tweening completion state.
     tween_state: TweenState,
+    /// Playback direction of the tweenable, mirrored for reflection.
+    direction: PlaybackDirection,
+    /// Elapsed time of the tweenable, mirrored for reflection.
+    elapsed: Duration,
+    /// Values of the mirrored fields when last synchronized with the
+    /// tweenable, to detect changes made through reflection.
+    #[reflect(ignore)]
+    mirrored: (PlaybackDirection, Duration),
+    /// In-progress fade of the weight, if any.
+    #[reflect(ignore)]
+    weight_fade: Option<WeightFade>,
+    /// Last value produced by the animation when blended, to blend again when
+    /// the animation is not stepped.
+    #[reflect(ignore)]
+    blend_cache: Option<Box<BlendValue>>,
+    /// Tween asset the animation was instantiated from, if any.
+    #[cfg(feature = "tween_asset")]
+    #[reflect(ignore)]
+    source: Option<AssetId<TweenAsset>>,
+}
+
+/// Linear fade of the weight of a [`TweenAnim`].
+#[derive(Debug, Clone, Copy)]
+struct WeightFade {
+    start: f32,
+    end: f32,
+    duration: Duration,
+    elapsed: Duration,
 }
 
 impl TweenAnim {
@@ -1988,13 +2608,52 @@ impl TweenAnim {
             tweenable.target_type_id().is_some(),
             "The top-level Tweenable of a TweenAnim must be typed (Tweenable::target_type_id() returns Some)."
         );
+        let mirrored = (tweenable.playback_direction(), tweenable.elapsed());
         Self {
             tweenable,
             playback_state: PlaybackState::Playing,
             speed: 1.,
             destroy_on_completion: true,
+            weight: 1.,
+            blend_mode: BlendMode::Override,
+            layer: 0,
             tween_state: TweenState::Active,
+            direction: mirrored.0,
+            elapsed: mirrored.1,
+            mirrored,
+            weight_fade: None,
+            blend_cache: None,
+            #[cfg(feature = "tween_asset")]
+            source: None,
+        }
+    }
+
+    /// Create a new tween animation from a [`TweenAsset`].
+    ///
+    /// The asset must be loaded. The animation keeps track of the asset it was
+    /// created from, and is rebuilt when that asset is modified, for example
+    /// on hot reload. See [`TweenAsset`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::InvalidAssetId`] if the asset is not loaded, or
+    /// any error of [`TweenAsset::build()`] if the asset can't be built.
+    #[cfg(feature = "tween_asset")]
+    pub fn from_asset(
+        handle: &Handle<TweenAsset>,
+        assets: &Assets<TweenAsset>,
+        type_registry: &bevy::reflect::TypeRegistry,
+    ) -> Result<Self, TweeningError> {
+        let asset = assets
+            .get(handle)
+            .ok_or(TweeningError::InvalidAssetId(handle.id().untyped()))?;
+        let tweenable = asset.build(type_registry)?;
+        if tweenable.target_type_id().is_none() {
+            return Err(TweeningError::UntypedTweenable);
         }
+        let mut anim = Self::new(tweenable);
+        anim.source = Some(handle.id());
+        Ok(anim)
     }
 
     /// Configure the playback speed.
@@ -2012,32 +2671,152 @@ impl TweenAnim {
         self
     }
 
-    /// Step a single animation.
+    /// Configure the blending weight.
     ///
-    /// _The [`step_all()`] function is called automatically by the animation
-    /// system registered by the [`TweeningPlugin`], you generally don't
-    /// need to call this one._
+    /// See [`weight`] for details.
     ///
-    /// This is a shortcut for `step_many(world, delta_time, [entity])`, with
-    /// the added benefit that it returns some error if the entity is not valid.
-    /// See [`step_many()`] for details.
+    /// [`weight`]: Self::weight
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Configure the blend mode.
     ///
-    /// # Example
+    /// See [`blend_mode`] for details.
+    ///
+    /// [`blend_mode`]: Self::blend_mode
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Configure the blending layer.
+    ///
+    /// See [`layer`] for details.
+    ///
+    /// [`layer`]: Self::layer
+    pub fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Fade the blending weight linearly to a new value.
+    ///
+    /// The weight changes from its current value to `weight` over `duration`,
+    /// as the animation is stepped. The fade is independent of the playback
+    /// [`speed`] and [`playback_state`], and continues even if the animation
+    /// is paused or completed. A fade replaces any fade in progress.
+    ///
+    /// Once the weight reaches `0.` at the end of the fade, the animation
+    /// doesn't contribute anymore to its target, and is destroyed if
+    /// [`destroy_on_completion`] is `true`.
+    ///
+    /// See [the top-level `TweenAnim` documentation] for details on blending.
+    ///
+    /// [`speed`]: Self::speed
+    /// [`playback_state`]: Self::playback_state
+    /// [`destroy_on_completion`]: Self::destroy_on_completion
+    /// [the top-level `TweenAnim` documentation]: crate::TweenAnim#blending
+    pub fn fade_weight(&mut self, weight: f32, duration: Duration) {
+        self.weight_fade = Some(WeightFade {
+            start: self.weight,
+            end: weight,
+            duration,
+            elapsed: Duration::ZERO,
+        });
+    }
+
+    /// Crossfade from an animation to another one.
+    ///
+    /// This fades the weight of the `from` animation to `0.`, and the weight of
+    /// the `to` animation from `0.` to `1.`, both over `duration`. The `to`
+    /// animation is moved to the layer of the `from` one, and both are
+    /// [`BlendMode::Override`] animations, so that their weighted average
+    /// transitions smoothly from one to the other. Once faded out, the `from`
+    /// animation is destroyed if its [`destroy_on_completion`] is `true`.
+    ///
+    /// Both animations must target the same component, resource, or asset, of
+    /// a [`Blendable`] type.
     ///
     /// ```
-    /// # use std::time::Duration;
     /// # use bevy::prelude::*;
     /// # use bevy_tweening::*;
-    /// # fn make_tweenable() -> Tween { unimplemented!() }
-    /// #[derive(Component)]
-    /// struct MyMarker;
+    /// # use std::time::Duration;
+    /// #[derive(Resource)]
+    /// struct Anims {
+    ///     idle: Entity,
+    ///     hover: Entity,
+    /// }
     ///
-    /// fn my_system(world: &mut World) -> Result<()> {
-    ///     let mut q_anims = world.query_filtered::<Entity, (With<TweenAnim>, With<MyMarker>)>();
-    ///     let entity = q_anims.single(world)?;
-    ///     let delta_time = Duration::from_millis(200);
-    ///     TweenAnim::step_one(world, delta_time, entity);
-    ///     Ok(())
+    /// fn on_hover(anims: Res<Anims>, mut q_anims: Query<&mut TweenAnim>) {
+    ///     let Ok([mut idle, mut hover]) = q_anims.get_many_mut([anims.idle, anims.hover]) else {
+    ///         return;
+    ///     };
+    ///     TweenAnim::crossfade(&mut idle, &mut hover, Duration::from_millis(300));
+    /// }
+    /// ```
+    ///
+    /// [`destroy_on_completion`]: Self::destroy_on_completion
+    pub fn crossfade(from: &mut TweenAnim, to: &mut TweenAnim, duration: Duration) {
+        from.blend_mode = BlendMode::Override;
+        from.fade_weight(0., duration);
+        to.blend_mode = BlendMode::Override;
+        to.layer = from.layer;
+        to.weight = 0.;
+        to.fade_weight(1., duration);
+    }
+
+    /// Check if the animation is blended into its target, instead of simply
+    /// overwriting it.
+    fn is_blended(&self) -> bool {
+        self.blend_mode == BlendMode::Additive || self.weight < 1. || self.weight_fade.is_some()
+    }
+
+    /// Advance the fade of the weight, if any.
+    ///
+    /// Returns `true` if the weight just finished fading out to zero.
+    fn advance_weight_fade(&mut self, delta_time: Duration) -> bool {
+        let Some(fade) = &mut self.weight_fade else {
+            return false;
+        };
+        fade.elapsed = fade.elapsed.saturating_add(delta_time).min(fade.duration);
+        if fade.elapsed < fade.duration {
+            let ratio = fade.elapsed.div_duration_f32(fade.duration);
+            self.weight = fade.start.lerp(fade.end, ratio);
+            return false;
+        }
+        self.weight = fade.end;
+        self.weight_fade = None;
+        self.weight <= 0.
+    }
+
+    /// Step a single animation.
+    ///
+    /// _The [`step_all()`] function is called automatically by the animation
+    /// system registered by the [`TweeningPlugin`], you generally don't
+    /// need to call this one._
+    ///
+    /// This is a shortcut for `step_many(world, delta_time, [entity])`, with
+    /// the added benefit that it returns some error if the entity is not valid.
+    /// See [`step_many()`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::*;
+    /// # fn make_tweenable() -> Tween { unimplemented!() }
+    /// #[derive(Component)]
+    /// struct MyMarker;
+    ///
+    /// fn my_system(world: &mut World) -> Result<()> {
+    ///     let mut q_anims = world.query_filtered::<Entity, (With<TweenAnim>, With<MyMarker>)>();
+    ///     let entity = q_anims.single(world)?;
+    ///     let delta_time = Duration::from_millis(200);
+    ///     TweenAnim::step_one(world, delta_time, entity);
+    ///     Ok(())
     /// }
     /// ```
     ///
@@ -2120,21 +2899,13 @@ impl TweenAnim {
                     }
 
                     // Actually step the tweenable and update the target
-                    if let Ok((target_type_id, component_id, target, is_retargetable)) =
-                        Self::resolve_target(
-                            world.components(),
-                            maybe_target,
-                            entity,
-                            anim.tweenable(),
-                        )
-                    {
-                        targets.push((
-                            entity,
-                            target_type_id,
-                            component_id,
-                            target,
-                            is_retargetable,
-                        ));
+                    if let Ok((target_type_id, component_id, target)) = Self::resolve_target(
+                        world.components(),
+                        maybe_target,
+                        entity,
+                        anim.tweenable(),
+                    ) {
+                        targets.push((entity, target_type_id, component_id, target));
                     }
                 }
             }
@@ -2172,13 +2943,9 @@ impl TweenAnim {
                         entity,
                         anim.tweenable(),
                     ) {
-                        Ok((target_type_id, component_id, target, is_retargetable)) => Some((
-                            entity,
-                            target_type_id,
-                            component_id,
-                            target,
-                            is_retargetable,
-                        )),
+                        Ok((target_type_id, component_id, target)) => {
+                            Some((entity, target_type_id, component_id, target))
+                        }
                         Err(err) => {
                             bevy::log::error!(
                                 "Error while stepping TweenAnim on entity {:?}: {:?}",
@@ -2199,13 +2966,14 @@ impl TweenAnim {
         maybe_target: Option<&AnimTarget>,
         anim_entity: Entity,
         tweenable: &dyn Tweenable,
-    ) -> Result<(TypeId, ComponentId, AnimTargetKind, bool), TweeningError> {
+    ) -> Result<(TypeId, ComponentId, AnimTargetKind), TweeningError> {
         let type_id = tweenable
             .target_type_id()
             .ok_or(TweeningError::UntypedTweenable)?;
         if let Some(target) = maybe_target {
             // Target explicitly specified with AnimTarget component
-            let component_id = match &target.kind {
+            let kind = target.kind_for(type_id)?;
+            let component_id = match &kind {
                 AnimTargetKind::Component { .. } => components
                     .get_id(type_id)
                     .ok_or(TweeningError::ComponentNotRegistered(type_id))?,
@@ -2216,11 +2984,9 @@ impl TweenAnim {
                     .get_id(*assets_type_id)
                     .ok_or(TweeningError::AssetNotRegistered(type_id))?,
             };
-            let is_retargetable = false; // explicit target
-            Ok((type_id, component_id, target.kind, is_retargetable))
+            Ok((type_id, component_id, kind))
         } else {
             // Target implicitly self; this can only be a component target
-            let is_retargetable = true;
             if let Some(component_id) = components.get_id(type_id) {
                 Ok((
                     type_id,
@@ -2228,7 +2994,6 @@ impl TweenAnim {
                     AnimTargetKind::Component {
                         entity: anim_entity,
                     },
-                    is_retargetable,
                 ))
             } else {
                 // We can't implicitly target an asset without its AssetId
@@ -2237,148 +3002,155 @@ impl TweenAnim {
         }
     }
 
+    /// Resolve again the target of an animation, after its tweenable requested
+    /// a retarget because the type of its target changed.
+    fn resolve_retarget(
+        world: &World,
+        anim_entity: Entity,
+    ) -> Result<(TypeId, ComponentId, AnimTargetKind), TweeningError> {
+        let anim = world
+            .get::<TweenAnim>(anim_entity)
+            .ok_or(TweeningError::MissingTweenAnim(anim_entity))?;
+        let maybe_target = world.get::<AnimTarget>(anim_entity);
+        Self::resolve_target(
+            world.components(),
+            maybe_target,
+            anim_entity,
+            anim.tweenable(),
+        )
+    }
+
     fn step_impl(
         world: &mut World,
         delta_time: Duration,
-        anims: &[(Entity, TypeId, ComponentId, AnimTargetKind, bool)],
+        anims: &[(Entity, TypeId, ComponentId, AnimTargetKind)],
     ) {
         let mut to_remove = Vec::with_capacity(anims.len());
-        world.resource_scope(|world, resolver: Mut<TweenResolver>| {
-            world.resource_scope(
-                |world, mut cycle_events: Mut<Messages<CycleCompletedEvent>>| {
-                    world.resource_scope(
-                        |world, mut anim_events: Mut<Messages<AnimCompletedEvent>>| {
-                            let anim_comp_id = world.component_id::<TweenAnim>().unwrap();
-                            for (
-                                anim_entity,
-                                target_type_id,
-                                component_id,
-                                anim_target,
-                                is_retargetable,
-                            ) in anims
-                            {
-                                let retain = match anim_target {
-                                    AnimTargetKind::Component {
-                                        entity: comp_entity,
-                                    } => {
-                                        let (mut entities, commands) =
-                                            world.entities_and_commands();
-                                        let ret = if *anim_entity == *comp_entity {
-                                            // The TweenAnim animates another component on the same
-                                            // entity
-                                            let Ok([mut ent]) = entities.get_mut([*anim_entity])
-                                            else {
-                                                continue;
-                                            };
-                                            let Ok([anim, target]) =
-                                                ent.get_mut_by_id([anim_comp_id, *component_id])
-                                            else {
-                                                continue;
-                                            };
-                                            // SAFETY: We fetched the EntityMut from the component
-                                            // ID of
-                                            // TweenAnim
-                                            #[allow(unsafe_code)]
-                                            let mut anim = unsafe { anim.with_type::<TweenAnim>() };
-                                            anim.step_self(
-                                                commands,
-                                                *anim_entity,
-                                                delta_time,
-                                                anim_target,
-                                                target,
-                                                target_type_id,
-                                                cycle_events.reborrow(),
-                                                anim_events.reborrow(),
-                                            )
-                                        } else {
-                                            // The TweenAnim animates a component on a different
-                                            // entity
-                                            let Ok([mut anim, mut target]) =
-                                                entities.get_mut([*anim_entity, *comp_entity])
-                                            else {
-                                                continue;
-                                            };
-                                            let Some(mut anim) = anim.get_mut::<TweenAnim>() else {
-                                                continue;
-                                            };
-                                            let Ok(target) = target.get_mut_by_id(*component_id)
-                                            else {
-                                                continue;
-                                            };
-                                            anim.step_self(
-                                                commands,
-                                                *anim_entity,
-                                                delta_time,
-                                                anim_target,
-                                                target,
-                                                target_type_id,
-                                                cycle_events.reborrow(),
-                                                anim_events.reborrow(),
-                                            )
+
+        // Apply the animations by layer, and in each layer the non-blended ones first,
+        // then the blended override ones, and finally the additive ones.
+        let mut anims = anims
+            .iter()
+            .map(|anim| {
+                let blending = world.get::<TweenAnim>(anim.0).map(|anim| {
+                    (
+                        anim.layer,
+                        anim.blend_mode,
+                        anim.is_blended(),
+                        anim.weight.max(0.),
+                    )
+                });
+                (*anim, blending)
+            })
+            .collect::<Vec<_>>();
+        anims.sort_by_key(|(_, blending)| {
+            blending.map(|(layer, blend_mode, blended, _)| (layer, blend_mode, blended))
+        });
+
+        // Apply any change made through reflection, and let any WaitUntil evaluate its
+        // condition, before stepping. This is done before extracting the resources
+        // below, so the conditions can access them, like the AnimCompletedEvent
+        // messages.
+        for ((anim_entity, ..), _) in &anims {
+            if let Some(mut anim) = world.get_mut::<TweenAnim>(*anim_entity) {
+                anim.apply_mirrored();
+            }
+            if let Some(anim) = world.get::<TweenAnim>(*anim_entity) {
+                anim.tweenable().poll_conditions(world);
+            }
+        }
+
+        world.resource_scope(|world, mut blender: Mut<TweenBlender>| {
+            let blender = blender.as_mut();
+            blender.begin_step(anims.iter().filter_map(|(anim, blending)| {
+                let (layer, blend_mode, blended, weight) = (*blending)?;
+                (blended && blend_mode == BlendMode::Override)
+                    .then_some((anim.1, anim.3, layer, weight))
+            }));
+
+            world.resource_scope(|world, resolver: Mut<TweenResolver>| {
+                world.resource_scope(
+                    |world, mut cycle_events: Mut<Messages<CycleCompletedEvent>>| {
+                        world.resource_scope(
+                            |world, mut anim_events: Mut<Messages<AnimCompletedEvent>>| {
+                                let anim_comp_id = world.component_id::<TweenAnim>().unwrap();
+                                for ((anim_entity, target_type_id, component_id, anim_target), _) in &anims {
+                                    let mut delta_time = delta_time;
+                                    let mut target = (*target_type_id, *component_id, *anim_target);
+                                    let prev_elapsed = world
+                                        .get::<TweenAnim>(*anim_entity)
+                                        .map(|anim| anim.tweenable().elapsed());
+                                    let retain = loop {
+                                        let (target_type_id, component_id, anim_target) = target;
+                                        let Some(ret) = Self::step_target(
+                                            world,
+                                            &resolver,
+                                            anim_comp_id,
+                                            *anim_entity,
+                                            delta_time,
+                                            &target_type_id,
+                                            component_id,
+                                            &anim_target,
+                                            cycle_events.reborrow(),
+                                            anim_events.reborrow(),
+                                            blender,
+                                        ) else {
+                                            // Animation or target entity not found; skip
+                                            break true;
                                         };
                                         match ret {
-                                            Ok(res) => {
-                                                if res.needs_retarget {
-                                                    assert!(res.retain);
-                                                    if *is_retargetable {
-                                                        //to_retarget.push(anim_entity);
-                                                        //true
-                                                        bevy::log::warn!("TODO: Multi-target tweenable sequence is not yet supported. Ensure the animation of the TweenAnim component on entity {:?} targets a single component type.", *anim_entity);
-                                                        false
-                                                    } else {
-                                                        bevy::log::warn!("Multi-target tweenable sequence cannot be used with an explicit single target. Remove the AnimTarget component from entity {:?}, or ensure all tweenables in the sequence target the same component.", *anim_entity);
-                                                        false
+                                            Ok(res) if res.needs_retarget => {
+                                                // The tweenable moved to a tweenable animating a
+                                                // different target type. Resolve that new target,
+                                                // and step again so the tweenable consumes any
+                                                // time left, which it saved internally.
+                                                match Self::resolve_retarget(world, *anim_entity) {
+                                                    Ok(new_target) => {
+                                                        target = new_target;
+                                                        delta_time = Duration::ZERO;
+                                                    }
+                                                    Err(err) => {
+                                                        bevy::log::error!(
+                                                            "Deleting animation on entity {:?} due to error while retargeting: {err:?}",
+                                                            *anim_entity
+                                                        );
+                                                        break false;
                                                     }
-                                                } else {
-                                                    res.retain
                                                 }
                                             }
-                                            Err(_) => false,
+                                            Ok(res) => break res.retain,
+                                            Err(err) => {
+                                                bevy::log::error!(
+                                                    "Deleting animation on entity {:?} due to error: {err:?}",
+                                                    *anim_entity
+                                                );
+                                                break false;
+                                            }
                                         }
-                                    }
-                                    AnimTargetKind::Resource => resolver
-                                        .resolve_resource(
-                                            world,
-                                            target_type_id,
-                                            *component_id,
-                                            *anim_entity,
-                                            delta_time,
-                                            cycle_events.reborrow(),
-                                            anim_events.reborrow(),
-                                        )
-                                        .unwrap_or_else(|err| {
-                                            bevy::log::error!(
-                                                "Deleting resource animation due to error: {err:?}"
-                                            );
-                                            false
-                                        }),
-                                    AnimTargetKind::Asset { asset_id, .. } => resolver
-                                        .resolve_asset(
+                                    };
+
+                                    if let Some(prev_elapsed) = prev_elapsed {
+                                        Self::send_marker_events(
                                             world,
-                                            target_type_id,
-                                            *component_id,
-                                            *asset_id,
                                             *anim_entity,
-                                            delta_time,
-                                            cycle_events.reborrow(),
-                                            anim_events.reborrow(),
-                                        )
-                                        .unwrap_or_else(|err| {
-                                            bevy::log::error!(
-                                                "Deleting asset animation due to error: {err:?}"
-                                            );
-                                            false
-                                        }),
-                                };
-
-                                if !retain {
-                                    to_remove.push(*anim_entity);
+                                            target.2,
+                                            prev_elapsed,
+                                        );
+                                    }
+
+                                    if let Some(mut anim) = world.get_mut::<TweenAnim>(*anim_entity) {
+                                        anim.update_mirrored();
+                                    }
+
+                                    if !retain {
+                                        to_remove.push(*anim_entity);
+                                    }
                                 }
-                            }
-                        },
-                    );
-                },
-            );
+                            },
+                        );
+                    },
+                );
+            });
         });
 
         let mut cmds = world.commands();
@@ -2389,6 +3161,176 @@ impl TweenAnim {
         world.flush();
     }
 
+    /// Apply to the tweenable any change made to the fields mirroring its state
+    /// through reflection.
+    fn apply_mirrored(&mut self) {
+        if self.direction != self.mirrored.0 {
+            self.set_playback_direction(self.direction);
+        }
+        if self.elapsed != self.mirrored.1 {
+            self.tweenable.set_elapsed(self.elapsed);
+            self.tween_state = TweenState::Active;
+        }
+        self.update_mirrored();
+    }
+
+    /// Update the fields mirroring the state of the tweenable for reflection.
+    fn update_mirrored(&mut self) {
+        self.mirrored = (
+            self.tweenable.playback_direction(),
+            self.tweenable.elapsed(),
+        );
+        (self.direction, self.elapsed) = self.mirrored;
+    }
+
+    /// Raise a [`MarkerEvent`] for each marker crossed since the animation was
+    /// at the given elapsed time.
+    fn send_marker_events(
+        world: &mut World,
+        anim_entity: Entity,
+        target: AnimTargetKind,
+        prev_elapsed: Duration,
+    ) {
+        let Some(anim) = world.get::<TweenAnim>(anim_entity) else {
+            return;
+        };
+        let tweenable = anim.tweenable();
+        if tweenable.elapsed() == prev_elapsed {
+            return;
+        }
+        let direction = tweenable.playback_direction();
+
+        // Markers at the start of the playback are crossed by the first step
+        let include_from = match direction {
+            PlaybackDirection::Forward => prev_elapsed.is_zero(),
+            PlaybackDirection::Backward => {
+                Some(prev_elapsed) == tweenable.total_duration().as_finite()
+            }
+        };
+
+        let mut events = vec![];
+        tweenable.visit_markers(
+            prev_elapsed,
+            tweenable.elapsed(),
+            include_from,
+            direction,
+            &mut |marker| {
+                events.push(MarkerEvent {
+                    anim_entity,
+                    target,
+                    name: marker.name.clone(),
+                    direction,
+                });
+            },
+        );
+        if events.is_empty() {
+            return;
+        }
+
+        // Send buffered events, if the app registered them
+        if let Some(mut marker_events) = world.get_resource_mut::<Messages<MarkerEvent>>() {
+            marker_events.write_batch(events.iter().cloned());
+        }
+
+        // Trigger all entity-scoped observers
+        let mut commands = world.commands();
+        for event in events {
+            commands.trigger(event);
+        }
+    }
+
+    /// Step a single animation, mutating the given target.
+    ///
+    /// Returns `None` if either the animation or its target component was not
+    /// found, in which case the animation is skipped.
+    #[allow(clippy::too_many_arguments)]
+    fn step_target(
+        world: &mut World,
+        resolver: &TweenResolver,
+        anim_comp_id: ComponentId,
+        anim_entity: Entity,
+        delta_time: Duration,
+        target_type_id: &TypeId,
+        component_id: ComponentId,
+        anim_target: &AnimTargetKind,
+        cycle_events: Mut<Messages<CycleCompletedEvent>>,
+        anim_events: Mut<Messages<AnimCompletedEvent>>,
+        blender: &mut TweenBlender,
+    ) -> Option<Result<StepResult, TweeningError>> {
+        match anim_target {
+            AnimTargetKind::Component {
+                entity: comp_entity,
+            } => {
+                let (mut entities, commands) = world.entities_and_commands();
+                if anim_entity == *comp_entity {
+                    // The TweenAnim animates another component on the same entity
+                    let Ok([mut ent]) = entities.get_mut([anim_entity]) else {
+                        return None;
+                    };
+                    let Ok([anim, target]) = ent.get_mut_by_id([anim_comp_id, component_id]) else {
+                        return None;
+                    };
+                    // SAFETY: We fetched the EntityMut from the component ID of TweenAnim
+                    #[allow(unsafe_code)]
+                    let mut anim = unsafe { anim.with_type::<TweenAnim>() };
+                    Some(anim.step_self(
+                        commands,
+                        anim_entity,
+                        delta_time,
+                        anim_target,
+                        target,
+                        target_type_id,
+                        cycle_events,
+                        anim_events,
+                        blender,
+                    ))
+                } else {
+                    // The TweenAnim animates a component on a different entity
+                    let Ok([mut anim, mut target]) = entities.get_mut([anim_entity, *comp_entity])
+                    else {
+                        return None;
+                    };
+                    let mut anim = anim.get_mut::<TweenAnim>()?;
+                    let Ok(target) = target.get_mut_by_id(component_id) else {
+                        return None;
+                    };
+                    Some(anim.step_self(
+                        commands,
+                        anim_entity,
+                        delta_time,
+                        anim_target,
+                        target,
+                        target_type_id,
+                        cycle_events,
+                        anim_events,
+                        blender,
+                    ))
+                }
+            }
+            AnimTargetKind::Resource => Some(resolver.resolve_resource(
+                world,
+                target_type_id,
+                component_id,
+                anim_entity,
+                delta_time,
+                cycle_events,
+                anim_events,
+                blender,
+            )),
+            AnimTargetKind::Asset { asset_id, .. } => Some(resolver.resolve_asset(
+                world,
+                target_type_id,
+                component_id,
+                *asset_id,
+                anim_entity,
+                delta_time,
+                cycle_events,
+                anim_events,
+                blender,
+            )),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn step_self(
         &mut self,
@@ -2396,29 +3338,50 @@ impl TweenAnim {
         anim_entity: Entity,
         delta_time: Duration,
         target_kind: &AnimTargetKind,
-        mut mut_untyped: MutUntyped,
+        mut_untyped: MutUntyped,
         target_type_id: &TypeId,
         mut cycle_events: Mut<Messages<CycleCompletedEvent>>,
         mut anim_events: Mut<Messages<AnimCompletedEvent>>,
+        blender: &mut TweenBlender,
     ) -> Result<StepResult, TweeningError> {
         let mut completed_events = Vec::with_capacity(8);
 
         // Sanity checks on fields which can be freely modified by the user
         self.speed = self.speed.max(0.);
+        self.weight = self.weight.max(0.);
 
-        // Retain completed animations only if requested
-        if self.tween_state == TweenState::Completed {
+        // Destroy animations which faded out, if requested
+        if self.advance_weight_fade(delta_time) {
             let ret = StepResult {
                 retain: !self.destroy_on_completion,
                 needs_retarget: false,
             };
             return Ok(ret);
         }
+        let blend = self.is_blended() && blender.is_blendable(*target_type_id);
+
+        if self.tween_state == TweenState::Completed
+            || self.playback_state == PlaybackState::Paused
+            || self.speed <= 0.
+        {
+            // Blended animations keep contributing to their target
+            if blend {
+                blender.blend(
+                    *target_type_id,
+                    *target_kind,
+                    self.layer,
+                    self.blend_mode,
+                    self.weight,
+                    mut_untyped,
+                    &mut self.blend_cache,
+                    None,
+                );
+            }
 
-        // Skip paused animations (but retain them)
-        if self.playback_state == PlaybackState::Paused || self.speed <= 0. {
+            // Retain completed animations only if requested, and skip paused animations
+            // (but retain them)
             let ret = StepResult {
-                retain: true,
+                retain: self.tween_state == TweenState::Active || !self.destroy_on_completion,
                 needs_retarget: false,
             };
             return Ok(ret);
@@ -2436,13 +3399,37 @@ impl TweenAnim {
                 target: *target_kind,
             });
         };
-        let (state, needs_retarget) = self.tweenable.step(
-            anim_entity,
-            delta_time,
-            mut_untyped.reborrow(),
-            target_type_id,
-            &mut notify_completed,
-        );
+        let (state, needs_retarget) = if blend {
+            let mut ret = (TweenState::Active, false);
+            let tweenable = &mut self.tweenable;
+            blender.blend(
+                *target_type_id,
+                *target_kind,
+                self.layer,
+                self.blend_mode,
+                self.weight,
+                mut_untyped,
+                &mut self.blend_cache,
+                Some(&mut |target| {
+                    ret = tweenable.step(
+                        anim_entity,
+                        delta_time,
+                        target,
+                        target_type_id,
+                        &mut notify_completed,
+                    );
+                }),
+            );
+            ret
+        } else {
+            self.tweenable.step(
+                anim_entity,
+                delta_time,
+                mut_untyped,
+                target_type_id,
+                &mut notify_completed,
+            )
+        };
         self.tween_state = state;
 
         // Send tween completed events once we reclaimed mut access to world and can get
@@ -2497,6 +3484,53 @@ impl TweenAnim {
         self.tween_state = TweenState::Active;
     }
 
+    /// Seek the animation to the first marker with the given name.
+    ///
+    /// This sets the elapsed time of the tweenable to the time returned by
+    /// [`Tweenable::marker_time()`], and resets the [`tween_state()`] to
+    /// [`TweenState::Active`]. Seeking doesn't cross any marker, so doesn't
+    /// raise any [`MarkerEvent`]. Like [`Tweenable::set_elapsed()`], this
+    /// doesn't update the target until the animation is stepped again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::MissingMarker`] if the tweenable doesn't have
+    /// any marker with that name.
+    ///
+    /// [`tween_state()`]: Self::tween_state
+    pub fn seek_to_marker(&mut self, name: &str) -> Result<(), TweeningError> {
+        let time = self
+            .tweenable
+            .marker_time(name)
+            .ok_or(TweeningError::MissingMarker)?;
+        self.tweenable.set_elapsed(time);
+        self.tween_state = TweenState::Active;
+        Ok(())
+    }
+
+    /// Set the playback direction of the animation.
+    ///
+    /// This changes the direction of the tweenable, and of all its children if
+    /// it's a composite tweenable like a [`Sequence`]. See
+    /// [`Tweenable::set_playback_direction()`] for details.
+    ///
+    /// This resets the [`tween_state()`] to [`TweenState::Active`], so that an
+    /// animation which completed can play again in the other direction, as long
+    /// as it wasn't destroyed on completion. The elapsed time and the target
+    /// are unchanged.
+    ///
+    /// [`tween_state()`]: Self::tween_state
+    pub fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.tweenable.set_playback_direction(direction);
+        self.tween_state = TweenState::Active;
+    }
+
+    /// Get the playback direction of the animation.
+    #[inline]
+    pub fn playback_direction(&self) -> PlaybackDirection {
+        self.tweenable.playback_direction()
+    }
+
     /// Get the tweenable describing this animation.
     ///
     /// To change the tweenable, use [`TweenAnim::set_tweenable()`].
@@ -2505,6 +3539,82 @@ impl TweenAnim {
         self.tweenable.as_ref()
     }
 
+    /// Get mutable access to the tweenable describing this animation, if it's
+    /// of type `T`.
+    ///
+    /// This allows changing a running tweenable in place, for example to
+    /// retarget a [`Spring`] with [`Spring::set_target()`]. Because the
+    /// tweenable may be modified, this resets the [`tween_state()`] to
+    /// [`TweenState::Active`], so that an animation which completed can play
+    /// again, as long as it wasn't destroyed on completion.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::*;
+    /// #[derive(Resource)]
+    /// struct Hovered(bool);
+    ///
+    /// fn hover(mut anim: Single<&mut TweenAnim>, hovered: Res<Hovered>) {
+    ///     let target = if hovered.0 { 1. } else { 0. };
+    ///     if let Some(spring) = anim.tweenable_mut::<Spring>() {
+    ///         spring.set_target(target);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns the tweenable, or `None` if it's not of type `T`. In the latter
+    /// case, the [`tween_state()`] is unchanged.
+    ///
+    /// [`tween_state()`]: Self::tween_state
+    pub fn tweenable_mut<T: Tweenable>(&mut self) -> Option<&mut T> {
+        let tweenable: &mut dyn Any = self.tweenable.as_mut();
+        let tweenable = tweenable.downcast_mut::<T>()?;
+        self.tween_state = TweenState::Active;
+        Some(tweenable)
+    }
+
+    /// Change the end value of a running [`Tween`] animation.
+    ///
+    /// This starts a new segment from the current value toward `end`, keeping
+    /// the current velocity so that the motion stays smooth. See
+    /// [`Tween::retarget()`] for details. On success, this resets the
+    /// [`tween_state()`] to [`TweenState::Active`], so that an animation which
+    /// completed can play again, as long as it wasn't destroyed on completion.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::{lens::*, *};
+    /// #[derive(Resource)]
+    /// struct Goal(Vec3);
+    ///
+    /// fn follow(mut anim: Single<&mut TweenAnim>, goal: Res<Goal>) {
+    ///     let _ = anim.retarget::<Transform, TransformPositionLens>(goal.0);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::MismatchingLens`] if the animation is not a
+    /// [`Tween`] animating a lens of type `L`. In that case, the animation is
+    /// unchanged.
+    ///
+    /// [`tween_state()`]: Self::tween_state
+    pub fn retarget<T, L>(&mut self, end: L::Value) -> Result<(), TweeningError>
+    where
+        T: 'static,
+        L: LinearLens<T> + Send + Sync + 'static,
+    {
+        let tweenable: &mut dyn Any = self.tweenable.as_mut();
+        let tween = tweenable
+            .downcast_mut::<Tween>()
+            .ok_or(TweeningError::MismatchingLens)?;
+        tween.retarget::<T, L>(end)?;
+        self.tween_state = TweenState::Active;
+        Ok(())
+    }
+
     /// Set a new animation description.
     ///
     /// Attempt to change the tweenable of an animation already spawned.
@@ -2523,6 +3633,9 @@ impl TweenAnim {
     /// other), call [`set_elapsed()`] first on the input `tweenable`, with
     /// the duration value of the old tweenable returned by [`elapsed()`].
     ///
+    /// If the animation was created from a `TweenAsset`, it stops being rebuilt
+    /// when that asset changes.
+    ///
     /// ```
     /// # use std::time::Duration;
     /// # use bevy::prelude::*;
@@ -2550,6 +3663,10 @@ impl TweenAnim {
     {
         let mut old_tweenable: BoxedTweenable = Box::new(tweenable);
         std::mem::swap(&mut self.tweenable, &mut old_tweenable);
+        #[cfg(feature = "tween_asset")]
+        {
+            self.source = None;
+        }
         // Reset tweening state, the new tweenable is at t=0
         self.tween_state = TweenState::Active;
         Ok(old_tweenable)
@@ -2565,6 +3682,54 @@ impl TweenAnim {
     pub fn tween_state(&self) -> TweenState {
         self.tween_state
     }
+
+    /// Save the playback state of the animation.
+    ///
+    /// The snapshot contains the playback state, speed, and completion state of
+    /// the animation, as well as the elapsed time and playback direction of its
+    /// tweenable and all its children. It can be saved, for example in a save
+    /// game, and restored later with [`restore()`] onto an identical
+    /// animation. See [`AnimSnapshot`] for details.
+    ///
+    /// [`restore()`]: Self::restore
+    #[must_use]
+    pub fn snapshot(&self) -> AnimSnapshot {
+        AnimSnapshot {
+            playback_state: self.playback_state,
+            speed: self.speed,
+            tween_state: self.tween_state,
+            tweenable: self.tweenable.save_state(),
+        }
+    }
+
+    /// Restore the playback state saved with [`snapshot()`].
+    ///
+    /// The animation must have been constructed identically to the one the
+    /// snapshot was taken from, so that playback continues exactly where it
+    /// stopped. Like [`Tweenable::set_elapsed()`], this doesn't update the
+    /// target until the animation is stepped again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::SnapshotMismatch`] if the checksum of the
+    /// snapshot doesn't match the structure of the tweenable of this
+    /// animation. In that case the animation is left unchanged.
+    ///
+    /// Returns [`TweeningError::UnsupportedSnapshot`] if the lens state of a
+    /// [`Tween`] can't be restored. See [`Tween`] for details.
+    ///
+    /// [`snapshot()`]: Self::snapshot
+    pub fn restore(&mut self, snapshot: &AnimSnapshot) -> Result<(), TweeningError> {
+        if self.tweenable.save_state().checksum != snapshot.tweenable.checksum {
+            return Err(TweeningError::SnapshotMismatch);
+        }
+        self.tweenable.restore_state(&snapshot.tweenable)?;
+        self.playback_state = snapshot.playback_state;
+        self.speed = snapshot.speed;
+        self.tween_state = snapshot.tween_state;
+        self.update_mirrored();
+        Ok(())
+    }
 }
 
 type ResourceResolver = Box<
@@ -2575,7 +3740,8 @@ type ResourceResolver = Box<
             Duration,
             Mut<Messages<CycleCompletedEvent>>,
             Mut<Messages<AnimCompletedEvent>>,
-        ) -> Result<bool, TweeningError>
+            &mut TweenBlender,
+        ) -> Result<StepResult, TweeningError>
         + Send
         + Sync
         + 'static,
@@ -2590,7 +3756,8 @@ type AssetResolver = Box<
             Duration,
             Mut<Messages<CycleCompletedEvent>>,
             Mut<Messages<AnimCompletedEvent>>,
-        ) -> Result<bool, TweeningError>
+            &mut TweenBlender,
+        ) -> Result<StepResult, TweeningError>
         + Send
         + Sync
         + 'static,
@@ -2638,8 +3805,9 @@ impl TweenResolver {
                         target_type_id: &TypeId,
                         delta_time: Duration,
                         mut cycle_events: Mut<Messages<CycleCompletedEvent>>,
-                        mut anim_events: Mut<Messages<AnimCompletedEvent>>|
-         -> Result<bool, TweeningError> {
+                        mut anim_events: Mut<Messages<AnimCompletedEvent>>,
+                        blender: &mut TweenBlender|
+         -> Result<StepResult, TweeningError> {
             // First, remove the resource R from the world so we can access it mutably in
             // parallel of the TweenAnim
             world.resource_scope(|world, resource: Mut<R>| {
@@ -2656,7 +3824,7 @@ impl TweenResolver {
                 };
 
                 // Finally, step the TweenAnim and mutate the target
-                let ret = anim.step_self(
+                anim.step_self(
                     commands,
                     entity,
                     delta_time,
@@ -2665,11 +3833,8 @@ impl TweenResolver {
                     target_type_id,
                     cycle_events.reborrow(),
                     anim_events.reborrow(),
-                );
-                ret.map(|result| {
-                    assert!(!result.needs_retarget, "Cannot use a multi-target sequence of tweenable animations with a resource target.");
-                    result.retain
-                })
+                    blender,
+                )
             })
         };
         self.resource_resolver
@@ -2686,8 +3851,9 @@ impl TweenResolver {
                         target_type_id: &TypeId,
                         delta_time: Duration,
                         mut cycle_events: Mut<Messages<CycleCompletedEvent>>,
-                        mut anim_events: Mut<Messages<AnimCompletedEvent>>|
-         -> Result<bool, TweeningError> {
+                        mut anim_events: Mut<Messages<AnimCompletedEvent>>,
+                        blender: &mut TweenBlender|
+         -> Result<StepResult, TweeningError> {
             let asset_id = asset_id.typed::<A>();
             // First, remove the Assets<A> from the world so we can access it mutably in
             // parallel of the TweenAnim
@@ -2697,8 +3863,7 @@ impl TweenResolver {
                 // Assets<A> was changed.
                 let this_tick = assets.last_changed().get();
 
-                let Some(mut asset_mut) = assets.get_mut(asset_id)
-                else {
+                let Some(mut asset_mut) = assets.get_mut(asset_id) else {
                     return Err(TweeningError::InvalidAssetId(asset_id.into()));
                 };
 
@@ -2720,13 +3885,20 @@ impl TweenResolver {
                 // Create a fake Mut<A> which is always unchanged before the anim steps.
                 // Its sole purpose is to know if the Lens::lerp() changed the asset.
                 // Ideally we'd directly use AssetMut<> but the interface doesn't match.
-                let mut added = Tick::MAX;  // hopefully unused...
+                let mut added = Tick::MAX; // hopefully unused...
                 let last_tick = this_tick.saturating_sub(1);
                 let mut last_changed = Tick::new(last_tick);
                 let last_run = last_changed;
                 let this_run = Tick::new(this_tick);
                 let mut caller = MaybeLocation::caller();
-                let typed_mut = Mut::new(asset_mut.bypass_change_detection(), &mut added, &mut last_changed, last_run, this_run, caller.as_mut());
+                let typed_mut = Mut::new(
+                    asset_mut.bypass_change_detection(),
+                    &mut added,
+                    &mut last_changed,
+                    last_run,
+                    this_run,
+                    caller.as_mut(),
+                );
                 assert!(!typed_mut.is_changed());
                 let mut mut_untyped: MutUntyped = typed_mut.into();
 
@@ -2740,6 +3912,7 @@ impl TweenResolver {
                     target_type_id,
                     cycle_events.reborrow(),
                     anim_events.reborrow(),
+                    blender,
                 );
 
                 // If the asset actually changed (as reported by Mut<>), mark it as such
@@ -2750,10 +3923,7 @@ impl TweenResolver {
                     let _ = asset_mut.into_inner();
                 }
 
-                ret.map(|result| {
-                    assert!(!result.needs_retarget, "Cannot use a multi-target sequence of tweenable animations with an asset target.");
-                    result.retain
-                })
+                ret
             })
         };
         self.asset_resolver
@@ -2772,7 +3942,8 @@ impl TweenResolver {
         delta_time: Duration,
         cycle_events: Mut<Messages<CycleCompletedEvent>>,
         anim_events: Mut<Messages<AnimCompletedEvent>>,
-    ) -> Result<bool, TweeningError> {
+        blender: &mut TweenBlender,
+    ) -> Result<StepResult, TweeningError> {
         let Some(resolver) = self.resource_resolver.get(&resource_id) else {
             println!("ERROR: resource not registered {:?}", resource_id);
             return Err(TweeningError::AssetResolverNotRegistered(resource_id));
@@ -2784,6 +3955,7 @@ impl TweenResolver {
             delta_time,
             cycle_events,
             anim_events,
+            blender,
         )
     }
 
@@ -2799,7 +3971,8 @@ impl TweenResolver {
         delta_time: Duration,
         cycle_events: Mut<Messages<CycleCompletedEvent>>,
         anim_events: Mut<Messages<AnimCompletedEvent>>,
-    ) -> Result<bool, TweeningError> {
+        blender: &mut TweenBlender,
+    ) -> Result<StepResult, TweeningError> {
         let Some(resolver) = self.asset_resolver.get(&resource_id) else {
             println!("ERROR: asset not registered {:?}", resource_id);
             return Err(TweeningError::AssetResolverNotRegistered(resource_id));
@@ -2812,6 +3985,7 @@ impl TweenResolver {
             delta_time,
             cycle_events,
             anim_events,
+            blender,
         )
     }
 }
@@ -2833,10 +4007,16 @@ mod tests {
         marker::PhantomData,
     };
 
-    use bevy::ecs::{change_detection::MaybeLocation, change_detection::Tick};
+    use bevy::{
+        ecs::{change_detection::MaybeLocation, change_detection::Tick},
+        reflect::{GetPath, TypeRegistry},
+    };
 
     use super::*;
-    use crate::test_utils::*;
+    use crate::{
+        lens::{CaptureStart, ReflectPathLens},
+        test_utils::*,
+    };
 
     struct DummyLens {
         start: f32,
@@ -2858,7 +4038,7 @@ mod tests {
         value: i32,
     }
 
-    #[derive(Debug, Default, Clone, Copy, Resource)]
+    #[derive(Debug, Default, Clone, Copy, Resource, Reflect)]
     struct DummyResource {
         value: f32,
     }
@@ -2874,6 +4054,19 @@ mod tests {
         }
     }
 
+    impl LinearLens<DummyComponent> for DummyLens {
+        type Value = f32;
+
+        fn endpoints(&self) -> (f32, f32) {
+            (self.start, self.end)
+        }
+
+        fn set_endpoints(&mut self, start: f32, end: f32) {
+            self.start = start;
+            self.end = end;
+        }
+    }
+
     impl Lens<DummyComponent2> for DummyLens2 {
         fn lerp(&mut self, mut target: Mut<DummyComponent2>, ratio: f32) {
             target.value = ((self.start as f32) * (1. - ratio) + (self.end as f32) * ratio) as i32;
@@ -2912,6 +4105,12 @@ mod tests {
         }
     }
 
+    impl CaptureStart<DummyResource> for DummyLens {
+        fn capture_start(&mut self, target: &DummyResource) {
+            self.start = target.value;
+        }
+    }
+
     #[test]
     fn dummy_lens_resource() {
         let mut res = DummyResource::default();
@@ -3051,6 +4250,54 @@ mod tests {
         assert_eq!(1., ease.sample(0.));
     }
 
+    #[test]
+    fn ease_method_curve() {
+        // Linear cubic bezier
+        let ease = EaseMethod::cubic_bezier(0.25, 0.25, 0.75, 0.75);
+        assert_approx_eq!(ease.sample(0.), 0., 1e-5);
+        assert_approx_eq!(ease.sample(0.3), 0.3, 1e-4);
+        assert_approx_eq!(ease.sample(1.), 1., 1e-5);
+
+        // CSS "ease-in" is slower than linear at the start
+        let ease = EaseMethod::cubic_bezier(0.42, 0., 1., 1.);
+        assert!(ease.sample(0.3) < 0.3);
+        assert_approx_eq!(ease.sample(1.), 1., 1e-5);
+
+        // Cubic bezier easing is reflected, unlike arbitrary curves
+        let reflected = EaseMethod::from_reflect(ease.as_partial_reflect()).unwrap();
+        assert_eq!(reflected.sample(0.3), ease.sample(0.3));
+        assert_eq!(
+            format!("{reflected:?}"),
+            "CubicBezier(Vec2(0.42, 0.0), Vec2(1.0, 1.0))"
+        );
+
+        let ease = EaseMethod::from_samples([0., 0.8, 1.]).unwrap();
+        assert_approx_eq!(ease.sample(0.25), 0.4, 1e-5);
+        assert_approx_eq!(ease.sample(0.75), 0.9, 1e-5);
+        assert!(matches!(
+            EaseMethod::from_samples([0.]),
+            Err(TweeningError::InvalidEaseSamples)
+        ));
+
+        let ease = EaseMethod::from_timed_samples([(1., 1.), (0., 0.), (0.8, 0.5)]).unwrap();
+        assert_approx_eq!(ease.sample(0.4), 0.25, 1e-5);
+        assert_approx_eq!(ease.sample(0.9), 0.75, 1e-5);
+        assert!(matches!(
+            EaseMethod::from_timed_samples([(f32::NAN, 0.), (0., 1.)]),
+            Err(TweeningError::InvalidEaseSamples)
+        ));
+
+        // Curves are sampled clamped to their domain
+        let ease =
+            EaseMethod::from_curve(FunctionCurve::new(Interval::new(0., 0.5).unwrap(), |x| x));
+        assert_approx_eq!(ease.sample(1.), 0.5, 1e-5);
+
+        let power = 3;
+        let ease = EaseMethod::from_fn(move |x| x.powi(power));
+        assert_approx_eq!(ease.sample(0.5), 0.125, 1e-5);
+        assert_eq!(format!("{ease:?}"), "Curve(..)");
+    }
+
     // TweenAnim::playback_state is entirely user-controlled; stepping animations
     // won't change it.
     #[test]
@@ -3312,25 +4559,16 @@ mod tests {
         assert!(env.component().value >= 2. && env.component().value <= 3.);
     }
 
-    // Currently multi-target sequences are not implemented. This _could_ work with
-    // implicit targets (so, multiple components on the same entity), but is a bit
-    // complex to implement with the current code. So leave that out for now, and
-    // test we assert if the user attempts it. The workaround is to create separate
-    // animations for each comopnent/target. Anyway multi-target sequence can't work
-    // with other target types, since they need an explicit TweenAnim, and we
-    // can't have more than one per entity.
     #[test]
-    #[should_panic(
-        expected = "TODO: Cannot use tweenable animations with different targets inside the same Sequence. Create separate animations for each target."
-    )]
     fn seq_multi_target() {
         let tween = Tween::new::<DummyComponent, DummyLens>(
-            EaseFunction::QuadraticInOut,
+            EaseFunction::Linear,
             Duration::from_secs(1),
             DummyLens { start: 0., end: 1. },
         )
+        .then(Delay::new(Duration::from_millis(500)))
         .then(Tween::new::<DummyComponent2, DummyLens2>(
-            EaseFunction::SmoothStep,
+            EaseFunction::Linear,
             Duration::from_secs(1),
             DummyLens2 { start: -5, end: 5 },
         ));
@@ -3339,7 +4577,454 @@ mod tests {
         env.world
             .entity_mut(entity)
             .insert(DummyComponent2 { value: -42 });
+
+        // The sequence starts by animating the first component
+        TweenAnim::step_one(&mut env.world, Duration::from_millis(500), entity).unwrap();
+        assert_approx_eq!(env.component().value, 0.5);
+        assert_eq!(env.world.get::<DummyComponent2>(entity).unwrap().value, -42);
+
+        // Retarget to the second component, and consume the time left this frame
         TweenAnim::step_one(&mut env.world, Duration::from_millis(1100), entity).unwrap();
+        assert_approx_eq!(env.component().value, 1.);
+        assert_eq!(env.world.get::<DummyComponent2>(entity).unwrap().value, -4);
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Active);
+
+        TweenAnim::step_one(&mut env.world, Duration::from_millis(900), entity).unwrap();
+        assert_approx_eq!(env.component().value, 1.);
+        assert_eq!(env.world.get::<DummyComponent2>(entity).unwrap().value, 5);
+        assert!(env.anim().is_none());
+    }
+
+    fn make_multi_target_seq() -> Sequence {
+        Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .then(Tween::new::<DummyComponent2, DummyLens2>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens2 { start: -5, end: 5 },
+        ))
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot use tweenable animations with different targets inside the same Parallel"
+    )]
+    fn par_multi_target() {
+        let _ = Parallel::new([
+            BoxedTweenable::from(make_multi_target_seq()),
+            BoxedTweenable::from(Tween::new::<DummyComponent, DummyLens>(
+                EaseFunction::Linear,
+                Duration::from_secs(1),
+                DummyLens { start: 0., end: 1. },
+            )),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot use tweenable animations with different targets inside the same Parallel"
+    )]
+    fn par_and_multi_target() {
+        let _ = Parallel::new([Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )])
+        .and(make_multi_target_seq());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cannot use tweenable animations with different targets inside the same Timeline"
+    )]
+    fn timeline_multi_target() {
+        let _ = Timeline::new()
+            .insert(
+                Duration::ZERO,
+                Tween::new::<DummyComponent, DummyLens>(
+                    EaseFunction::Linear,
+                    Duration::from_secs(1),
+                    DummyLens { start: 0., end: 1. },
+                ),
+            )
+            .insert(Duration::from_millis(500), make_multi_target_seq());
+    }
+
+    #[test]
+    fn par_single_target_seq() {
+        // A sequence animating a single target type is fine, even with untyped children
+        let seq = Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .then(Delay::new(Duration::from_secs(1)));
+        let par = Parallel::new([
+            BoxedTweenable::from(seq),
+            BoxedTweenable::from(Delay::new(Duration::from_secs(3))),
+        ]);
+        assert_eq!(par.target_type_id(), Some(TypeId::of::<DummyComponent>()));
+    }
+
+    #[test]
+    fn anim_wait_until() {
+        #[derive(Resource)]
+        struct Ready;
+
+        let seq = WaitUntil::new(|world| world.contains_resource::<Ready>()).then(Tween::new::<
+            DummyComponent,
+            DummyLens,
+        >(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        ));
+        let mut env = TestEnv::<DummyComponent>::new(seq);
+        env.step_all(Duration::from_millis(500));
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Active);
+        assert_eq!(env.component().value, 0.);
+
+        // The condition is evaluated by the animation system before stepping
+        env.world.insert_resource(Ready);
+        env.step_all(Duration::from_millis(200));
+        assert_approx_eq!(env.component().value, 0.2);
+        assert_eq!(
+            env.anim().unwrap().tweenable().total_duration(),
+            TotalDuration::Finite(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn anim_wait_until_completed() {
+        let mut env = TestEnv::<DummyComponent>::empty();
+        let first = env
+            .world
+            .spawn((
+                DummyComponent::default(),
+                TweenAnim::new(Tween::new::<DummyComponent, DummyLens>(
+                    EaseFunction::Linear,
+                    Duration::from_secs(1),
+                    DummyLens { start: 0., end: 1. },
+                )),
+            ))
+            .id();
+        let wait = WaitUntil::message_received::<AnimCompletedEvent>().then(Tween::new::<
+            DummyComponent,
+            DummyLens,
+        >(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        ));
+        let second = env
+            .world
+            .spawn((DummyComponent::default(), TweenAnim::new(wait)))
+            .id();
+
+        // The second animation waits until the first one completed
+        env.step_all(Duration::from_millis(1000));
+        assert!(env.world.get::<TweenAnim>(first).is_none());
+        assert_eq!(env.world.get::<DummyComponent>(first).unwrap().value, 1.);
+        assert_eq!(env.world.get::<DummyComponent>(second).unwrap().value, 0.);
+
+        // The completion message is received before the next step
+        env.step_all(Duration::from_millis(200));
+        assert_approx_eq!(env.world.get::<DummyComponent>(second).unwrap().value, 0.2);
+    }
+
+    #[test]
+    fn anim_spring_retarget() {
+        let spring = Spring::new::<DummyComponent, DummyLens>(DummyLens { start: 0., end: 1. });
+        let mut env = TestEnv::<DummyComponent>::new(spring);
+        env.anim_mut().unwrap().destroy_on_completion = false;
+        for _ in 0..100 {
+            env.step_all(Duration::from_millis(16));
+        }
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+        assert_eq!(env.component().value, 1.);
+
+        // Accessing the tweenable with the wrong type doesn't change anything
+        assert!(env.anim_mut().unwrap().tweenable_mut::<Tween>().is_none());
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+
+        // Retarget the settled spring
+        env.anim_mut()
+            .unwrap()
+            .tweenable_mut::<Spring>()
+            .unwrap()
+            .set_target(0.);
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Active);
+        env.step_all(Duration::from_millis(16));
+        assert!(env.component().value < 1.);
+        for _ in 0..100 {
+            env.step_all(Duration::from_millis(16));
+        }
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+        assert_eq!(env.component().value, 0.);
+    }
+
+    #[test]
+    fn anim_retarget() {
+        let tween = Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        let mut env = TestEnv::<DummyComponent>::new(tween);
+        env.anim_mut().unwrap().destroy_on_completion = false;
+        env.step_all(Duration::from_millis(1000));
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+        assert_eq!(env.component().value, 1.);
+
+        // Retargeting with the wrong lens type doesn't change anything
+        assert!(matches!(
+            env.anim_mut()
+                .unwrap()
+                .retarget::<Transform, TransformPositionLens>(Vec3::ZERO),
+            Err(TweeningError::MismatchingLens)
+        ));
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+
+        // Retarget the completed tween
+        env.anim_mut()
+            .unwrap()
+            .retarget::<DummyComponent, DummyLens>(-1.)
+            .unwrap();
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Active);
+        env.step_all(Duration::from_millis(500));
+        assert_approx_eq!(env.component().value, 0., 1e-5);
+        env.step_all(Duration::from_millis(500));
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+        assert_eq!(env.component().value, -1.);
+    }
+
+    #[test]
+    fn anim_decay() {
+        let decay = Decay::new::<DummyComponent, DummyLens>(DummyLens { start: 0., end: 1. }, 4.)
+            .with_friction(4.);
+        let mut env = TestEnv::<DummyComponent>::new(decay);
+        let mut frames = 0;
+        while env.anim().is_some() {
+            frames += 1;
+            assert!(frames < 1000);
+            env.step_all(Duration::from_millis(16));
+            let completed = env.event_count::<AnimCompletedEvent>();
+            assert_eq!(completed, usize::from(env.anim().is_none()));
+        }
+        assert_approx_eq!(env.component().value, 1., 1e-3);
+    }
+
+    #[test]
+    fn anim_markers() {
+        let tween = Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .with_marker("start", Duration::ZERO)
+        .with_marker("impact", Duration::from_millis(300))
+        .with_marker("end", Duration::from_secs(1));
+        let mut env = TestEnv::<DummyComponent>::new(tween);
+        env.anim_mut().unwrap().destroy_on_completion = false;
+        let marker_names = |env: &TestEnv<DummyComponent>| {
+            let events = env.world.resource::<Messages<MarkerEvent>>();
+            events
+                .get_cursor()
+                .read(events)
+                .map(|event| {
+                    assert_eq!(event.anim_entity, env.entity);
+                    (event.name.to_string(), event.direction)
+                })
+                .collect::<Vec<_>>()
+        };
+        let forward = |name: &str| (name.to_string(), PlaybackDirection::Forward);
+        let backward = |name: &str| (name.to_string(), PlaybackDirection::Backward);
+
+        env.step_all(Duration::from_millis(200));
+        assert_eq!(marker_names(&env), [forward("start")]);
+        env.step_all(Duration::from_millis(200));
+        assert_eq!(marker_names(&env), [forward("impact")]);
+        env.step_all(Duration::from_secs(1));
+        assert_eq!(marker_names(&env), [forward("end")]);
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+
+        // Seeking doesn't cross any marker
+        env.anim_mut().unwrap().seek_to_marker("impact").unwrap();
+        assert_eq!(
+            env.anim().unwrap().tweenable().elapsed(),
+            Duration::from_millis(300)
+        );
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Active);
+        let Err(TweeningError::MissingMarker) = env.anim_mut().unwrap().seek_to_marker("missing")
+        else {
+            panic!("Expected TweeningError::MissingMarker");
+        };
+
+        // Markers are also crossed backward
+        env.anim_mut()
+            .unwrap()
+            .set_playback_direction(PlaybackDirection::Backward);
+        env.step_all(Duration::from_millis(100));
+        assert!(marker_names(&env).is_empty());
+        env.step_all(Duration::from_millis(500));
+        assert_eq!(marker_names(&env), [backward("start")]);
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+
+        // Paused animations don't cross any marker
+        env.anim_mut()
+            .unwrap()
+            .set_playback_direction(PlaybackDirection::Forward);
+        env.anim_mut().unwrap().playback_state = PlaybackState::Paused;
+        env.step_all(Duration::from_millis(500));
+        assert!(marker_names(&env).is_empty());
+    }
+
+    #[test]
+    fn seq_multi_target_backward() {
+        let tween = Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .then(Delay::new(Duration::from_millis(500)))
+        .then(Tween::new::<DummyComponent2, DummyLens2>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens2 { start: -5, end: 5 },
+        ));
+        let mut env = TestEnv::<DummyComponent>::new(tween);
+        env.anim_mut().unwrap().destroy_on_completion = false;
+        let entity = env.entity;
+        env.world
+            .entity_mut(entity)
+            .insert(DummyComponent2 { value: -42 });
+
+        TweenAnim::step_one(&mut env.world, Duration::from_secs(3), entity).unwrap();
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+        assert_eq!(env.world.get::<DummyComponent2>(entity).unwrap().value, 5);
+
+        // Reverse the completed animation, starting with the last component
+        env.anim_mut()
+            .unwrap()
+            .set_playback_direction(PlaybackDirection::Backward);
+        assert_eq!(
+            env.anim().unwrap().playback_direction(),
+            PlaybackDirection::Backward
+        );
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Active);
+        TweenAnim::step_one(&mut env.world, Duration::from_millis(500), entity).unwrap();
+        assert_eq!(env.world.get::<DummyComponent2>(entity).unwrap().value, 0);
+
+        // Retarget to the first component
+        TweenAnim::step_one(&mut env.world, Duration::from_millis(1500), entity).unwrap();
+        assert_eq!(env.world.get::<DummyComponent2>(entity).unwrap().value, -5);
+        assert_approx_eq!(env.component().value, 0.5);
+
+        TweenAnim::step_one(&mut env.world, Duration::from_secs(1), entity).unwrap();
+        assert_approx_eq!(env.component().value, 0.);
+        assert_eq!(env.anim().unwrap().tween_state(), TweenState::Completed);
+    }
+
+    #[test]
+    fn seq_multi_target_explicit() {
+        let mut env = TestEnv::<DummyComponent>::empty();
+        env.world.init_resource::<DummyResource>();
+        let mut assets = Assets::<DummyAsset>::default();
+        let handle = assets.add(DummyAsset::default());
+        env.world.insert_resource(assets);
+
+        let tween = Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .then(Tween::new::<DummyResource, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        ))
+        .then(Tween::new::<DummyAsset, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        ));
+        let target_entity = env.world.spawn(DummyComponent::default()).id();
+        let anim_entity = env
+            .world
+            .spawn((
+                TweenAnim::new(tween),
+                AnimTarget::component::<DummyComponent>(target_entity)
+                    .with_resource::<DummyResource>()
+                    .with_asset(handle.id()),
+            ))
+            .id();
+
+        let value = |world: &World| {
+            (
+                world.get::<DummyComponent>(target_entity).unwrap().value,
+                world.resource::<DummyResource>().value,
+                world
+                    .resource::<Assets<DummyAsset>>()
+                    .get(handle.id())
+                    .unwrap()
+                    .value,
+            )
+        };
+
+        env.step_all(Duration::from_millis(1500));
+        let (c, r, a) = value(&env.world);
+        assert_approx_eq!(c, 1.);
+        assert_approx_eq!(r, 0.5);
+        assert_approx_eq!(a, 0.);
+
+        env.step_all(Duration::from_millis(1000));
+        let (c, r, a) = value(&env.world);
+        assert_approx_eq!(c, 1.);
+        assert_approx_eq!(r, 1.);
+        assert_approx_eq!(a, 0.5);
+        assert!(env.world.get::<TweenAnim>(anim_entity).is_some());
+
+        env.step_all(Duration::from_millis(1000));
+        let (_, _, a) = value(&env.world);
+        assert_approx_eq!(a, 1.);
+        assert!(env.world.get::<TweenAnim>(anim_entity).is_none());
+    }
+
+    #[test]
+    fn seq_multi_target_missing() {
+        let mut env = TestEnv::<DummyComponent>::empty();
+        env.world.init_resource::<DummyResource>();
+        let mut assets = Assets::<DummyAsset>::default();
+        let handle = assets.add(DummyAsset::default());
+        env.world.insert_resource(assets);
+
+        // Asset primary target, without any resource target
+        let tween = Tween::new::<DummyAsset, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        )
+        .then(Tween::new::<DummyResource, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        ));
+        let target = AnimTarget::asset(handle.id());
+        let Err(TweeningError::MissingTarget(type_id)) =
+            target.kind_for(TypeId::of::<DummyResource>())
+        else {
+            panic!("Expected MissingTarget error");
+        };
+        assert_eq!(type_id, TypeId::of::<DummyResource>());
+        let anim_entity = env.world.spawn((TweenAnim::new(tween), target)).id();
+
+        // Retargeting fails, and the animation is destroyed
+        env.step_all(Duration::from_millis(1500));
+        assert!(env.world.get::<TweenAnim>(anim_entity).is_none());
+        assert_approx_eq!(env.world.resource::<DummyResource>().value, 0.);
     }
 
     // #[test]
@@ -3509,6 +5194,115 @@ mod tests {
         assert!(env.world.entity(anim_entity).get::<TweenAnim>().is_none());
     }
 
+    #[test]
+    fn anim_target_resource_from_current() {
+        let mut env = TestEnv::<Transform>::empty();
+        env.world.insert_resource(DummyResource { value: 0.5 });
+        let tween = Tween::from_current::<DummyResource, DummyLens>(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        let anim_entity = env
+            .world
+            .spawn((
+                TweenAnim::new(tween),
+                AnimTarget::resource::<DummyResource>(),
+            ))
+            .id();
+
+        // Starts from the current value of the resource
+        assert!(
+            TweenAnim::step_one(&mut env.world, Duration::from_millis(200), anim_entity).is_ok()
+        );
+        let res = env.world.resource::<DummyResource>();
+        assert_approx_eq!(res.value, 0.6, 1e-5);
+    }
+
+    #[test]
+    fn anim_target_resource_reflect_path() {
+        let mut env = TestEnv::<Transform>::empty();
+        env.world.init_resource::<DummyResource>();
+        let lens = ReflectPathLens::<DummyResource>::new("value", 1., 2.).unwrap();
+        let tween = Tween::new(EaseFunction::Linear, Duration::from_secs(1), lens);
+        let anim_entity = env
+            .world
+            .spawn((
+                TweenAnim::new(tween),
+                AnimTarget::resource::<DummyResource>(),
+            ))
+            .id();
+
+        assert!(
+            TweenAnim::step_one(&mut env.world, Duration::from_millis(300), anim_entity).is_ok()
+        );
+        let res = env.world.resource::<DummyResource>();
+        assert_approx_eq!(res.value, 1.3, 1e-5);
+    }
+
+    #[test]
+    fn anim_reflect() {
+        let tween = Tween::new(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut env = TestEnv::<Transform>::new(tween);
+
+        // The components can't be created from a reflected value, so can't be
+        // reflected as components, for example to be saved to a scene.
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<TweenAnim>();
+        type_registry.register::<AnimTarget>();
+        assert!(type_registry
+            .get_type_data::<ReflectComponent>(TypeId::of::<TweenAnim>())
+            .is_none());
+        assert!(type_registry
+            .get_type_data::<ReflectComponent>(TypeId::of::<AnimTarget>())
+            .is_none());
+
+        env.step_all(Duration::from_millis(200));
+        {
+            let anim = env.anim().unwrap();
+            assert_eq!(
+                *anim.path::<Duration>("elapsed").unwrap(),
+                Duration::from_millis(200)
+            );
+            assert_eq!(
+                *anim.path::<PlaybackDirection>("direction").unwrap(),
+                PlaybackDirection::Forward
+            );
+        }
+
+        // Edit the playback through reflection
+        {
+            let mut anim = env.anim_mut().unwrap();
+            *anim.path_mut::<f64>("speed").unwrap() = 2.;
+            *anim.path_mut::<Duration>("elapsed").unwrap() = Duration::from_millis(800);
+            *anim.path_mut::<PlaybackDirection>("direction").unwrap() = PlaybackDirection::Backward;
+        }
+        env.step_all(Duration::from_millis(100));
+        let anim = env.anim().unwrap();
+        assert_eq!(anim.speed, 2.);
+        assert_eq!(anim.playback_direction(), PlaybackDirection::Backward);
+        assert_eq!(anim.tweenable().elapsed(), Duration::from_millis(600));
+        assert_eq!(anim.elapsed, Duration::from_millis(600));
+        let tr = env.component();
+        assert!(tr.translation.abs_diff_eq(Vec3::splat(0.6), 1e-5));
+
+        // Changes made through the API are mirrored after the next step
+        env.anim_mut()
+            .unwrap()
+            .set_playback_direction(PlaybackDirection::Forward);
+        env.step_all(Duration::ZERO);
+        let anim = env.anim().unwrap();
+        assert_eq!(anim.direction, PlaybackDirection::Forward);
+        assert_eq!(anim.tweenable().elapsed(), Duration::from_millis(600));
+    }
+
     #[test]
     fn anim_target_asset() {
         let mut env = TestEnv::<Transform>::empty();
@@ -3814,6 +5608,36 @@ mod tests {
         assert_eq!(tr.rotation, Quat::from_rotation_z(FRAC_PI_2)); // 100%
     }
 
+    #[test]
+    fn animated_entity_commands_tween_with() {
+        let dummy_tween = Tween::new::<DummyComponent, DummyLens>(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            DummyLens { start: 0., end: 1. },
+        );
+        let mut env = TestEnv::<DummyComponent>::new(dummy_tween);
+
+        let entity = env
+            .world
+            .commands()
+            .spawn(Transform::default())
+            .tween_with(
+                Duration::from_secs(1),
+                EaseFunction::Linear,
+                |mut transform: Mut<Transform>, ratio| {
+                    transform.translation.x = ratio * 2.;
+                },
+            )
+            .with_repeat_count(2)
+            .id();
+        env.world.flush();
+
+        env.step_all(Duration::from_millis(1300)); // 130%
+
+        let tr = env.world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(tr.translation.x, 0.6, 1e-5);
+    }
+
     #[test]
     fn resolver_resource() {
         let dummy_tween = Tween::new::<DummyComponent, DummyLens>(
@@ -3854,6 +5678,7 @@ mod tests {
                                         delta_time,
                                         cycle_events.reborrow(),
                                         anim_events.reborrow(),
+                                        &mut TweenBlender::default(),
                                     )
                                     .is_err());
                             },
@@ -3875,17 +5700,21 @@ mod tests {
                     |world, mut cycle_events: Mut<Messages<CycleCompletedEvent>>| {
                         world.resource_scope(
                             |world, mut anim_events: Mut<Messages<AnimCompletedEvent>>| {
-                                assert!(resolver
-                                    .resolve_resource(
-                                        world,
-                                        &TypeId::of::<DummyResource>(),
-                                        resource_id,
-                                        entity,
-                                        delta_time,
-                                        cycle_events.reborrow(),
-                                        anim_events.reborrow(),
-                                    )
-                                    .unwrap());
+                                assert!(
+                                    resolver
+                                        .resolve_resource(
+                                            world,
+                                            &TypeId::of::<DummyResource>(),
+                                            resource_id,
+                                            entity,
+                                            delta_time,
+                                            cycle_events.reborrow(),
+                                            anim_events.reborrow(),
+                                            &mut TweenBlender::default(),
+                                        )
+                                        .unwrap()
+                                        .retain
+                                );
                             },
                         );
                     },
@@ -3937,6 +5766,7 @@ mod tests {
                                         delta_time,
                                         cycle_events.reborrow(),
                                         anim_events.reborrow(),
+                                        &mut TweenBlender::default(),
                                     )
                                     .is_err());
                             },
@@ -3958,18 +5788,22 @@ mod tests {
                     |world, mut cycle_events: Mut<Messages<CycleCompletedEvent>>| {
                         world.resource_scope(
                             |world, mut anim_events: Mut<Messages<AnimCompletedEvent>>| {
-                                assert!(resolver
-                                    .resolve_asset(
-                                        world,
-                                        &TypeId::of::<DummyAsset>(),
-                                        resource_id,
-                                        untyped_asset_id,
-                                        entity,
-                                        delta_time,
-                                        cycle_events.reborrow(),
-                                        anim_events.reborrow(),
-                                    )
-                                    .unwrap());
+                                assert!(
+                                    resolver
+                                        .resolve_asset(
+                                            world,
+                                            &TypeId::of::<DummyAsset>(),
+                                            resource_id,
+                                            untyped_asset_id,
+                                            entity,
+                                            delta_time,
+                                            cycle_events.reborrow(),
+                                            anim_events.reborrow(),
+                                            &mut TweenBlender::default(),
+                                        )
+                                        .unwrap()
+                                        .retain
+                                );
                             },
                         );
                     },
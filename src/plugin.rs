@@ -1,6 +1,12 @@
 use bevy::prelude::*;
 
-use crate::{AnimCompletedEvent, CycleCompletedEvent, TweenAnim, TweenResolver};
+#[cfg(feature = "tween_asset")]
+use crate::{asset::tween_asset_reload_system, ReflectTweenTarget, TweenAsset, TweenAssetLoader};
+use crate::{
+    AnimCompletedEvent, AnimSnapshot, AnimTarget, AnimTargetKind, BlendMode, CycleCompletedEvent,
+    EaseMethod, MarkerEvent, PlaybackDirection, PlaybackState, RepeatCount, RepeatStrategy,
+    TotalDuration, TweenAnim, TweenBlender, TweenResolver, TweenState,
+};
 
 /// Plugin to register the 🍃 Bevy Tweening animation framework.
 ///
@@ -10,6 +16,15 @@ use crate::{AnimCompletedEvent, CycleCompletedEvent, TweenAnim, TweenResolver};
 /// [`AnimationSystem::AnimationUpdate`] system set, during the [`Update`]
 /// schedule.
 ///
+/// The plugin also registers the [`TweenAnim`] and [`AnimTarget`] components,
+/// and the types they use, for reflection. This allows inspecting and editing
+/// the playback of animations at runtime, for example with an inspector.
+///
+/// With the `tween_asset` feature, if the `AssetPlugin` is present, the plugin
+/// also registers the `TweenAsset` type and its loader, as well as a system
+/// rebuilding the animations instantiated from a `TweenAsset` when it
+/// changes. That system runs just before the core animation system.
+///
 /// ```no_run
 /// use bevy::prelude::*;
 /// use bevy_tweening::*;
@@ -25,12 +40,46 @@ pub struct TweeningPlugin;
 impl Plugin for TweeningPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TweenResolver>()
+            .init_resource::<TweenBlender>()
             .add_message::<CycleCompletedEvent>()
             .add_message::<AnimCompletedEvent>()
+            .add_message::<MarkerEvent>()
             .add_systems(
                 Update,
                 animator_system.in_set(AnimationSystem::AnimationUpdate),
-            );
+            )
+            .register_type::<TweenAnim>()
+            .register_type::<AnimTarget>()
+            .register_type::<AnimTargetKind>()
+            .register_type::<PlaybackState>()
+            .register_type::<PlaybackDirection>()
+            .register_type::<RepeatCount>()
+            .register_type::<RepeatStrategy>()
+            .register_type::<EaseMethod>()
+            .register_type::<TotalDuration>()
+            .register_type::<TweenState>()
+            .register_type::<BlendMode>()
+            .register_type::<AnimSnapshot>();
+
+        #[cfg(feature = "tween_asset")]
+        app.register_type::<Transform>()
+            .register_type_data::<Transform, ReflectTweenTarget>();
+    }
+
+    fn finish(&self, app: &mut App) {
+        // The asset types can only be registered once the AssetPlugin was built, which may
+        // happen after this plugin is added.
+        #[cfg(feature = "tween_asset")]
+        if app.world().contains_resource::<AssetServer>() {
+            app.init_asset::<TweenAsset>()
+                .init_asset_loader::<TweenAssetLoader>()
+                .add_systems(
+                    Update,
+                    tween_asset_reload_system.before(AnimationSystem::AnimationUpdate),
+                );
+        }
+        #[cfg(not(feature = "tween_asset"))]
+        let _ = app;
     }
 }
 
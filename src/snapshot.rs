@@ -0,0 +1,498 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{lens::ReflectValue, PlaybackDirection, PlaybackState, TweenState, TweeningError};
+
+/// Saved playback state of a [`TweenAnim`].
+///
+/// A snapshot captures the progress of a running animation, so that it can be
+/// saved, for example in a save game, and later restored onto an identical
+/// animation to continue playback exactly where it stopped. It only contains
+/// the playback state, and not the description of the animation itself, like
+/// its lenses or durations, which can't be serialized in general. To restore a
+/// snapshot, the application first recreates the same animation from code, then
+/// calls [`TweenAnim::restore()`].
+///
+/// A snapshot can be serialized with `serde` when the `serde` feature is
+/// enabled, or through reflection.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::*;
+/// # fn make_tweenable() -> Tween { unimplemented!() }
+/// fn save(anim: &TweenAnim) -> AnimSnapshot {
+///     anim.snapshot()
+/// }
+///
+/// fn load(snapshot: &AnimSnapshot) -> Result<TweenAnim, TweeningError> {
+///     // Recreate the same animation, then restore its playback state
+///     let mut anim = TweenAnim::new(make_tweenable());
+///     anim.restore(snapshot)?;
+///     Ok(anim)
+/// }
+/// ```
+///
+/// [`TweenAnim`]: crate::TweenAnim
+/// [`TweenAnim::restore()`]: crate::TweenAnim::restore
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimSnapshot {
+    /// Playback state of the animation.
+    pub playback_state: PlaybackState,
+    /// Relative playback speed of the animation.
+    pub speed: f64,
+    /// Tweening completion state of the animation.
+    pub tween_state: TweenState,
+    /// Saved state of the tweenable of the animation.
+    pub tweenable: TweenableSnapshot,
+}
+
+/// Saved playback state of a [`Tweenable`] and its children.
+///
+/// This is produced by [`Tweenable::save_state()`], and restored by
+/// [`Tweenable::restore_state()`]. Each snapshot contains a checksum of the
+/// structure of the tweenable, which covers the type of the tweenable and
+/// recursively the ones of its children. Restoring a snapshot onto a tweenable
+/// with a different structure fails with [`TweeningError::SnapshotMismatch`].
+/// Note that the checksum doesn't cover the durations and other parameters of
+/// the tweenables, which are assumed to be identical.
+///
+/// [`Tweenable`]: crate::Tweenable
+/// [`Tweenable::save_state()`]: crate::Tweenable::save_state
+/// [`Tweenable::restore_state()`]: crate::Tweenable::restore_state
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TweenableSnapshot {
+    /// Elapsed time of the tweenable.
+    pub elapsed: Duration,
+    /// Playback direction of the tweenable.
+    pub direction: PlaybackDirection,
+    /// Index of the active child, for tweenables playing their children one
+    /// after the other, like a [`Sequence`], or number of cycles completed by
+    /// a [`Repeat`] of unknown cycle duration.
+    ///
+    /// [`Sequence`]: crate::Sequence
+    /// [`Repeat`]: crate::Repeat
+    pub index: Option<usize>,
+    /// Duration resolved at runtime, for tweenables whose duration is only
+    /// known once they complete, like a [`WaitUntil`].
+    ///
+    /// [`WaitUntil`]: crate::WaitUntil
+    pub resolved_duration: Option<Duration>,
+    /// State of the physics simulation, for tweenables driven by one instead
+    /// of their elapsed time, like a [`Spring`] or a [`Decay`].
+    ///
+    /// [`Spring`]: crate::Spring
+    /// [`Decay`]: crate::Decay
+    pub simulation: Option<SimulationSnapshot>,
+    /// State of the lens derived at runtime, for a [`Tween`] which captured
+    /// its start value or was retargeted.
+    ///
+    /// [`Tween`]: crate::Tween
+    pub lens: Option<LensSnapshot>,
+    /// Saved state of the children of the tweenable, in order.
+    pub children: Vec<TweenableSnapshot>,
+    /// Checksum of the structure of the tweenable and its children.
+    pub checksum: u64,
+}
+
+impl TweenableSnapshot {
+    /// Create a new snapshot of a tweenable without children.
+    ///
+    /// The `kind` identifies the type of the tweenable in the checksum. It's a
+    /// fixed string naming that type, like `"Sequence"`, which unlike
+    /// [`std::any::type_name()`] is stable across compiler versions, so the
+    /// snapshot remains valid after upgrading the compiler.
+    #[must_use]
+    pub fn new(kind: &str, elapsed: Duration, direction: PlaybackDirection) -> Self {
+        Self {
+            elapsed,
+            direction,
+            index: None,
+            resolved_duration: None,
+            simulation: None,
+            lens: None,
+            children: vec![],
+            checksum: fnv1a(FNV_OFFSET_BASIS, kind.as_bytes()),
+        }
+    }
+
+    /// Set the index of the active child.
+    #[must_use]
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Set the duration resolved at runtime.
+    #[must_use]
+    pub fn with_resolved_duration(mut self, duration: Option<Duration>) -> Self {
+        self.resolved_duration = duration;
+        self
+    }
+
+    /// Set the state of the physics simulation.
+    #[must_use]
+    pub fn with_simulation(mut self, simulation: SimulationSnapshot) -> Self {
+        self.simulation = Some(simulation);
+        self
+    }
+
+    /// Set the state of the lens derived at runtime.
+    #[must_use]
+    pub fn with_lens(mut self, lens: Option<LensSnapshot>) -> Self {
+        self.lens = lens;
+        self
+    }
+
+    /// Set the saved state of the children, and include their structure in the
+    /// checksum.
+    #[must_use]
+    pub fn with_children(mut self, children: Vec<TweenableSnapshot>) -> Self {
+        let mut checksum = fnv1a(self.checksum, &(children.len() as u64).to_le_bytes());
+        for child in &children {
+            checksum = fnv1a(checksum, &child.checksum.to_le_bytes());
+        }
+        self.checksum = checksum;
+        self.children = children;
+        self
+    }
+
+    /// Check the snapshot has the expected number of children.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::SnapshotMismatch`] if the snapshot has a
+    /// different number of children.
+    pub fn check_children(&self, count: usize) -> Result<(), TweeningError> {
+        if self.children.len() == count {
+            Ok(())
+        } else {
+            Err(TweeningError::SnapshotMismatch)
+        }
+    }
+}
+
+/// Saved state of the lens of a [`Tween`], derived at runtime.
+///
+/// A [`Tween`] created with [`Tween::from_current()`] captures its start value
+/// from the target, and a [`Tween`] retargeted with [`Tween::retarget()`] plays
+/// a new segment from its value at that time. Neither can be recomputed from
+/// the elapsed time alone, so the values are saved with [`ReflectValue`],
+/// which limits them to the types it supports.
+///
+/// [`Tween`]: crate::Tween
+/// [`Tween::from_current()`]: crate::Tween::from_current
+/// [`Tween::retarget()`]: crate::Tween::retarget
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LensSnapshot {
+    /// Start value captured from the target.
+    Captured(ReflectValue),
+    /// Segment played after a retarget.
+    Retargeted {
+        /// Value at the start of the segment.
+        start: ReflectValue,
+        /// Tangent at the start of the segment, in value per cycle.
+        tangent: ReflectValue,
+        /// Value at the end of the segment.
+        end: ReflectValue,
+    },
+    /// State which can't be saved, because the type of the lens values is not
+    /// supported by [`ReflectValue`], or because the lens doesn't expose its
+    /// captured start value.
+    Unsupported,
+}
+
+/// Saved state of the physics simulation of a [`Spring`] or a [`Decay`].
+///
+/// The simulation of those tweenables depends on more than their elapsed time,
+/// for example when a [`Spring`] is retargeted while moving, so their state
+/// can't be recomputed from the elapsed time alone.
+///
+/// [`Spring`]: crate::Spring
+/// [`Decay`]: crate::Decay
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationSnapshot {
+    /// Current position, as a lens ratio.
+    pub position: f32,
+    /// Current velocity, in lens ratio per second.
+    pub velocity: f32,
+    /// Position the simulation is pulled toward, if any. This is the target of
+    /// a [`Spring`], or the bound a [`Decay`] is pulled back to after going out
+    /// of bounds.
+    ///
+    /// [`Spring`]: crate::Spring
+    /// [`Decay`]: crate::Decay
+    pub target: Option<f32>,
+    /// Whether the simulation came to rest.
+    pub at_rest: bool,
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Hash some bytes with the FNV-1a algorithm, which unlike the standard library
+/// hasher is stable across builds, so can be saved.
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::Any,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        lens::{ReflectValue, TransformPositionLens},
+        test_utils::*,
+        *,
+    };
+
+    fn make_tween(end: f32) -> Tween {
+        Tween::new(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::splat(end),
+            },
+        )
+    }
+
+    fn make_sequence(wait: Arc<AtomicBool>) -> Sequence {
+        make_tween(1.)
+            .then(WaitUntil::new(move |_world: &World| {
+                wait.load(Ordering::Relaxed)
+            }))
+            .then(Repeat::new(make_tween(2.), 2))
+    }
+
+    #[test]
+    fn restore() {
+        let wait = Arc::new(AtomicBool::new(false));
+        let mut env = TestEnv::<Transform>::new(make_sequence(wait.clone()));
+        env.step_all(Duration::from_millis(1500));
+        wait.store(true, Ordering::Relaxed);
+        env.step_all(Duration::from_millis(1200));
+        env.anim_mut().unwrap().speed = 2.;
+        let snapshot = env.anim().unwrap().snapshot();
+        assert_eq!(snapshot.speed, 2.);
+        assert_eq!(snapshot.tween_state, TweenState::Active);
+        assert_eq!(snapshot.tweenable.index, Some(2));
+        assert_eq!(snapshot.tweenable.children.len(), 3);
+        assert_eq!(
+            snapshot.tweenable.children[1].resolved_duration,
+            Some(Duration::from_millis(500))
+        );
+
+        // Restore onto a freshly constructed, identical animation, whose condition
+        // was never met
+        let mut env2 = TestEnv::<Transform>::new(make_sequence(Arc::new(AtomicBool::new(false))));
+        env2.anim_mut().unwrap().restore(&snapshot).unwrap();
+        let anim = env2.anim().unwrap();
+        assert_eq!(anim.speed, 2.);
+        assert_eq!(anim.snapshot(), snapshot);
+        let tweenable: &dyn Any = anim.tweenable();
+        let seq = tweenable.downcast_ref::<Sequence>().unwrap();
+        assert_eq!(seq.index(), 2);
+        assert_eq!(
+            seq.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(3500))
+        );
+
+        // Both continue identically
+        env.step_all(Duration::from_millis(100));
+        env2.step_all(Duration::from_millis(100));
+        assert_eq!(env.component().translation, env2.component().translation);
+        assert_eq!(
+            env.anim().unwrap().snapshot(),
+            env2.anim().unwrap().snapshot()
+        );
+    }
+
+    #[test]
+    fn restore_simulation() {
+        let make_spring = || {
+            Spring::new(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            })
+        };
+        let mut env = TestEnv::<Transform>::new(make_spring());
+        env.step_all(Duration::from_millis(100));
+        env.anim_mut()
+            .unwrap()
+            .tweenable_mut::<Spring>()
+            .unwrap()
+            .set_target(-1.);
+        env.step_all(Duration::from_millis(50));
+        let snapshot = env.anim().unwrap().snapshot();
+        let simulation = snapshot.tweenable.simulation.unwrap();
+        assert_eq!(simulation.target, Some(-1.));
+        assert!(!simulation.at_rest);
+
+        // The retargeted spring continues exactly where it stopped
+        let mut env2 = TestEnv::<Transform>::new(make_spring());
+        env2.anim_mut().unwrap().restore(&snapshot).unwrap();
+        let tweenable: &dyn Any = env2.anim().unwrap().tweenable();
+        let spring = tweenable.downcast_ref::<Spring>().unwrap();
+        assert_eq!(spring.target(), -1.);
+        assert_eq!(spring.position(), simulation.position);
+        assert_eq!(spring.velocity(), simulation.velocity);
+        env.step_all(Duration::from_millis(100));
+        env2.step_all(Duration::from_millis(100));
+        assert_eq!(env.component().translation, env2.component().translation);
+
+        // A decay pulled back to its bound too
+        let make_decay = || {
+            Decay::new(
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+                2.,
+            )
+            .with_bounds(0., 0.5)
+        };
+        let mut env = TestEnv::<Transform>::new(make_decay());
+        env.step_all(Duration::from_millis(400));
+        let snapshot = env.anim().unwrap().snapshot();
+        assert_eq!(snapshot.tweenable.simulation.unwrap().target, Some(0.5));
+        let mut env2 = TestEnv::<Transform>::new(make_decay());
+        env2.anim_mut().unwrap().restore(&snapshot).unwrap();
+        env.step_all(Duration::from_millis(100));
+        env2.step_all(Duration::from_millis(100));
+        assert_eq!(env.component().translation, env2.component().translation);
+        assert_eq!(
+            env.anim().unwrap().snapshot(),
+            env2.anim().unwrap().snapshot()
+        );
+
+        // A snapshot without simulation state can't be restored onto a spring
+        let snapshot = TweenAnim::new(make_tween(1.)).snapshot();
+        let mut anim = TweenAnim::new(make_spring());
+        assert!(matches!(
+            anim.restore(&snapshot),
+            Err(TweeningError::SnapshotMismatch)
+        ));
+    }
+
+    #[test]
+    fn checksum() {
+        // The checksum only depends on the kind of the tweenables, so is stable across
+        // builds.
+        let snapshot = make_tween(1.).save_state();
+        assert_eq!(snapshot.checksum, 0x6d30_2b26_2fdd_b09c);
+        assert_eq!(
+            snapshot.checksum,
+            TweenableSnapshot::new("Tween", Duration::ZERO, PlaybackDirection::Forward).checksum
+        );
+        assert_ne!(
+            snapshot.checksum,
+            Delay::new(Duration::from_secs(1)).save_state().checksum
+        );
+    }
+
+    #[test]
+    fn restore_captured() {
+        let make_tween = || {
+            Tween::from_current(
+                EaseFunction::Linear,
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        };
+        let mut env = TestEnv::<Transform>::new(make_tween());
+        env.component_mut().translation = Vec3::splat(2.);
+        env.step_all(Duration::from_millis(500));
+        assert_approx_eq!(env.component().translation, Vec3::splat(1.5));
+        let snapshot = env.anim().unwrap().snapshot();
+        assert_eq!(
+            snapshot.tweenable.lens,
+            Some(LensSnapshot::Captured(ReflectValue::Vec3(Vec3::splat(2.))))
+        );
+
+        // The start value is restored instead of captured again from the target
+        let mut env2 = TestEnv::<Transform>::new(make_tween());
+        env2.component_mut().translation = Vec3::splat(1.5);
+        env2.anim_mut().unwrap().restore(&snapshot).unwrap();
+        env2.step_all(Duration::from_millis(250));
+        assert_approx_eq!(env2.component().translation, Vec3::splat(1.25));
+    }
+
+    #[test]
+    fn restore_retargeted() {
+        let mut env = TestEnv::<Transform>::new(make_tween(1.));
+        env.step_all(Duration::from_millis(500));
+        env.anim_mut()
+            .unwrap()
+            .tweenable_mut::<Tween>()
+            .unwrap()
+            .retarget::<Transform, TransformPositionLens>(Vec3::splat(2.))
+            .unwrap();
+        env.step_all(Duration::from_millis(300));
+        let snapshot = env.anim().unwrap().snapshot();
+        assert!(matches!(
+            snapshot.tweenable.lens,
+            Some(LensSnapshot::Retargeted { .. })
+        ));
+        env.step_all(Duration::from_millis(200));
+        let expected = env.component().translation;
+
+        // The segment can only be restored onto a retargeted tween
+        let mut env2 = TestEnv::<Transform>::new(make_tween(1.));
+        assert!(matches!(
+            env2.anim_mut().unwrap().restore(&snapshot),
+            Err(TweeningError::UnsupportedSnapshot)
+        ));
+        env2.anim_mut()
+            .unwrap()
+            .tweenable_mut::<Tween>()
+            .unwrap()
+            .retarget::<Transform, TransformPositionLens>(Vec3::ZERO)
+            .unwrap();
+        env2.anim_mut().unwrap().restore(&snapshot).unwrap();
+        env2.step_all(Duration::from_millis(200));
+        assert_approx_eq!(env2.component().translation, expected);
+    }
+
+    #[test]
+    fn restore_mismatch() {
+        let mut env = TestEnv::<Transform>::new(make_tween(1.).then(make_tween(2.)));
+        env.step_all(Duration::from_millis(1500));
+        let snapshot = env.anim().unwrap().snapshot();
+
+        let mut anim = TweenAnim::new(make_tween(1.));
+        assert!(matches!(
+            anim.restore(&snapshot),
+            Err(TweeningError::SnapshotMismatch)
+        ));
+        assert_eq!(anim.tweenable().elapsed(), Duration::ZERO);
+
+        let mut anim = TweenAnim::new(make_tween(1.).then(Delay::new(Duration::from_secs(1))));
+        assert!(matches!(
+            anim.restore(&snapshot),
+            Err(TweeningError::SnapshotMismatch)
+        ));
+    }
+}
@@ -0,0 +1,274 @@
+use std::time::Duration;
+
+use bevy::{ecs::component::Mutable, prelude::*};
+
+use crate::{AnimTarget, Delay, EaseMethod, IntoBoxedTweenable, TweenAnim, Tweenable};
+
+/// Origin of a [`Stagger`], from which the start offsets increase.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StaggerFrom {
+    /// The first target starts immediately, and the last one starts last.
+    #[default]
+    Start,
+    /// The last target starts immediately, and the first one starts last.
+    End,
+    /// The target(s) closest to the middle start first, and the first and last
+    /// ones start last. The offsets are proportional to the distance to the
+    /// middle of the collection, so with an odd number of targets the middle
+    /// one starts immediately, whereas with an even number of targets the two
+    /// middle ones start together after a short offset.
+    Center,
+}
+
+/// Builder to spawn the same animation on many targets, offset in time.
+///
+/// A stagger spawns one animation per target entity, each delayed by a start
+/// offset which depends on the index of the target in the input collection.
+/// This is typically used to animate the items of a menu or a list one after
+/// the other.
+///
+/// The start offsets are spread over the total stagger duration, which is the
+/// time between the start of the first animation and the start of the last
+/// one. The distribution of the offsets is controlled by the [`StaggerFrom`]
+/// origin and by an optional ease method applied to the normalized offsets.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// fn setup(mut commands: Commands, q_items: Query<Entity, With<Node>>) {
+///     // Scale each item up, starting 100 ms after the previous one
+///     let items: Vec<Entity> = q_items.iter().collect();
+///     let duration = Duration::from_millis(100) * items.len().saturating_sub(1) as u32;
+///     Stagger::new(duration).spawn::<Transform, _>(&mut commands, items, |_index, _entity| {
+///         Tween::new(
+///             EaseFunction::QuadraticOut,
+///             Duration::from_secs(1),
+///             TransformScaleLens {
+///                 start: Vec3::ZERO,
+///                 end: Vec3::ONE,
+///             },
+///         )
+///     });
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stagger {
+    duration: Duration,
+    origin: StaggerFrom,
+    ease_method: EaseMethod,
+}
+
+impl Stagger {
+    /// Create a new stagger with the given total duration.
+    ///
+    /// The total duration is the time between the start of the first animation
+    /// and the start of the last one. The offsets are by default distributed
+    /// linearly from the first target.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            origin: StaggerFrom::Start,
+            ease_method: EaseMethod::default(),
+        }
+    }
+
+    /// Set the origin of the stagger.
+    #[must_use]
+    pub fn with_origin(mut self, origin: StaggerFrom) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Set the ease method applied to the normalized start offsets.
+    ///
+    /// With the default linear ease, the offsets are evenly spaced. Other ease
+    /// methods can be used for example to make the animations start closer to
+    /// each other at the end of the stagger.
+    #[must_use]
+    pub fn with_ease(mut self, ease_method: impl Into<EaseMethod>) -> Self {
+        self.ease_method = ease_method.into();
+        self
+    }
+
+    /// Get the total duration of the stagger.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Get the origin of the stagger.
+    #[must_use]
+    pub fn origin(&self) -> StaggerFrom {
+        self.origin
+    }
+
+    /// Get the ease method applied to the normalized start offsets.
+    #[must_use]
+    pub fn ease_method(&self) -> &EaseMethod {
+        &self.ease_method
+    }
+
+    /// Get the start offset of the target with the given index, in a collection
+    /// of `count` targets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `count`.
+    #[must_use]
+    pub fn offset(&self, index: usize, count: usize) -> Duration {
+        assert!(index < count);
+        if count == 1 {
+            return Duration::ZERO;
+        }
+        let last = (count - 1) as f32;
+        let index = index as f32;
+        let ratio = match self.origin {
+            StaggerFrom::Start => index / last,
+            StaggerFrom::End => (last - index) / last,
+            StaggerFrom::Center => {
+                let half = last / 2.;
+                (index - half).abs() / half
+            }
+        };
+        let ratio = self.ease_method.sample(ratio.clamp(0., 1.));
+        self.duration.mul_f32(ratio.max(0.))
+    }
+
+    /// Spawn the staggered animations.
+    ///
+    /// For each target entity, this calls the `make_tweenable` factory with the
+    /// index of that entity in `targets` and the entity itself, and spawns a new
+    /// entity with a [`TweenAnim`] playing the returned tweenable, and an
+    /// [`AnimTarget`] referencing the component `C` on the target entity. The
+    /// tweenable is preceded by a [`Delay`] equal to the start offset of that
+    /// target, unless that offset is zero.
+    ///
+    /// The animation entities are spawned without a parent. To despawn them
+    /// together with another entity, like the container of the targets, add
+    /// them as children of that entity.
+    ///
+    /// # Returns
+    ///
+    /// Returns the entities owning the [`TweenAnim`] components, in the same
+    /// order as `targets`.
+    pub fn spawn<C, T>(
+        &self,
+        commands: &mut Commands,
+        targets: impl IntoIterator<Item = Entity>,
+        mut make_tweenable: impl FnMut(usize, Entity) -> T,
+    ) -> Vec<Entity>
+    where
+        C: Component<Mutability = Mutable>,
+        T: Tweenable + 'static,
+    {
+        let targets: Vec<Entity> = targets.into_iter().collect();
+        let count = targets.len();
+        targets
+            .into_iter()
+            .enumerate()
+            .map(|(index, target)| {
+                let tweenable = make_tweenable(index, target);
+                let offset = self.offset(index, count);
+                let tweenable = if offset.is_zero() {
+                    tweenable.into_boxed()
+                } else {
+                    Delay::new(offset).then(tweenable).into_boxed()
+                };
+                commands
+                    .spawn((
+                        TweenAnim::new(tweenable),
+                        AnimTarget::component::<C>(target),
+                    ))
+                    .id()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::world::CommandQueue;
+
+    use super::*;
+    use crate::{lens::TransformPositionLens, test_utils::*, Tween};
+
+    #[test]
+    fn offsets() {
+        let stagger = Stagger::new(Duration::from_secs(1));
+        assert_eq!(stagger.origin(), StaggerFrom::Start);
+        assert_eq!(stagger.offset(0, 1), Duration::ZERO);
+        let offsets: Vec<_> = (0..5).map(|i| stagger.offset(i, 5)).collect();
+        assert_eq!(offsets, [0, 250, 500, 750, 1000].map(Duration::from_millis));
+
+        let stagger = stagger.with_origin(StaggerFrom::End);
+        let offsets: Vec<_> = (0..5).map(|i| stagger.offset(i, 5)).collect();
+        assert_eq!(offsets, [1000, 750, 500, 250, 0].map(Duration::from_millis));
+
+        let stagger = stagger.with_origin(StaggerFrom::Center);
+        let offsets: Vec<_> = (0..5).map(|i| stagger.offset(i, 5)).collect();
+        assert_eq!(
+            offsets,
+            [1000, 500, 0, 500, 1000].map(Duration::from_millis)
+        );
+        let offsets: Vec<_> = (0..4).map(|i| stagger.offset(i, 4)).collect();
+        assert_eq!(offsets[0], offsets[3]);
+        assert_approx_eq!(offsets[1].as_secs_f32(), 1. / 3.);
+        assert_eq!(offsets[1], offsets[2]);
+
+        let stagger = Stagger::new(Duration::from_secs(1)).with_ease(EaseFunction::QuadraticIn);
+        let offsets: Vec<_> = (0..3).map(|i| stagger.offset(i, 3)).collect();
+        assert_eq!(offsets, [0, 250, 1000].map(Duration::from_millis));
+    }
+
+    #[test]
+    fn spawn() {
+        let mut env = TestEnv::<Transform>::empty();
+        let targets: Vec<Entity> = (0..3)
+            .map(|_| env.world.spawn(Transform::default()).id())
+            .collect();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &env.world);
+        let anims = Stagger::new(Duration::from_secs(2)).spawn::<Transform, _>(
+            &mut commands,
+            targets.iter().copied(),
+            |index, _| {
+                Tween::new(
+                    EaseFunction::Linear,
+                    Duration::from_secs(1),
+                    TransformPositionLens {
+                        start: Vec3::ZERO,
+                        end: Vec3::splat(index as f32 + 1.),
+                    },
+                )
+            },
+        );
+        queue.apply(&mut env.world);
+        assert_eq!(anims.len(), 3);
+
+        // Each anim targets the entity with the same index, with a leading delay
+        for (index, (anim_entity, target)) in anims.iter().zip(&targets).enumerate() {
+            let anim_target = env.world.get::<AnimTarget>(*anim_entity).unwrap();
+            assert_eq!(
+                anim_target.kind,
+                crate::AnimTargetKind::Component { entity: *target }
+            );
+            let anim = env.world.get::<TweenAnim>(*anim_entity).unwrap();
+            assert_eq!(
+                anim.tweenable().total_duration(),
+                crate::TotalDuration::Finite(Duration::from_secs(1 + index as u64))
+            );
+        }
+
+        env.step_all(Duration::from_millis(1500));
+        let positions: Vec<Vec3> = targets
+            .iter()
+            .map(|entity| env.world.get::<Transform>(*entity).unwrap().translation)
+            .collect();
+        assert_approx_eq!(positions[0], Vec3::ONE);
+        assert_approx_eq!(positions[1], Vec3::splat(1.));
+        assert_approx_eq!(positions[2], Vec3::ZERO);
+    }
+}
@@ -117,7 +117,10 @@ macro_rules! assert_approx_eq {
 
 pub(crate) use assert_approx_eq;
 
-use crate::{AnimCompletedEvent, CycleCompletedEvent, TweenAnim, TweenResolver, Tweenable};
+use crate::{
+    AnimCompletedEvent, CycleCompletedEvent, MarkerEvent, TweenAnim, TweenBlender, TweenResolver,
+    Tweenable,
+};
 
 /// A simple isolated test environment with a [`World`] and a single
 /// [`Entity`] in it.
@@ -136,7 +139,9 @@ impl<T: Component<Mutability = Mutable> + Default> TestEnv<T> {
         world.init_resource::<Time>();
         world.init_resource::<Messages<CycleCompletedEvent>>();
         world.init_resource::<Messages<AnimCompletedEvent>>();
+        world.init_resource::<Messages<MarkerEvent>>();
         world.init_resource::<TweenResolver>();
+        world.init_resource::<TweenBlender>();
 
         let mut system = IntoSystem::into_system(crate::plugin::animator_system);
         system.initialize(&mut world);
@@ -156,7 +161,9 @@ impl<T: Component<Mutability = Mutable> + Default> TestEnv<T> {
         world.init_resource::<Time>();
         world.init_resource::<Messages<CycleCompletedEvent>>();
         world.init_resource::<Messages<AnimCompletedEvent>>();
+        world.init_resource::<Messages<MarkerEvent>>();
         world.init_resource::<TweenResolver>();
+        world.init_resource::<TweenBlender>();
 
         let mut system = IntoSystem::into_system(crate::plugin::animator_system);
         system.initialize(&mut world);
@@ -194,6 +201,8 @@ impl<T: Component<Mutability = Mutable>> TestEnv<T> {
         events.update();
         let mut events = self.world.resource_mut::<Messages<AnimCompletedEvent>>();
         events.update();
+        let mut events = self.world.resource_mut::<Messages<MarkerEvent>>();
+        events.update();
     }
 
     /// Get the animation.
@@ -1,8 +1,26 @@
-use std::{any::TypeId, cmp::Ordering, time::Duration};
-
-use bevy::{ecs::change_detection::MutUntyped, prelude::*};
-
-use crate::{AnimTargetKind, EaseMethod, Lens, PlaybackDirection, RepeatCount, RepeatStrategy};
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    cmp::Ordering,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering as AtomicOrdering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use bevy::{
+    ecs::{change_detection::MutUntyped, message::MessageCursor},
+    math::VectorSpace,
+    prelude::*,
+};
+
+use crate::{
+    lens::{CaptureStart, LinearLens, ReflectValue},
+    AnimTargetKind, EaseMethod, Lens, LensSnapshot, PlaybackDirection, RepeatCount, RepeatStrategy,
+    SimulationSnapshot, TweenableSnapshot, TweeningError,
+};
 
 /// The dynamic tweenable type.
 ///
@@ -79,7 +97,9 @@ impl<T: Tweenable + 'static> IntoBoxedTweenable for T {
 /// This is returned by [`Tweenable::step()`] to allow the caller to execute
 /// some logic based on the updated state of the tweenable, like advanding a
 /// sequence to its next child tweenable.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TweenState {
     /// The tweenable is still active, and did not reach its end state yet.
     Active,
@@ -342,7 +362,8 @@ impl AnimClock {
 /// Possibly infinite duration of an animation.
 ///
 /// Used to measure the total duration of an animation including any looping.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
 pub enum TotalDuration {
     /// The duration is finite, of the given value.
     Finite(Duration),
@@ -425,9 +446,120 @@ impl Ord for TotalDuration {
     }
 }
 
+/// Named point in time on the timeline of a tweenable.
+///
+/// Markers are added to a tweenable with builder functions like
+/// [`Tween::with_marker()`]. When the playback of a [`TweenAnim`] crosses a
+/// marker, in either direction, a [`MarkerEvent`] is raised with the name of
+/// that marker. Markers can also be used as seek targets with
+/// [`TweenAnim::seek_to_marker()`].
+///
+/// [`TweenAnim`]: crate::TweenAnim
+/// [`MarkerEvent`]: crate::MarkerEvent
+/// [`TweenAnim::seek_to_marker()`]: crate::TweenAnim::seek_to_marker
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Marker {
+    /// Name of the marker. Several markers can share the same name.
+    pub name: Cow<'static, str>,
+    /// Time of the marker, relative to the start of the tweenable it's
+    /// attached to.
+    pub time: Duration,
+}
+
+impl Marker {
+    /// Create a new marker.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>, time: Duration) -> Self {
+        Self {
+            name: name.into(),
+            time,
+        }
+    }
+
+    /// Check if moving from `from` to `to` crosses the given time.
+    ///
+    /// See [`Tweenable::visit_markers()`] for details.
+    fn is_crossed(time: Duration, from: Duration, to: Duration, include_from: bool) -> bool {
+        let past_from = if from <= to {
+            from < time && time <= to
+        } else {
+            to <= time && time < from
+        };
+        past_from || (include_from && time == from)
+    }
+}
+
+/// Unwrap the elapsed times of a step of an infinitely repeating animation,
+/// whose elapsed time loops around after `period`, so that moving from `from`
+/// to `to` is monotonic in the given direction.
+fn unwrap_elapsed(
+    from: Duration,
+    to: Duration,
+    direction: PlaybackDirection,
+    period: Duration,
+) -> (Duration, Duration) {
+    match direction {
+        PlaybackDirection::Forward if to < from => (from, to + period),
+        PlaybackDirection::Backward if to > from => (from + period, to),
+        _ => (from, to),
+    }
+}
+
+/// Visit the markers of a child tweenable starting at `start` on the timeline of
+/// its parent, over the portion of a step from `from` to `to` overlapping it.
+fn visit_child_markers(
+    child: &dyn Tweenable,
+    start: Duration,
+    from: Duration,
+    to: Duration,
+    include_from: bool,
+    direction: PlaybackDirection,
+    visitor: &mut dyn FnMut(&Marker),
+) {
+    let end = child
+        .total_duration()
+        .as_finite()
+        .map(|duration| start + duration);
+    if from.max(to) < start || end.is_some_and(|end| from.min(to) > end) {
+        return;
+    }
+    let local_elapsed = |elapsed: Duration| {
+        let local_elapsed = elapsed.max(start) - start;
+        match end {
+            Some(end) => local_elapsed.min(end - start),
+            None => local_elapsed,
+        }
+    };
+    // If the step enters the child from outside, the endpoint it enters through
+    // is crossed.
+    let outside = from < start || end.is_some_and(|end| from > end);
+    child.visit_markers(
+        local_elapsed(from),
+        local_elapsed(to),
+        include_from || outside,
+        direction,
+        visitor,
+    );
+}
+
+/// Get the range of cycle indices overlapping a step from `from` to `to`.
+///
+/// A step starting or ending exactly at the start of a cycle also overlaps the
+/// end of the previous cycle.
+fn overlapping_cycles(
+    from: Duration,
+    to: Duration,
+    cycle_duration: Duration,
+) -> std::ops::RangeInclusive<u128> {
+    let cycle_duration = cycle_duration.as_nanos().max(1);
+    let first = from.min(to).as_nanos().saturating_sub(1) / cycle_duration;
+    let last = from.max(to).as_nanos() / cycle_duration;
+    first..=last
+}
+
 /// Tweening animation description, either a single [`Tween`] or a collection of
 /// them.
-pub trait Tweenable: Send + Sync {
+pub trait Tweenable: Any + Send + Sync {
     /// Get the duration of a single cycle of the animation.
     ///
     /// Note that for [`RepeatStrategy::MirroredRepeat`], this is the duration
@@ -521,6 +653,131 @@ pub trait Tweenable: Send + Sync {
     /// repeating.
     fn rewind(&mut self);
 
+    /// Set the playback direction of the animation.
+    ///
+    /// The playback direction controls whether the elapsed time moves forward
+    /// or backward when the tweenable is stepped. Composite tweenables like
+    /// [`Sequence`] forward the direction to all their children, so that the
+    /// entire animation plays in reverse, from its last child to its first
+    /// one.
+    ///
+    /// Changing the direction doesn't change any target state, nor the elapsed
+    /// time of the animation. Only the direction of playback from this moment
+    /// potentially changes.
+    ///
+    /// The default implementation ignores the direction, for tweenables which
+    /// only support forward playback.
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        let _ = direction;
+    }
+
+    /// Get the current playback direction of the animation.
+    ///
+    /// The default implementation always returns
+    /// [`PlaybackDirection::Forward`].
+    #[must_use]
+    fn playback_direction(&self) -> PlaybackDirection {
+        PlaybackDirection::Forward
+    }
+
+    /// Visit the markers crossed by moving the elapsed time from `from` to
+    /// `to`.
+    ///
+    /// This calls `visitor` for each marker of this tweenable and of all its
+    /// children crossed when the elapsed time moves from `from` to `to`. A
+    /// marker exactly at `to` is crossed, whereas a marker exactly at `from` is
+    /// only crossed if `include_from` is `true`. This ensures a marker reached
+    /// exactly at the end of a step is not crossed again at the start of the
+    /// next one. For infinitely repeating animations, whose [`elapsed()`] time
+    /// loops around, `to` can be on the other side of `from` compared to the
+    /// playback `direction`.
+    ///
+    /// This is called by [`TweenAnim`] after each step to raise a
+    /// [`MarkerEvent`] for each crossed marker. The default implementation
+    /// doesn't visit anything, for tweenables without markers.
+    ///
+    /// [`elapsed()`]: Self::elapsed
+    /// [`TweenAnim`]: crate::TweenAnim
+    /// [`MarkerEvent`]: crate::MarkerEvent
+    fn visit_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        include_from: bool,
+        direction: PlaybackDirection,
+        visitor: &mut dyn FnMut(&Marker),
+    ) {
+        let _ = (from, to, include_from, direction, visitor);
+    }
+
+    /// Get the elapsed time of the first marker with the given name.
+    ///
+    /// This searches the markers of this tweenable, then those of its children
+    /// in order, and returns the elapsed time to pass to [`set_elapsed()`] to
+    /// seek to the marker, if found. The default implementation returns
+    /// `None`, for tweenables without markers.
+    ///
+    /// [`set_elapsed()`]: Self::set_elapsed
+    #[must_use]
+    fn marker_time(&self, name: &str) -> Option<Duration> {
+        let _ = name;
+        None
+    }
+
+    /// Poll the runtime conditions this tweenable waits on.
+    ///
+    /// This is called by [`TweenAnim`] before each step, with read-only access
+    /// to the ECS `World`, to let a [`WaitUntil`] evaluate its condition.
+    /// Composite tweenables forward the call to their active children only, so
+    /// that a condition is only evaluated once its [`WaitUntil`] is reached. The
+    /// default implementation does nothing, for tweenables without any runtime
+    /// condition.
+    ///
+    /// [`TweenAnim`]: crate::TweenAnim
+    fn poll_conditions(&self, world: &World) {
+        let _ = world;
+    }
+
+    /// Save the playback state of this tweenable and all its children.
+    ///
+    /// This is called by [`TweenAnim::snapshot()`] to save the progress of an
+    /// animation. Composite tweenables save the state of their children too.
+    /// The default implementation saves the elapsed time and the playback
+    /// direction, for tweenables whose state is entirely restored by
+    /// [`set_playback_direction()`] and [`set_elapsed()`]. Implementations
+    /// identify their type in the snapshot with a fixed kind string, like
+    /// `"Sequence"`; see [`TweenableSnapshot::new()`].
+    ///
+    /// [`TweenAnim::snapshot()`]: crate::TweenAnim::snapshot
+    /// [`set_playback_direction()`]: Self::set_playback_direction
+    /// [`set_elapsed()`]: Self::set_elapsed
+    #[must_use]
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Tweenable", self.elapsed(), self.playback_direction())
+    }
+
+    /// Restore the playback state saved by [`save_state()`].
+    ///
+    /// This is called by [`TweenAnim::restore()`] with a snapshot taken from an
+    /// identical tweenable. Like [`set_elapsed()`], this doesn't update the
+    /// target until the tweenable is stepped again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::SnapshotMismatch`] if the snapshot doesn't
+    /// match the structure of the tweenable, for example if it doesn't have the
+    /// same number of children.
+    ///
+    /// [`save_state()`]: Self::save_state
+    /// [`TweenAnim::restore()`]: crate::TweenAnim::restore
+    /// [`set_elapsed()`]: Self::set_elapsed
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(0)?;
+        self.set_playback_direction(snapshot.direction);
+        self.set_elapsed(snapshot.elapsed);
+        Ok(())
+    }
+
     /// Get the number of cycles completed.
     ///
     /// For repeating animations, this returns the number of times a single
@@ -550,13 +807,28 @@ pub trait Tweenable: Send + Sync {
     /// # Returns
     ///
     /// Returns the type of the target, if any, or `None` if this tweenable is
-    /// untyped. Typically only [`Delay`] is untyped, as this is the only
-    /// tweenable which doesn't actually mutate the target, so it doesn't
-    /// actually have any target type associated with it.
+    /// untyped. Typically only [`Delay`] and [`WaitUntil`] are untyped, as
+    /// those are the only tweenables which don't actually mutate the target,
+    /// so they don't actually have any target type associated with them.
     ///
     /// [`TweenAnim`]: crate::TweenAnim
     #[must_use]
     fn target_type_id(&self) -> Option<TypeId>;
+
+    /// Visit all the target types this tweenable may animate during playback.
+    ///
+    /// Unlike [`target_type_id()`] which only returns the type of the target
+    /// currently animated, this calls `visitor` for the target type of every
+    /// typed child, for example each tweenable of a multi-target [`Sequence`].
+    /// A type may be visited more than once. The default implementation visits
+    /// the type returned by [`target_type_id()`], if any.
+    ///
+    /// [`target_type_id()`]: Self::target_type_id
+    fn visit_target_types(&self, visitor: &mut dyn FnMut(TypeId)) {
+        if let Some(type_id) = self.target_type_id() {
+            visitor(type_id);
+        }
+    }
 }
 
 macro_rules! impl_boxed {
@@ -571,15 +843,247 @@ macro_rules! impl_boxed {
 
 impl_boxed!(Tween);
 impl_boxed!(Sequence);
+impl_boxed!(Parallel);
+impl_boxed!(Timeline);
 impl_boxed!(Delay);
+impl_boxed!(WaitUntil);
+impl_boxed!(Spring);
+impl_boxed!(Decay);
+
+/// Check that all the target types of a tweenable match the target type of
+/// the collection it's inserted into, and return the common target type.
+///
+/// # Panics
+///
+/// Panics if the tweenable animates a target type different from
+/// `target_type_id`, or animates several target types, like a multi-target
+/// [`Sequence`].
+fn check_target_types(
+    mut target_type_id: Option<TypeId>,
+    tween: &dyn Tweenable,
+    collection: &str,
+) -> Option<TypeId> {
+    tween.visit_target_types(&mut |type_id| {
+        assert!(
+            target_type_id.is_none() || target_type_id == Some(type_id),
+            "Cannot use tweenable animations with different targets inside the same {collection}. Create separate animations for each target."
+        );
+        target_type_id = Some(type_id);
+    });
+    target_type_id
+}
+
+type AnyLens = dyn Any + Send + Sync + 'static;
+
+/// A type-erased lens, applied to an untyped target.
+///
+/// The lens is kept as [`Any`] so that it can be accessed again with its
+/// concrete type, for example to retarget it.
+struct LensAction {
+    lens: Box<AnyLens>,
+    apply: fn(&mut AnyLens, MutUntyped, f32),
+    /// Pending capture of the lens start value, consumed on first apply.
+    capture: Option<fn(&mut AnyLens, &MutUntyped)>,
+    /// Save the state of the lens derived at runtime, if any.
+    save: fn(&AnyLens) -> Option<LensSnapshot>,
+    /// Restore the state saved by `save`, returning `false` if not supported.
+    restore: fn(&mut AnyLens, &LensSnapshot) -> bool,
+}
+
+impl LensAction {
+    /// Erase a lens applied to a target of type `T`.
+    fn new<T, L>(lens: L) -> Self
+    where
+        T: 'static,
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        Self {
+            lens: Box::new(lens),
+            apply: |lens: &mut AnyLens, ptr: MutUntyped, ratio: f32| {
+                let lens = lens.downcast_mut::<L>().unwrap();
+                // SAFETY: ptr was obtained from the same type, via the type_id saved by the
+                // caller alongside the action.
+                #[allow(unsafe_code)]
+                let target = unsafe { ptr.with_type::<T>() };
+                lens.lerp(target, ratio);
+            },
+            capture: None,
+            save: |_| None,
+            restore: |_, _| false,
+        }
+    }
+
+    /// Erase a lens applied to a target of type `T`, which captures its start
+    /// value from the target the first time it's applied.
+    fn new_capturing<T, L>(lens: L) -> Self
+    where
+        T: 'static,
+        L: CaptureStart<T> + Send + Sync + 'static,
+    {
+        Self {
+            capture: Some(|lens: &mut AnyLens, ptr: &MutUntyped| {
+                let lens = lens.downcast_mut::<L>().unwrap();
+                // SAFETY: ptr was obtained from the same type, via the type_id saved by the
+                // caller alongside the action.
+                #[allow(unsafe_code)]
+                let target = unsafe { ptr.as_ref().deref::<T>() };
+                lens.capture_start(target);
+            }),
+            save: |lens: &AnyLens| {
+                let lens = lens.downcast_ref::<L>().unwrap();
+                Some(
+                    lens.saved_start()
+                        .map_or(LensSnapshot::Unsupported, LensSnapshot::Captured),
+                )
+            },
+            restore: |lens: &mut AnyLens, snapshot: &LensSnapshot| {
+                let LensSnapshot::Captured(start) = snapshot else {
+                    return false;
+                };
+                lens.downcast_mut::<L>().unwrap().restore_start(start)
+            },
+            ..Self::new::<T, L>(lens)
+        }
+    }
+
+    /// Apply the lens to the given target.
+    fn apply(&mut self, target: MutUntyped, ratio: f32) {
+        if let Some(capture) = self.capture.take() {
+            capture(self.lens.as_mut(), &target);
+        }
+        (self.apply)(self.lens.as_mut(), target, ratio);
+    }
+
+    /// Save the state of the lens derived at runtime, if any.
+    fn save(&self) -> Option<LensSnapshot> {
+        // Until the start value is captured, the lens is in its initial state
+        if self.capture.is_some() {
+            return None;
+        }
+        (self.save)(self.lens.as_ref())
+    }
+
+    /// Restore the state of the lens saved by [`save()`].
+    ///
+    /// Returns `false` if the lens doesn't support restoring that state.
+    ///
+    /// [`save()`]: Self::save
+    fn restore(&mut self, snapshot: &LensSnapshot) -> bool {
+        if !(self.restore)(self.lens.as_mut(), snapshot) {
+            return false;
+        }
+        // The start value is restored, so must not be captured again
+        self.capture = None;
+        true
+    }
+
+    /// Get the lens, if it's of type `L`.
+    fn lens<L: 'static>(&self) -> Option<&L> {
+        self.lens.downcast_ref::<L>()
+    }
+
+    /// Get the lens mutably, if it's of type `L`.
+    fn lens_mut<L: 'static>(&mut self) -> Option<&mut L> {
+        self.lens.downcast_mut::<L>()
+    }
+}
+
+/// Lens playing a cubic Hermite segment, used by [`Tween::retarget()`].
+///
+/// The segment starts at a given value with a given tangent, and ends at the
+/// end value of the inner lens with a zero tangent. It's applied by moving the
+/// endpoints of the inner lens so that its linear interpolation produces the
+/// curve.
+struct RetargetLens<T, L: LinearLens<T>> {
+    lens: L,
+    start: L::Value,
+    /// Tangent at the start of the segment, in value per cycle.
+    tangent: L::Value,
+    end: L::Value,
+    /// Pending capture of the start value of the inner lens, if the tween was
+    /// retargeted before it was ever applied. The captured value becomes the
+    /// start of the segment.
+    capture: Option<fn(&mut AnyLens, &MutUntyped)>,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<T, L: LinearLens<T>> RetargetLens<T, L> {
+    /// Sample the segment value and its derivative at the given cycle fraction.
+    fn sample(&self, fraction: f32) -> (L::Value, L::Value) {
+        let t = fraction;
+        let delta = self.end - self.start;
+        // Hermite basis functions for the start tangent and end value
+        let h10 = t * (t * (t - 2.) + 1.);
+        let h01 = t * t * (3. - 2. * t);
+        let dh10 = t * (3. * t - 4.) + 1.;
+        let dh01 = 6. * t * (1. - t);
+        let value = self.start + self.tangent * h10 + delta * h01;
+        let derivative = self.tangent * dh10 + delta * dh01;
+        (value, derivative)
+    }
+}
+
+impl<T: 'static, L: LinearLens<T> + 'static> RetargetLens<T, L> {
+    /// Save the segment, if the type of its values is supported.
+    fn save(&self) -> LensSnapshot {
+        match (
+            ReflectValue::from_any(&self.start),
+            ReflectValue::from_any(&self.tangent),
+            ReflectValue::from_any(&self.end),
+        ) {
+            (Some(start), Some(tangent), Some(end)) => LensSnapshot::Retargeted {
+                start,
+                tangent,
+                end,
+            },
+            _ => LensSnapshot::Unsupported,
+        }
+    }
+
+    /// Restore the segment saved by [`save()`].
+    ///
+    /// [`save()`]: Self::save
+    fn restore(&mut self, snapshot: &LensSnapshot) -> bool {
+        let LensSnapshot::Retargeted {
+            start,
+            tangent,
+            end,
+        } = snapshot
+        else {
+            return false;
+        };
+        let (Some(start), Some(tangent), Some(end)) =
+            (start.downcast(), tangent.downcast(), end.downcast())
+        else {
+            return false;
+        };
+        self.start = start;
+        self.tangent = tangent;
+        self.end = end;
+        self.capture = None;
+        true
+    }
+}
 
-type TargetAction = dyn FnMut(MutUntyped, f32) + Send + Sync + 'static;
+impl<T, L: LinearLens<T>> Lens<T> for RetargetLens<T, L> {
+    fn lerp(&mut self, target: Mut<'_, T>, ratio: f32) {
+        // Offset the endpoints of the inner lens so that interpolating them by
+        // h01 produces the segment value.
+        let t = ratio;
+        let h10 = t * (t * (t - 2.) + 1.);
+        let h01 = t * t * (3. - 2. * t);
+        let base = self.start + self.tangent * h10;
+        self.lens
+            .set_endpoints(base, base + (self.end - self.start));
+        self.lens.lerp(target, h01);
+    }
+}
 
 /// Configuration to create a [`Tween`].
 ///
 /// This is largely an internal type, only exposed due to other constraints.
 #[doc(hidden)]
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Clone)]
 pub struct TweenConfig {
     /// Ease method.
     pub ease_method: EaseMethod,
@@ -677,7 +1181,24 @@ pub struct TweenConfig {
 /// - At the end of all cycles, when the animation itself completes, the tween
 ///   emits an [`AnimCompletedEvent`]. This event is always emitted.
 ///
+/// # Snapshots
+///
+/// Most of the state of a tween is restored from its elapsed time and playback
+/// direction. A tween created with [`from_current()`] also saves the start
+/// value it captured from its target, and a tween retargeted with
+/// [`retarget()`] saves the segment it plays, as a [`LensSnapshot`]. This
+/// requires values of a type supported by [`ReflectValue`], and for a captured
+/// start value a lens implementing [`CaptureStart::saved_start()`], like all
+/// the built-in lenses. Because the segment of a retargeted tween depends on
+/// the type of its lens, restoring it requires retargeting the restored tween
+/// first, with the same lens type. Otherwise, restoring returns
+/// [`TweeningError::UnsupportedSnapshot`].
+///
 /// [`TweenAnim`]: crate::TweenAnim
+/// [`from_current()`]: Self::from_current
+/// [`retarget()`]: Self::retarget
+/// [`ReflectValue`]: crate::lens::ReflectValue
+/// [`CaptureStart::saved_start()`]: crate::lens::CaptureStart::saved_start
 /// [`with_cycle_completed_event()`]: Self::with_cycle_completed_event
 /// [`set_cycle_completed_event()`]: Self::set_cycle_completed_event
 /// [`AnimCompletedEvent`]: crate::AnimCompletedEvent
@@ -686,10 +1207,12 @@ pub struct Tween {
     clock: AnimClock,
     /// Direction of playback the user asked for.
     playback_direction: PlaybackDirection,
-    action: Box<TargetAction>,
+    action: LensAction,
     send_cycle_completed_event: bool,
     /// Type ID of the target.
     type_id: TypeId,
+    /// Markers, relative to the start of a cycle.
+    markers: Vec<Marker>,
 }
 
 impl Tween {
@@ -718,55 +1241,124 @@ impl Tween {
     /// ```
     #[inline]
     #[must_use]
-    pub fn new<T, L>(
-        ease_method: impl Into<EaseMethod>,
-        cycle_duration: Duration,
-        mut lens: L,
-    ) -> Self
+    pub fn new<T, L>(ease_method: impl Into<EaseMethod>, cycle_duration: Duration, lens: L) -> Self
     where
         T: 'static,
         L: Lens<T> + Send + Sync + 'static,
     {
-        let action = move |ptr: MutUntyped, ratio: f32| {
-            // SAFETY: ptr was obtained from the same type, via the type_id saved below.
-            #[allow(unsafe_code)]
-            let target = unsafe { ptr.with_type::<T>() };
-            lens.lerp(target, ratio);
-        };
         Self {
             ease_method: ease_method.into(),
             clock: AnimClock::new(cycle_duration),
             playback_direction: PlaybackDirection::Forward,
-            action: Box::new(action),
+            action: LensAction::new(lens),
             send_cycle_completed_event: false,
             type_id: TypeId::of::<T>(),
+            markers: vec![],
         }
     }
 
     #[inline]
     #[must_use]
-    pub(crate) fn from_config<T, L>(config: TweenConfig, mut lens: L) -> Self
+    pub(crate) fn from_config<T, L>(config: TweenConfig, lens: L) -> Self
     where
         T: 'static,
         L: Lens<T> + Send + Sync + 'static,
     {
-        let action = move |ptr: MutUntyped, ratio: f32| {
-            // SAFETY: ptr was obtained from the same type, via the type_id saved below.
-            #[allow(unsafe_code)]
-            let target = unsafe { ptr.with_type::<T>() };
-            lens.lerp(target, ratio);
-        };
         let this = Self {
             ease_method: config.ease_method,
             clock: AnimClock::new(config.cycle_duration),
             playback_direction: config.playback_direction,
-            action: Box::new(action),
+            action: LensAction::new(lens),
             send_cycle_completed_event: config.send_cycle_completed_event,
             type_id: TypeId::of::<T>(),
+            markers: vec![],
         };
         this.with_repeat(config.repeat_count, config.repeat_strategy)
     }
 
+    /// Create a new tween animation applied with a closure.
+    ///
+    /// This is a convenience for [`new()`] with a closure as the lens, which
+    /// allows the compiler to infer the types of the closure arguments. The
+    /// closure receives the target and the eased ratio, and can mutate the
+    /// target in any way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::*;
+    /// # use bevy::prelude::*;
+    /// # use std::time::Duration;
+    /// let tween = Tween::from_fn(
+    ///     EaseFunction::QuadraticInOut,
+    ///     Duration::from_secs(1),
+    ///     |mut transform: Mut<Transform>, ratio| {
+    ///         transform.scale = Vec3::splat(1. + ratio);
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [`new()`]: Self::new
+    #[inline]
+    #[must_use]
+    pub fn from_fn<T, F>(ease_method: impl Into<EaseMethod>, cycle_duration: Duration, f: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(Mut<T>, f32) + Send + Sync + 'static,
+    {
+        Self::new::<T, F>(ease_method, cycle_duration, f)
+    }
+
+    /// Create a new tween animation starting from the current value of its
+    /// target.
+    ///
+    /// This is similar to [`new()`], but the start value of the lens is
+    /// ignored. Instead, it's captured from the target itself the first time
+    /// the animation is applied to it, via [`CaptureStart::capture_start()`].
+    /// This works for any target kind, whether a component, a resource, or an
+    /// asset. The start value is captured only once; rewinding the tween
+    /// doesn't capture it again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::{Vec3, curve::EaseFunction};
+    /// # use std::time::Duration;
+    /// // Move from wherever the entity is, to (3.5, 0, 0).
+    /// let tween = Tween::from_current(
+    ///     EaseFunction::QuadraticInOut,
+    ///     Duration::from_secs(1),
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO, // ignored
+    ///         end: Vec3::new(3.5, 0., 0.),
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [`new()`]: Self::new
+    #[inline]
+    #[must_use]
+    pub fn from_current<T, L>(
+        ease_method: impl Into<EaseMethod>,
+        cycle_duration: Duration,
+        lens: L,
+    ) -> Self
+    where
+        T: 'static,
+        L: CaptureStart<T> + Send + Sync + 'static,
+    {
+        Self {
+            ease_method: ease_method.into(),
+            clock: AnimClock::new(cycle_duration),
+            playback_direction: PlaybackDirection::Forward,
+            action: LensAction::new_capturing::<T, L>(lens),
+            send_cycle_completed_event: false,
+            type_id: TypeId::of::<T>(),
+            markers: vec![],
+        }
+    }
+
     /// Set the number of times to repeat the animation.
     ///
     /// The repeat count determines the number of cycles of the animation. See
@@ -928,6 +1520,46 @@ impl Tween {
         self.playback_direction
     }
 
+    /// Add a named marker to the tween.
+    ///
+    /// The marker time is relative to the start of a cycle, and should be at
+    /// most the [`cycle_duration()`]. For repeating tweens, the marker is
+    /// crossed once per cycle, at the same position along the lens, which for
+    /// mirrored cycles means at the mirrored time in the cycle.
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::math::{Vec3, curve::EaseFunction};
+    /// # use std::time::Duration;
+    /// let duration = Duration::from_secs(1);
+    /// let tween = Tween::new(
+    ///     EaseFunction::QuadraticIn,
+    ///     duration,
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::new(3.5, 0., 0.),
+    ///     },
+    /// )
+    /// .with_marker("footstep", Duration::from_millis(300))
+    /// .with_marker("particles", duration * 4 / 5);
+    /// assert_eq!(tween.marker_time("particles"), Some(Duration::from_millis(800)));
+    /// ```
+    ///
+    /// See [`Marker`] for details.
+    ///
+    /// [`cycle_duration()`]: Tweenable::cycle_duration
+    #[must_use]
+    pub fn with_marker(mut self, name: impl Into<Cow<'static, str>>, time: Duration) -> Self {
+        self.markers.push(Marker::new(name, time));
+        self
+    }
+
+    /// Get the markers of the tween.
+    #[must_use]
+    pub fn markers(&self) -> &[Marker] {
+        &self.markers
+    }
+
     /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with
     /// the two.
     ///
@@ -1001,20 +1633,161 @@ impl Tween {
     pub fn is_cycle_mirrored(&self) -> bool {
         self.clock.is_cycle_mirrored(self.clock.cycle_index())
     }
-}
 
-impl Tweenable for Tween {
-    fn cycle_duration(&self) -> Duration {
-        self.clock.cycle_duration
+    /// Get the lens of the tween, if it's of type `L`.
+    #[must_use]
+    pub fn lens<L: 'static>(&self) -> Option<&L> {
+        self.action.lens::<L>()
     }
 
-    fn total_duration(&self) -> TotalDuration {
-        self.clock.total_duration
+    /// Get mutable access to the lens of the tween, if it's of type `L`.
+    ///
+    /// Changes to the lens take effect the next time the tween is stepped.
+    #[must_use]
+    pub fn lens_mut<L: 'static>(&mut self) -> Option<&mut L> {
+        self.action.lens_mut::<L>()
     }
 
-    fn set_elapsed(&mut self, elapsed: Duration) {
-        self.clock.set_elapsed(elapsed, self.playback_direction);
-    }
+    /// Change the end value of the tween while it's running.
+    ///
+    /// This starts a new segment from the current value of the tween toward
+    /// the new `end` value, and restarts the tween clock. The new segment lasts
+    /// one [`cycle_duration()`], and is a cubic curve whose initial velocity is
+    /// the current velocity of the tween, and which comes to rest at `end`.
+    /// This keeps the animated value and its velocity continuous (C1), so that
+    /// a tween can smoothly follow a moving target by retargeting it as often
+    /// as needed, for example every frame.
+    ///
+    /// The new segment plays a single cycle forward with a linear easing, which
+    /// overrides any repeat count, repeat strategy, playback direction, and
+    /// easing function previously set on the tween. If the tween already
+    /// completed, it starts from its end value at rest. If the tween was
+    /// created with [`from_current()`] and never applied yet, it starts at rest
+    /// from the value captured from the target.
+    ///
+    /// The change takes effect the next time the tween is stepped.
+    ///
+    /// ```
+    /// # use bevy_tweening::{lens::*, *};
+    /// # use bevy::prelude::*;
+    /// # use std::time::Duration;
+    /// let mut tween = Tween::new(
+    ///     EaseFunction::QuadraticInOut,
+    ///     Duration::from_secs(1),
+    ///     TransformPositionLens {
+    ///         start: Vec3::ZERO,
+    ///         end: Vec3::X,
+    ///     },
+    /// );
+    /// tween
+    ///     .retarget::<Transform, TransformPositionLens>(Vec3::Y)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TweeningError::MismatchingLens`] if the lens of the tween is
+    /// not of type `L`. In that case, the tween is unchanged.
+    ///
+    /// [`cycle_duration()`]: Tweenable::cycle_duration
+    /// [`from_current()`]: Self::from_current
+    pub fn retarget<T, L>(&mut self, end: L::Value) -> Result<(), TweeningError>
+    where
+        T: 'static,
+        L: LinearLens<T> + Send + Sync + 'static,
+    {
+        // Current value and derivative relative to the lens ratio
+        let fraction = self.clock.mirrored_cycle_fraction();
+        let ratio = self.ease_method.sample(fraction);
+        let (value, derivative) = if let Some(lens) = self.action.lens::<L>() {
+            let (start, end) = lens.endpoints();
+            (start + (end - start) * ratio, end - start)
+        } else if let Some(lens) = self.action.lens::<RetargetLens<T, L>>() {
+            lens.sample(ratio)
+        } else {
+            return Err(TweeningError::MismatchingLens);
+        };
+
+        // Current velocity, in value per cycle. This uses a numerical derivative
+        // of the easing function, to support any custom function. If the start value
+        // wasn't captured yet, the tween was never applied, so the target is at rest.
+        let capture_pending = self.action.capture.is_some();
+        let tangent = if capture_pending
+            || self.clock.state(self.playback_direction) == TweenState::Completed
+        {
+            L::Value::ZERO
+        } else {
+            const H: f32 = 1e-3;
+            let (x0, x1) = if fraction + H <= 1. {
+                (fraction, fraction + H)
+            } else {
+                (fraction - H, fraction)
+            };
+            let slope = (self.ease_method.sample(x1) - self.ease_method.sample(x0)) / (x1 - x0);
+            let backward = self.playback_direction == PlaybackDirection::Backward;
+            let sign = if self.is_cycle_mirrored() != backward {
+                -1.
+            } else {
+                1.
+            };
+            derivative * (slope * sign)
+        };
+
+        if let Some(lens) = self.action.lens_mut::<RetargetLens<T, L>>() {
+            lens.start = value;
+            lens.tangent = tangent;
+            lens.end = end;
+        } else {
+            let lens = std::mem::replace(&mut self.action.lens, Box::new(()));
+            let lens = *lens.downcast::<L>().unwrap();
+            // Carry over any pending capture, so the segment starts from the current
+            // value of the target instead of the placeholder start value of the lens.
+            let capture = self.action.capture.take();
+            self.action = LensAction::new(RetargetLens {
+                lens,
+                start: value,
+                tangent,
+                end,
+                capture,
+                _phantom: PhantomData,
+            });
+            self.action.save =
+                |lens: &AnyLens| Some(lens.downcast_ref::<RetargetLens<T, L>>().unwrap().save());
+            self.action.restore = |lens: &mut AnyLens, snapshot: &LensSnapshot| {
+                lens.downcast_mut::<RetargetLens<T, L>>()
+                    .unwrap()
+                    .restore(snapshot)
+            };
+            if capture.is_some() {
+                self.action.capture = Some(|lens: &mut AnyLens, ptr: &MutUntyped| {
+                    let lens = lens.downcast_mut::<RetargetLens<T, L>>().unwrap();
+                    if let Some(capture) = lens.capture.take() {
+                        capture(&mut lens.lens, ptr);
+                        lens.start = lens.lens.endpoints().0;
+                    }
+                });
+            }
+        }
+
+        self.ease_method = EaseMethod::EaseFunction(EaseFunction::Linear);
+        self.playback_direction = PlaybackDirection::Forward;
+        self.clock = AnimClock::new(self.clock.cycle_duration);
+        Ok(())
+    }
+}
+
+impl Tweenable for Tween {
+    fn cycle_duration(&self) -> Duration {
+        self.clock.cycle_duration
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        self.clock.total_duration
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.clock.set_elapsed(elapsed, self.playback_direction);
+    }
 
     fn elapsed(&self) -> Duration {
         self.clock.elapsed()
@@ -1045,7 +1818,7 @@ impl Tweenable for Tween {
         // consistent.
         let fraction = self.clock.mirrored_cycle_fraction();
         let fraction = self.ease_method.sample(fraction);
-        (self.action)(target, fraction);
+        self.action.apply(target, fraction);
 
         // If completed at least once this frame, notify the user
         if times_completed != 0 && self.send_cycle_completed_event {
@@ -1059,18 +1832,149 @@ impl Tweenable for Tween {
         self.clock.rewind(self.playback_direction);
     }
 
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.playback_direction = direction;
+    }
+
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
+    }
+
+    fn visit_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        include_from: bool,
+        direction: PlaybackDirection,
+        visitor: &mut dyn FnMut(&Marker),
+    ) {
+        if self.markers.is_empty() || (from == to && !include_from) {
+            return;
+        }
+        let cycle_duration = self.clock.cycle_duration;
+        let mirrored_repeat = self.clock.strategy == RepeatStrategy::MirroredRepeat;
+        let (from, to) = if self.clock.total_duration.is_finite() {
+            (from, to)
+        } else {
+            let period = if mirrored_repeat {
+                cycle_duration * 2
+            } else {
+                cycle_duration
+            };
+            unwrap_elapsed(from, to, direction, period)
+        };
+
+        // Check the markers of each cycle overlapping the step
+        for index in overlapping_cycles(from, to, cycle_duration) {
+            let start = cycle_duration * index as u32;
+            if let TotalDuration::Finite(total_duration) = self.clock.total_duration {
+                if start >= total_duration && index > 0 {
+                    break;
+                }
+            }
+            let mirrored = self.clock.is_cycle_mirrored(index as u32);
+            for marker in &self.markers {
+                let position = if mirrored {
+                    cycle_duration.saturating_sub(marker.time)
+                } else {
+                    marker.time
+                };
+                // With mirroring, the start of a cycle is the same point as the end of the
+                // previous one, so only cross it once.
+                if mirrored_repeat && index > 0 && position.is_zero() {
+                    continue;
+                }
+                if Marker::is_crossed(start + position, from, to, include_from) {
+                    visitor(marker);
+                }
+            }
+        }
+    }
+
+    fn marker_time(&self, name: &str) -> Option<Duration> {
+        self.markers
+            .iter()
+            .find(|marker| marker.name == name)
+            .map(|marker| marker.time)
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Tween", self.elapsed(), self.playback_direction)
+            .with_lens(self.action.save())
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(0)?;
+        if let Some(lens) = &snapshot.lens {
+            if !self.action.restore(lens) {
+                return Err(TweeningError::UnsupportedSnapshot);
+            }
+        }
+        self.set_playback_direction(snapshot.direction);
+        self.set_elapsed(snapshot.elapsed);
+        Ok(())
+    }
+
     fn target_type_id(&self) -> Option<TypeId> {
         Some(self.type_id)
     }
 }
 
 /// A sequence of tweenable animations played in order one after the other.
+///
+/// # Multi-target sequences
+///
+/// The tweenables of a sequence don't need to all animate the same target type.
+/// For example, a sequence can animate a [`Transform`] component, then a
+/// [`Sprite`] component on the same entity. When playback moves from one
+/// tweenable to the next one, and the target type changes, the [`TweenAnim`]
+/// owning the sequence resolves the new target and continues playback with any
+/// time left in the current frame.
+///
+/// Component targets are resolved on the same entity, whether it's the entity
+/// owning the [`TweenAnim`] (implicit target) or the one referenced by the
+/// [`AnimTarget`] component. Resource and asset targets must be explicitly
+/// added to the [`AnimTarget`] with [`AnimTarget::with_resource()`] and
+/// [`AnimTarget::with_asset()`], respectively.
+///
+/// # Backward playback
+///
+/// A sequence can be played backward with [`with_playback_direction()`]. In
+/// that case, the children play in reverse order, from the last one to the
+/// first one, and each child itself plays backward, including any nested
+/// sequence. This allows reusing for example an animation opening a menu to
+/// close it.
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::{Vec3, curve::EaseFunction};
+/// # use std::time::Duration;
+/// # let scale_up = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformScaleLens { start: Vec3::ZERO, end: Vec3::ONE });
+/// # let slide_in = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformPositionLens { start: Vec3::X, end: Vec3::ZERO });
+/// let open = scale_up.then(Delay::new(Duration::from_millis(200))).then(slide_in);
+/// // Play the same sequence backward from its end: slide out, wait, scale down
+/// let mut close = open.with_playback_direction(PlaybackDirection::Backward);
+/// close.rewind();
+/// assert_eq!(close.elapsed(), Duration::from_millis(2200));
+/// ```
+///
+/// [`with_playback_direction()`]: Self::with_playback_direction
+/// [`Sprite`]: https://docs.rs/bevy/0.19/bevy/sprite/struct.Sprite.html
+/// [`TweenAnim`]: crate::TweenAnim
+/// [`AnimTarget`]: crate::AnimTarget
+/// [`AnimTarget::with_resource()`]: crate::AnimTarget::with_resource
+/// [`AnimTarget::with_asset()`]: crate::AnimTarget::with_asset
 pub struct Sequence {
     tweens: Vec<BoxedTweenable>,
     index: usize,
     cycle_duration: TotalDuration,
     total_duration: TotalDuration,
     elapsed: Duration,
+    /// Time left to step the current tweenable with, after the sequence
+    /// requested a retarget.
+    pending_delta: Duration,
+    playback_direction: PlaybackDirection,
+    markers: Vec<Marker>,
 }
 
 impl Sequence {
@@ -1097,6 +2001,9 @@ impl Sequence {
             cycle_duration: total_duration,
             total_duration,
             elapsed: Duration::ZERO,
+            pending_delta: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+            markers: vec![],
         }
     }
 
@@ -1112,6 +2019,9 @@ impl Sequence {
             cycle_duration: total_duration,
             total_duration,
             elapsed: Duration::ZERO,
+            pending_delta: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+            markers: vec![],
         }
     }
 
@@ -1125,18 +2035,67 @@ impl Sequence {
             cycle_duration: total_duration,
             total_duration,
             elapsed: Duration::ZERO,
+            pending_delta: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+            markers: vec![],
         }
     }
 
     /// Append a [`Tweenable`] to this sequence.
+    ///
+    /// If the sequence plays backward, the appended tweenable is set to play
+    /// backward too.
     #[must_use]
-    pub fn then(mut self, tween: impl Tweenable + 'static) -> Self {
+    pub fn then(mut self, mut tween: impl Tweenable + 'static) -> Self {
         self.total_duration = self.total_duration + tween.total_duration();
         self.cycle_duration = self.cycle_duration + tween.total_duration();
+        if self.playback_direction.is_backward() {
+            tween.set_playback_direction(self.playback_direction);
+        }
         self.tweens.push(Box::new(tween));
         self
     }
 
+    /// Recalculate the duration of the sequence from the current duration of
+    /// its children.
+    fn update_duration(&mut self) {
+        self.total_duration = self.tweens.iter().map(|tween| tween.total_duration()).sum();
+        self.cycle_duration = self.total_duration;
+    }
+
+    /// Set the playback direction of the sequence and all its children.
+    ///
+    /// When playing backward, the sequence plays its children in reverse order,
+    /// from the last one to the first one, and each child plays backward. Call
+    /// [`rewind()`] after changing the direction to start playing from the end
+    /// of the sequence.
+    ///
+    /// See [`Tweenable::set_playback_direction()`] for details.
+    ///
+    /// [`rewind()`]: Tweenable::rewind
+    #[must_use]
+    pub fn with_playback_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.set_playback_direction(direction);
+        self
+    }
+
+    /// Add a named marker to the sequence.
+    ///
+    /// The marker time is relative to the start of the sequence. Markers can
+    /// also be added to the children of the sequence, relative to the start of
+    /// each child. See [`Marker`] for details.
+    #[must_use]
+    pub fn with_marker(mut self, name: impl Into<Cow<'static, str>>, time: Duration) -> Self {
+        self.markers.push(Marker::new(name, time));
+        self
+    }
+
+    /// Get the markers of the sequence, excluding those of its children.
+    #[must_use]
+    pub fn markers(&self) -> &[Marker] {
+        &self.markers
+    }
+
     /// Index of the current active tween in the sequence.
     #[must_use]
     pub fn index(&self) -> usize {
@@ -1148,13 +2107,66 @@ impl Sequence {
     pub fn current(&self) -> &dyn Tweenable {
         self.tweens[self.index()].as_ref()
     }
+
+    /// Step the sequence backward, from its current child toward the first one.
+    fn step_back(
+        &mut self,
+        tween_id: Entity,
+        mut delta: Duration,
+        mut target: MutUntyped,
+        target_type_id: &TypeId,
+        notify_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        // Resume from the last child if the sequence completed forward
+        let Some(last) = self.tweens.len().checked_sub(1) else {
+            return (TweenState::Completed, false);
+        };
+        self.index = self.index.min(last);
+
+        self.elapsed = self.elapsed.saturating_sub(delta);
+        delta = delta.saturating_add(std::mem::take(&mut self.pending_delta));
+
+        // Tick one or more tweenables backward until the new elapsed time is reached.
+        loop {
+            let tween = &mut self.tweens[self.index];
+
+            let prev_elapsed = tween.elapsed();
+
+            if let (TweenState::Active, retarget) = tween.step(
+                tween_id,
+                delta,
+                target.reborrow(),
+                target_type_id,
+                notify_completed,
+            ) {
+                return (TweenState::Active, retarget);
+            }
+
+            // Child tween has completed backward, so it consumed all the time left
+            // between its previous elapsed time and its start.
+            delta = delta.saturating_sub(prev_elapsed);
+            if self.index == 0 {
+                return (TweenState::Completed, false);
+            }
+            self.index -= 1;
+
+            // If the target type changed, we need to ask the caller to retarget and step
+            // again.
+            if let Some(type_id) = self.tweens[self.index].target_type_id() {
+                if type_id != *target_type_id {
+                    self.pending_delta = delta;
+                    return (TweenState::Active, true);
+                }
+            }
+        }
+    }
 }
 
 impl Tweenable for Sequence {
     fn cycle_duration(&self) -> Duration {
-        // FIXME - unwrap() because we can cycle over an infinite child, but that's
-        // undefined behavior
-        self.cycle_duration.as_finite().unwrap()
+        // A sequence is a single cycle; if it contains an infinite child, that cycle
+        // never ends. Use a Repeat to loop a sequence.
+        self.cycle_duration.as_finite().unwrap_or(Duration::MAX)
     }
 
     fn total_duration(&self) -> TotalDuration {
@@ -1164,6 +2176,7 @@ impl Tweenable for Sequence {
     fn set_elapsed(&mut self, elapsed: Duration) {
         // Set the total sequence elapsed time
         self.elapsed = elapsed;
+        self.pending_delta = Duration::ZERO;
 
         // Find which tween is active in the sequence at that time
         let mut accum_duration = Duration::ZERO;
@@ -1212,6 +2225,10 @@ impl Tweenable for Sequence {
         target_type_id: &TypeId,
         notify_completed: &mut dyn FnMut(),
     ) -> (TweenState, bool) {
+        if self.playback_direction.is_backward() {
+            return self.step_back(tween_id, delta, target, target_type_id, notify_completed);
+        }
+
         // Early out
         if self.index >= self.tweens.len() {
             return (TweenState::Completed, false);
@@ -1223,6 +2240,10 @@ impl Tweenable for Sequence {
             self.elapsed = self.elapsed.min(total_duration);
         }
 
+        // If a previous step requested a retarget, the time left over at that point
+        // wasn't consumed yet. Consume it now with the new target.
+        delta = delta.saturating_add(std::mem::take(&mut self.pending_delta));
+
         // Tick one or more tweenables until the new elapsed time is reached.
         while self.index < self.tweens.len() {
             // Tick the current tweenable
@@ -1252,6 +2273,11 @@ impl Tweenable for Sequence {
             delta -= consumed_duration;
             self.index += 1;
 
+            // A child of unknown duration, like a WaitUntil, may have just resolved it
+            if self.total_duration == TotalDuration::Infinite {
+                self.update_duration();
+            }
+
             // If the target type changed, we need to ask the caller to retarget and step
             // again.
             if self.index < self.tweens.len() {
@@ -1259,6 +2285,7 @@ impl Tweenable for Sequence {
                 // can pass any target as argument.
                 if let Some(type_id) = self.tweens[self.index].target_type_id() {
                     if type_id != *target_type_id {
+                        self.pending_delta = delta;
                         return (TweenState::Active, true);
                     }
                 }
@@ -1269,968 +2296,4545 @@ impl Tweenable for Sequence {
     }
 
     fn rewind(&mut self) {
-        self.elapsed = Duration::ZERO;
-        self.index = 0;
+        match self.playback_direction {
+            PlaybackDirection::Forward => {
+                self.elapsed = Duration::ZERO;
+                self.index = 0;
+            }
+            PlaybackDirection::Backward => {
+                self.elapsed = self.total_duration.as_finite().unwrap();
+                self.index = self.tweens.len().saturating_sub(1);
+            }
+        }
+        self.pending_delta = Duration::ZERO;
         for tween in &mut self.tweens {
-            // or only first?
             tween.rewind();
         }
+        // Rewinding a WaitUntil makes its duration unknown again
+        self.update_duration();
     }
 
-    fn target_type_id(&self) -> Option<TypeId> {
-        // Loop over all children, because we want to skip all untyped ones (Delay).
-        // Otherwise the animator will panic, because we can't create an untyped
-        // animation.
-        // for tween in &self.tweens {
-        //     if let Some(type_id) = tween.target_type_id() {
-        //         return Some(type_id);
-        //     }
-        // }
-        //None
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.playback_direction = direction;
+        for tween in &mut self.tweens {
+            tween.set_playback_direction(direction);
+        }
+    }
+
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
+    }
+
+    fn visit_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        include_from: bool,
+        direction: PlaybackDirection,
+        visitor: &mut dyn FnMut(&Marker),
+    ) {
+        for marker in &self.markers {
+            if Marker::is_crossed(marker.time, from, to, include_from) {
+                visitor(marker);
+            }
+        }
+        let mut start = Duration::ZERO;
+        for tween in &self.tweens {
+            visit_child_markers(
+                tween.as_ref(),
+                start,
+                from,
+                to,
+                include_from,
+                direction,
+                visitor,
+            );
+            let TotalDuration::Finite(duration) = tween.total_duration() else {
+                break;
+            };
+            start += duration;
+        }
+    }
 
-        // TEMP - not supporting multi-target yet, so assert here so users know
-        let mut target_type_id = None;
+    fn marker_time(&self, name: &str) -> Option<Duration> {
+        if let Some(marker) = self.markers.iter().find(|marker| marker.name == name) {
+            return Some(marker.time);
+        }
+        let mut start = Duration::ZERO;
         for tween in &self.tweens {
-            if let Some(type_id) = tween.target_type_id() {
-                assert!(target_type_id.is_none() || target_type_id == Some(type_id), "TODO: Cannot use tweenable animations with different targets inside the same Sequence. Create separate animations for each target.");
-                target_type_id = Some(type_id);
+            if let Some(time) = tween.marker_time(name) {
+                return Some(start + time);
             }
+            start += tween.total_duration().as_finite()?;
+        }
+        None
+    }
+
+    fn poll_conditions(&self, world: &World) {
+        if let Some(tween) = self.tweens.get(self.index) {
+            tween.poll_conditions(world);
+        }
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Sequence", self.elapsed, self.playback_direction)
+            .with_index(self.index)
+            .with_children(self.tweens.iter().map(|tween| tween.save_state()).collect())
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(self.tweens.len())?;
+        let index = snapshot
+            .index
+            .filter(|index| *index <= self.tweens.len())
+            .ok_or(TweeningError::SnapshotMismatch)?;
+        self.set_playback_direction(snapshot.direction);
+        for (tween, child) in self.tweens.iter_mut().zip(&snapshot.children) {
+            tween.restore_state(child)?;
+        }
+        // Restoring a WaitUntil may resolve its duration
+        self.update_duration();
+        self.elapsed = snapshot.elapsed;
+        self.index = index;
+        self.pending_delta = Duration::ZERO;
+        Ok(())
+    }
+
+    fn target_type_id(&self) -> Option<TypeId> {
+        // Return the type of the current child, or if untyped (Delay) the type of the
+        // next typed child, since that's the one which will access the target next.
+        // Otherwise the animator will fail to resolve the target, because we can't
+        // create an untyped animation. Once the sequence completed, keep the type of
+        // the last typed child, which is the last target animated.
+        let index = self.index.min(self.tweens.len());
+        self.tweens[index..]
+            .iter()
+            .find_map(|tween| tween.target_type_id())
+            .or_else(|| {
+                self.tweens[..index]
+                    .iter()
+                    .rev()
+                    .find_map(|tween| tween.target_type_id())
+            })
+    }
+
+    fn visit_target_types(&self, visitor: &mut dyn FnMut(TypeId)) {
+        for tween in &self.tweens {
+            tween.visit_target_types(visitor);
         }
-        target_type_id
     }
 }
 
-/// A time delay that doesn't animate anything.
+/// A collection of tweenable animations played in parallel.
 ///
-/// This is generally useful for combining with other tweenables into sequences
-/// and tracks, for example to delay the start of a tween in a track relative to
-/// another track. The `menu` example (`examples/menu.rs`) uses this technique
-/// to delay the animation of its buttons.
-#[derive(Debug)]
-pub struct Delay {
-    timer: Timer,
+/// All the children of a [`Parallel`] start playing at the same time, and are
+/// all stepped with the same delta time. The parallel tweenable completes once
+/// all its children completed, so its total duration is the maximum of the
+/// total durations of its children. If any child is of infinite duration, the
+/// parallel tweenable itself is of infinite duration.
+///
+/// A [`Parallel`] can be nested inside a [`Sequence`], and conversely, to build
+/// more complex animations:
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::{Vec3, curve::EaseFunction};
+/// # use std::time::Duration;
+/// # let scale_up = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformScaleLens { start: Vec3::ZERO, end: Vec3::ONE });
+/// # let spin = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformRotateZLens { start: 0., end: 1. });
+/// # let move_right = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformPositionLens { start: Vec3::ZERO, end: Vec3::X });
+/// // Scale up while spinning, then move to the right
+/// let anim = Parallel::new([scale_up, spin]).then(move_right);
+/// ```
+///
+/// Because all children are stepped at the same time with a single target,
+/// all the typed children of a [`Parallel`] must animate the same target type.
+/// This includes nested children, so a multi-target [`Sequence`] can't be
+/// played in parallel of another tweenable.
+pub struct Parallel {
+    tweens: Vec<BoxedTweenable>,
+    elapsed: Duration,
+    playback_direction: PlaybackDirection,
 }
 
-impl Delay {
-    /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with
-    /// the two.
+impl Parallel {
+    /// Create a new collection of tweens playing in parallel.
+    ///
+    /// The total duration of the collection is the maximum of the total
+    /// durations of all its children.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input collection is empty, or if its children animate
+    /// different target types.
     #[must_use]
-    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence {
-        Sequence::with_capacity(2).then(self).then(tween)
+    #[inline]
+    pub fn new(items: impl IntoIterator<Item = impl Into<BoxedTweenable>>) -> Self {
+        let tweens: Vec<_> = items.into_iter().map(Into::into).collect();
+        assert!(!tweens.is_empty());
+        tweens.iter().fold(None, |target_type_id, tween| {
+            check_target_types(target_type_id, tween.as_ref(), "Parallel")
+        });
+
+        Self {
+            tweens,
+            elapsed: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+        }
     }
 
-    /// Create a new [`Delay`] with a given duration.
+    /// Create a new parallel collection with the specified capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            tweens: Vec::with_capacity(capacity),
+            elapsed: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+        }
+    }
+
+    /// Add a [`Tweenable`] to play in parallel of the existing ones.
+    ///
+    /// If the collection plays backward, the added tweenable is set to play
+    /// backward too.
     ///
     /// # Panics
     ///
-    /// Panics if the duration is zero.
+    /// Panics if the tweenable animates a target type different from the one
+    /// of the existing children.
     #[must_use]
-    pub fn new(duration: Duration) -> Self {
-        assert!(!duration.is_zero());
-        Self {
-            timer: Timer::new(duration, TimerMode::Once),
+    pub fn and(mut self, mut tween: impl Tweenable + 'static) -> Self {
+        check_target_types(self.target_type_id(), &tween, "Parallel");
+        if self.playback_direction.is_backward() {
+            tween.set_playback_direction(self.playback_direction);
         }
+        self.tweens.push(Box::new(tween));
+        self
     }
 
-    /// Check if the delay completed.
-    pub fn is_completed(&self) -> bool {
-        self.timer.is_finished()
+    /// Set the playback direction of the collection and all its children.
+    ///
+    /// When playing backward, children shorter than the collection wait at
+    /// their end until the elapsed time of the collection reaches back their
+    /// duration, so that they all end up together at their start.
+    ///
+    /// See [`Tweenable::set_playback_direction()`] for details.
+    #[must_use]
+    pub fn with_playback_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.set_playback_direction(direction);
+        self
     }
 
-    /// Get the current tweenable state.
-    pub fn state(&self) -> TweenState {
-        if self.is_completed() {
-            TweenState::Completed
-        } else {
-            TweenState::Active
-        }
+    /// Chain another [`Tweenable`] after this parallel collection, making a
+    /// [`Sequence`] with the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+
+    /// Get the tweenables playing in parallel.
+    #[must_use]
+    pub fn tweenables(&self) -> &[BoxedTweenable] {
+        &self.tweens
     }
 }
 
-impl Tweenable for Delay {
+impl Tweenable for Parallel {
     fn cycle_duration(&self) -> Duration {
-        self.timer.duration()
+        // A parallel collection is a single cycle; if infinite, that cycle never ends.
+        self.total_duration().as_finite().unwrap_or(Duration::MAX)
     }
 
     fn total_duration(&self) -> TotalDuration {
-        TotalDuration::Finite(self.cycle_duration())
+        // Children like a Spring or a WaitUntil only know their duration once they
+        // complete, so always use their current duration.
+        self.tweens
+            .iter()
+            .map(|tween| tween.total_duration())
+            .max()
+            .unwrap_or(TotalDuration::Finite(Duration::ZERO))
     }
 
     fn set_elapsed(&mut self, elapsed: Duration) {
-        // need to reset() to clear is_finished() unfortunately
-        self.timer.reset();
-        self.timer.set_elapsed(elapsed);
-        // set_elapsed() does not update finished() etc. which we rely on
-        self.timer.tick(Duration::ZERO);
+        self.elapsed = match self.total_duration() {
+            TotalDuration::Finite(total_duration) => elapsed.min(total_duration),
+            TotalDuration::Infinite => elapsed,
+        };
+
+        // Seek all children to the same time, clamping to their own duration so that
+        // shorter children are left completed.
+        for tween in &mut self.tweens {
+            let local_elapsed = match tween.total_duration() {
+                TotalDuration::Finite(duration) => self.elapsed.min(duration),
+                TotalDuration::Infinite => self.elapsed,
+            };
+            tween.set_elapsed(local_elapsed);
+        }
     }
 
     fn elapsed(&self) -> Duration {
-        self.timer.elapsed()
+        self.elapsed
     }
 
     fn step(
         &mut self,
-        _tween_id: Entity,
+        tween_id: Entity,
         delta: Duration,
-        _target: MutUntyped,
-        _target_type_id: &TypeId,
-        _notify_completed: &mut dyn FnMut(),
+        mut target: MutUntyped,
+        target_type_id: &TypeId,
+        notify_completed: &mut dyn FnMut(),
     ) -> (TweenState, bool) {
-        self.timer.tick(delta);
+        let prev_elapsed = self.elapsed;
+        if self.playback_direction.is_forward() {
+            self.elapsed = self.elapsed.saturating_add(delta);
+            if let TotalDuration::Finite(total_duration) = self.total_duration() {
+                self.elapsed = self.elapsed.min(total_duration);
+            }
+        } else {
+            self.elapsed = self.elapsed.saturating_sub(delta);
+        }
 
-        let state = self.state();
+        // Step all children by the portion of the delta which overlaps their own
+        // duration. Children which already completed are no-op, so we don't need to
+        // track them individually. When playing backward, this keeps shorter
+        // children at their end until the elapsed time reaches back their duration.
+        let mut state = TweenState::Completed;
+        let mut needs_retarget = false;
+        for tween in &mut self.tweens {
+            let local_delta = match tween.total_duration() {
+                TotalDuration::Finite(duration) => prev_elapsed
+                    .min(duration)
+                    .abs_diff(self.elapsed.min(duration)),
+                TotalDuration::Infinite => delta,
+            };
+            let (child_state, retarget) = tween.step(
+                tween_id,
+                local_delta,
+                target.reborrow(),
+                target_type_id,
+                notify_completed,
+            );
+            if child_state == TweenState::Active {
+                state = TweenState::Active;
+            }
+            needs_retarget |= retarget;
+        }
 
-        // // If completed this frame, notify the user
-        // if (state == TweenState::Completed) && !was_completed {
-        //     if self.send_completed_events {
-        //         let event = CycleCompletedEvent {
-        //             id: tween_id,
-        //             entity,
-        //             progress,
-        //         };
+        (state, needs_retarget)
+    }
 
-        //         // send regular event
-        //         events.send(event);
+    fn rewind(&mut self) {
+        self.elapsed = match self.playback_direction {
+            PlaybackDirection::Forward => Duration::ZERO,
+            PlaybackDirection::Backward => self.total_duration().as_finite().unwrap(),
+        };
+        for tween in &mut self.tweens {
+            tween.rewind();
+        }
+    }
 
-        //         // trigger all entity-scoped observers
-        //         //commands.trigger_targets(event, entity);
-        //     }
-        //     if let Some(system_id) = &self.system_id {
-        //         commands.run_system(*system_id);
-        //     }
-        // }
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.playback_direction = direction;
+        for tween in &mut self.tweens {
+            tween.set_playback_direction(direction);
+        }
+    }
 
-        (state, false)
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
     }
 
-    fn rewind(&mut self) {
-        self.timer.reset();
+    fn visit_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        include_from: bool,
+        direction: PlaybackDirection,
+        visitor: &mut dyn FnMut(&Marker),
+    ) {
+        for tween in &self.tweens {
+            visit_child_markers(
+                tween.as_ref(),
+                Duration::ZERO,
+                from,
+                to,
+                include_from,
+                direction,
+                visitor,
+            );
+        }
+    }
+
+    fn marker_time(&self, name: &str) -> Option<Duration> {
+        self.tweens.iter().find_map(|tween| tween.marker_time(name))
+    }
+
+    fn poll_conditions(&self, world: &World) {
+        for tween in &self.tweens {
+            tween.poll_conditions(world);
+        }
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Parallel", self.elapsed, self.playback_direction)
+            .with_children(self.tweens.iter().map(|tween| tween.save_state()).collect())
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(self.tweens.len())?;
+        self.set_playback_direction(snapshot.direction);
+        self.set_elapsed(snapshot.elapsed);
+        for (tween, child) in self.tweens.iter_mut().zip(&snapshot.children) {
+            tween.restore_state(child)?;
+        }
+        // Restoring a child may resolve its duration, which bounds the elapsed time
+        self.elapsed = match self.total_duration() {
+            TotalDuration::Finite(total_duration) => snapshot.elapsed.min(total_duration),
+            TotalDuration::Infinite => snapshot.elapsed,
+        };
+        Ok(())
     }
 
     fn target_type_id(&self) -> Option<TypeId> {
-        None
+        // All children share the same target, so have the same type, with the
+        // exception of untyped ones (Delay). This is checked on insertion.
+        self.tweens.iter().find_map(|tween| tween.target_type_id())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    // use std::sync::{Arc, Mutex};
+    fn visit_target_types(&self, visitor: &mut dyn FnMut(TypeId)) {
+        for tween in &self.tweens {
+            tween.visit_target_types(visitor);
+        }
+    }
+}
 
-    use std::ops::{Deref as _, DerefMut as _};
+/// Position of a child tweenable inside a [`Timeline`].
+///
+/// The position defines the time at which a child starts playing, relative to
+/// the start of the timeline. Relative positions reference another child by
+/// its index in the timeline, which is the order in which the children were
+/// inserted. Only children inserted before can be referenced.
+///
+/// A [`Duration`] converts into an absolute position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimelinePosition {
+    /// Absolute offset from the start of the timeline.
+    At(Duration),
+    /// Offset after the start of another child.
+    AfterStartOf(usize, Duration),
+    /// Offset before the start of another child. The position is clamped to
+    /// the start of the timeline.
+    BeforeStartOf(usize, Duration),
+    /// Offset after the end of another child.
+    AfterEndOf(usize, Duration),
+    /// Offset before the end of another child. The position is clamped to the
+    /// start of the timeline.
+    BeforeEndOf(usize, Duration),
+}
 
-    use bevy::ecs::{change_detection::MaybeLocation, change_detection::Tick, system::SystemState};
+impl From<Duration> for TimelinePosition {
+    fn from(offset: Duration) -> Self {
+        Self::At(offset)
+    }
+}
 
-    use super::*;
-    use crate::{lens::*, test_utils::assert_approx_eq};
+/// A collection of tweenable animations played at arbitrary positions in time.
+///
+/// Each child of a [`Timeline`] starts playing at its own start offset, which
+/// is either absolute or relative to the start or end of another child (see
+/// [`TimelinePosition`]). Unlike for a [`Sequence`], children can overlap,
+/// and unlike for a [`Parallel`], they don't need to all start at the same
+/// time. The timeline completes once all its children completed, so its total
+/// duration is the maximum of the end times of its children. If any child is of
+/// infinite duration, the timeline itself is of infinite duration.
+///
+/// A child is not stepped before its start offset, so it doesn't mutate the
+/// target until then.
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::{Vec3, curve::EaseFunction};
+/// # use std::time::Duration;
+/// # let move_right = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformPositionLens { start: Vec3::ZERO, end: Vec3::X });
+/// # let scale_down = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformScaleLens { start: Vec3::ONE, end: Vec3::ZERO });
+/// // Move to the right, and start scaling down 200 ms before the move ends
+/// let anim = Timeline::new().insert(Duration::ZERO, move_right).insert(
+///     TimelinePosition::BeforeEndOf(0, Duration::from_millis(200)),
+///     scale_down,
+/// );
+/// assert_eq!(
+///     anim.total_duration(),
+///     TotalDuration::Finite(Duration::from_millis(1800))
+/// );
+/// ```
+///
+/// Because all children are stepped with a single target, all the typed
+/// children of a [`Timeline`] must animate the same target type, including
+/// nested children like those of a multi-target [`Sequence`].
+pub struct Timeline {
+    tweens: Vec<BoxedTweenable>,
+    /// Start offset of each child, in the same order as `tweens`.
+    starts: Vec<Duration>,
+    elapsed: Duration,
+    playback_direction: PlaybackDirection,
+    markers: Vec<Marker>,
+}
 
-    // #[derive(Default, Copy, Clone)]
-    // struct CallbackMonitor {
-    //     invoke_count: u64,
-    //     last_reported_count: u32,
-    // }
+impl Timeline {
+    /// Create a new empty timeline.
+    ///
+    /// An empty timeline has a duration of zero, and completes immediately.
+    /// Use [`insert()`] to add children to it.
+    ///
+    /// [`insert()`]: Self::insert
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
 
-    // Check the behavior of the functions calculating cycle information
-    #[test]
-    fn anim_clock_cycles() {
-        // The direction passed to AnimClock::set_elapsed() only affects the returned
-        // state, which is not tested in this test, so passing any value should produce
-        // the same test result here.
-        for dummy in [PlaybackDirection::Forward, PlaybackDirection::Backward] {
-            let cycle_duration = Duration::from_millis(100);
-            let repeat_count = 4;
-            let total_duration = cycle_duration * repeat_count;
-            let mut clock = AnimClock::new(cycle_duration);
-            clock.total_duration = TotalDuration::Finite(total_duration);
+    /// Create a new empty timeline with the specified capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            tweens: Vec::with_capacity(capacity),
+            starts: Vec::with_capacity(capacity),
+            elapsed: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+            markers: vec![],
+        }
+    }
 
-            assert_eq!(cycle_duration, clock.cycle_duration);
-            assert_eq!(RepeatStrategy::Repeat, clock.strategy);
+    /// Insert a [`Tweenable`] at the given position in the timeline.
+    ///
+    /// The index of the new child, which can be used to position other children
+    /// relative to it, is the number of children before insertion, as returned
+    /// by [`len()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is relative to a child which doesn't exist, or is
+    /// relative to the end of a child of infinite duration. Also panics if the
+    /// tweenable animates a target type different from the one of the existing
+    /// children.
+    ///
+    /// [`len()`]: Self::len
+    #[must_use]
+    pub fn insert(
+        mut self,
+        position: impl Into<TimelinePosition>,
+        tween: impl Into<BoxedTweenable>,
+    ) -> Self {
+        let start = self.resolve(position.into());
+        let mut tween = tween.into();
+        check_target_types(self.target_type_id(), tween.as_ref(), "Timeline");
+        if self.playback_direction.is_backward() {
+            tween.set_playback_direction(self.playback_direction);
+        }
+        self.tweens.push(tween);
+        self.starts.push(start);
+        self
+    }
 
-            assert_eq!(Duration::ZERO, clock.elapsed());
-            assert_eq!(0, clock.cycle_index());
-            assert_approx_eq!(0.0, clock.cycle_fraction());
-            assert_approx_eq!(0.0, clock.mirrored_cycle_fraction());
+    /// Add a named marker to the timeline.
+    ///
+    /// The marker time is relative to the start of the timeline. Markers can
+    /// also be added to the children of the timeline, relative to the start of
+    /// each child. See [`Marker`] for details.
+    #[must_use]
+    pub fn with_marker(mut self, name: impl Into<Cow<'static, str>>, time: Duration) -> Self {
+        self.markers.push(Marker::new(name, time));
+        self
+    }
 
-            let dt = Duration::from_millis(30);
-            clock.set_elapsed(dt, dummy);
-            assert_eq!(dt, clock.elapsed());
-            assert_eq!(0, clock.cycle_index());
-            assert_approx_eq!(0.3, clock.cycle_fraction());
-            assert_approx_eq!(0.3, clock.mirrored_cycle_fraction());
+    /// Get the markers of the timeline, excluding those of its children.
+    #[must_use]
+    pub fn markers(&self) -> &[Marker] {
+        &self.markers
+    }
 
-            let dt = Duration::from_millis(110);
-            clock.set_elapsed(dt, dummy);
-            assert_eq!(dt, clock.elapsed());
-            assert_eq!(1, clock.cycle_index());
-            assert_approx_eq!(0.1, clock.cycle_fraction());
-            assert_approx_eq!(0.1, clock.mirrored_cycle_fraction());
+    /// Set the playback direction of the timeline and all its children.
+    ///
+    /// When playing backward, each child plays backward over the same time
+    /// span it plays forward, so the last child to end is the first one to
+    /// play.
+    ///
+    /// See [`Tweenable::set_playback_direction()`] for details.
+    #[must_use]
+    pub fn with_playback_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.set_playback_direction(direction);
+        self
+    }
 
-            let dt = Duration::from_millis(400);
-            clock.set_elapsed(dt, dummy);
-            assert_eq!(dt, clock.elapsed());
-            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
-            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
-            assert_approx_eq!(1.0, clock.mirrored_cycle_fraction());
+    /// Chain another [`Tweenable`] after this timeline, making a [`Sequence`]
+    /// with the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
 
-            let dt = Duration::from_millis(410); // > total_duration; clamped
-            clock.set_elapsed(dt, dummy);
-            assert_eq!(total_duration, clock.elapsed()); // clamped
-            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
-            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
-            assert_approx_eq!(1.0, clock.mirrored_cycle_fraction());
+    /// Get the number of children in the timeline.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tweens.len()
+    }
 
-            clock.strategy = RepeatStrategy::MirroredRepeat;
+    /// Check whether the timeline is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tweens.is_empty()
+    }
 
-            let dt = Duration::from_millis(110);
-            clock.set_elapsed(dt, dummy);
-            assert_eq!(dt, clock.elapsed());
-            assert_eq!(1, clock.cycle_index());
-            assert_approx_eq!(0.1, clock.cycle_fraction());
-            assert_approx_eq!(0.9, clock.mirrored_cycle_fraction()); // mirrored
+    /// Get the tweenables of the timeline, in insertion order.
+    #[must_use]
+    pub fn tweenables(&self) -> &[BoxedTweenable] {
+        &self.tweens
+    }
 
-            let dt = Duration::from_millis(400);
-            clock.set_elapsed(dt, dummy);
-            assert_eq!(dt, clock.elapsed());
-            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
-            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
-            assert_approx_eq!(0.0, clock.mirrored_cycle_fraction()); // mirrored
+    /// Get the start offset of the child with the given index, relative to the
+    /// start of the timeline.
+    #[must_use]
+    pub fn start_of(&self, index: usize) -> Option<Duration> {
+        self.starts.get(index).copied()
+    }
 
-            let dt = Duration::from_millis(410); // > total_duration; clamped
-            clock.set_elapsed(dt, dummy);
-            assert_eq!(total_duration, clock.elapsed()); // clamped
-            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
-            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
-            assert_approx_eq!(0.0, clock.mirrored_cycle_fraction()); // mirrored
+    fn resolve(&self, position: TimelinePosition) -> Duration {
+        let start_of = |index: usize| {
+            *self.starts.get(index).unwrap_or_else(|| {
+                panic!(
+                    "Invalid timeline position: no child at index {index} (timeline has {} children).",
+                    self.tweens.len()
+                )
+            })
+        };
+        let end_of = |index: usize| {
+            let start = start_of(index);
+            let duration = self.tweens[index].total_duration().as_finite().unwrap_or_else(|| {
+                panic!("Invalid timeline position: child at index {index} has an infinite duration.")
+            });
+            start + duration
+        };
+        match position {
+            TimelinePosition::At(offset) => offset,
+            TimelinePosition::AfterStartOf(index, offset) => start_of(index) + offset,
+            TimelinePosition::BeforeStartOf(index, offset) => {
+                start_of(index).saturating_sub(offset)
+            }
+            TimelinePosition::AfterEndOf(index, offset) => end_of(index) + offset,
+            TimelinePosition::BeforeEndOf(index, offset) => end_of(index).saturating_sub(offset),
         }
     }
 
-    // Check the accumulated error of the clock over a long period
-    #[test]
-    fn anim_clock_precision() {
-        let cycle_duration = Duration::from_millis(1);
-        let mut clock = AnimClock::new(cycle_duration);
-        clock.total_duration = TotalDuration::Infinite;
+    fn is_completed(&self) -> bool {
+        match self.total_duration() {
+            TotalDuration::Finite(total_duration) => self.elapsed >= total_duration,
+            TotalDuration::Infinite => false,
+        }
+    }
+}
 
-        let test_ticks = [
-            Duration::from_micros(123),
-            Duration::from_millis(1),
-            Duration::from_secs_f32(1. / 24.),
-            Duration::from_secs_f32(1. / 30.),
-            Duration::from_secs_f32(1. / 60.),
-            Duration::from_secs_f32(1. / 120.),
-            Duration::from_secs_f32(1. / 144.),
-            Duration::from_secs_f32(1. / 240.),
-        ];
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        let mut times_completed = 0;
-        let mut total_duration = Duration::ZERO;
-        let num_iter = 100_000_000; // 100m ms, >27h
-        for i in 0..num_iter {
-            let tick = test_ticks[i % test_ticks.len()];
-            times_completed += clock.tick(tick).1;
-            total_duration += tick;
-        }
+impl Tweenable for Timeline {
+    fn cycle_duration(&self) -> Duration {
+        // A timeline is a single cycle; if infinite, that cycle never ends.
+        self.total_duration().as_finite().unwrap_or(Duration::MAX)
+    }
 
-        assert_eq!(
-            total_duration.div_duration_f64(cycle_duration) as i32,
-            times_completed
-        );
+    fn total_duration(&self) -> TotalDuration {
+        // Children like a Spring or a WaitUntil only know their duration once they
+        // complete, so always use their current duration.
+        self.tweens
+            .iter()
+            .zip(&self.starts)
+            .map(|(tween, start)| match tween.total_duration() {
+                TotalDuration::Finite(duration) => TotalDuration::Finite(*start + duration),
+                TotalDuration::Infinite => TotalDuration::Infinite,
+            })
+            .max()
+            .unwrap_or(TotalDuration::Finite(Duration::ZERO))
     }
 
-    /// Utility to create a tween for testing.
-    fn make_test_tween() -> Tween {
-        Tween::new(
-            EaseMethod::default(),
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        )
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = match self.total_duration() {
+            TotalDuration::Finite(total_duration) => elapsed.min(total_duration),
+            TotalDuration::Infinite => elapsed,
+        };
+
+        // Seek all children to their local time. Children not started yet are left at
+        // zero, and children already ended are left completed.
+        for (tween, start) in self.tweens.iter_mut().zip(&self.starts) {
+            let local_elapsed = self.elapsed.saturating_sub(*start);
+            let local_elapsed = match tween.total_duration() {
+                TotalDuration::Finite(duration) => local_elapsed.min(duration),
+                TotalDuration::Infinite => local_elapsed,
+            };
+            tween.set_elapsed(local_elapsed);
+        }
     }
 
-    /// Utility to create a test environment to tick a tween.
-    fn make_test_env() -> (World, Entity) {
-        let mut world = World::new();
-        world.init_resource::<Messages<CycleCompletedEvent>>();
-        let entity = world.spawn(Transform::default()).id();
-        (world, entity)
+    fn elapsed(&self) -> Duration {
+        self.elapsed
     }
 
-    /// Manually tick a test tweenable targeting a component.
-    fn manual_tick_component(
-        anim_entity: Entity,
-        duration: Duration,
-        tween: &mut dyn Tweenable,
-        world: &mut World,
-        entity: Entity,
-    ) -> TweenState {
-        // Tick the given tween and apply its state to the given entity target
-        let target_type_id = TypeId::of::<Transform>();
-        let ret = world.resource_scope(
-            |world: &mut World, mut events: Mut<Messages<CycleCompletedEvent>>| {
-                let component_id = world.component_id::<Transform>().unwrap();
-                let entity_mut = &mut world.get_entity_mut([entity]).unwrap()[0];
-                if let Ok(mut target) = entity_mut.get_mut_by_id(component_id) {
-                    let world_target = AnimTargetKind::Component { entity };
-                    let mut notify_completed = || {
-                        events.write(CycleCompletedEvent {
-                            anim_entity,
-                            target: world_target,
-                        });
-                    };
-                    tween.step(
-                        anim_entity,
-                        duration,
-                        target.reborrow(),
-                        &target_type_id,
-                        &mut notify_completed,
-                    )
-                } else {
-                    (TweenState::Completed, false)
+    fn step(
+        &mut self,
+        tween_id: Entity,
+        delta: Duration,
+        mut target: MutUntyped,
+        target_type_id: &TypeId,
+        notify_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        let prev_elapsed = self.elapsed;
+        if self.playback_direction.is_forward() {
+            self.elapsed = self.elapsed.saturating_add(delta);
+            if let TotalDuration::Finite(total_duration) = self.total_duration() {
+                self.elapsed = self.elapsed.min(total_duration);
+            }
+        } else {
+            self.elapsed = self.elapsed.saturating_sub(delta);
+        }
+
+        // Step each child which started by the time elapsed this step, only by the
+        // portion of that time which overlaps the child. Children which already
+        // completed are no-op, so we don't need to track them individually.
+        let mut needs_retarget = false;
+        for (tween, start) in self.tweens.iter_mut().zip(&self.starts) {
+            if self.elapsed.max(prev_elapsed) < *start {
+                continue;
+            }
+            let local_elapsed = |elapsed: Duration| {
+                let local_elapsed = elapsed.saturating_sub(*start);
+                match tween.total_duration() {
+                    TotalDuration::Finite(duration) => local_elapsed.min(duration),
+                    TotalDuration::Infinite => local_elapsed,
                 }
-            },
-        );
+            };
+            let local_delta = local_elapsed(self.elapsed).abs_diff(local_elapsed(prev_elapsed));
+            let (_, retarget) = tween.step(
+                tween_id,
+                local_delta,
+                target.reborrow(),
+                target_type_id,
+                notify_completed,
+            );
+            needs_retarget |= retarget;
+        }
 
-        // Propagate events
-        {
-            let mut events = world.resource_mut::<Messages<CycleCompletedEvent>>();
-            events.update();
+        let completed = match self.playback_direction {
+            PlaybackDirection::Forward => self.is_completed(),
+            PlaybackDirection::Backward => self.elapsed.is_zero(),
+        };
+        let state = if completed {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        };
+        (state, needs_retarget)
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = match self.playback_direction {
+            PlaybackDirection::Forward => Duration::ZERO,
+            PlaybackDirection::Backward => self.total_duration().as_finite().unwrap(),
+        };
+        for tween in &mut self.tweens {
+            tween.rewind();
         }
+    }
 
-        ret.0
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.playback_direction = direction;
+        for tween in &mut self.tweens {
+            tween.set_playback_direction(direction);
+        }
     }
 
-    #[derive(Debug, Default, Clone, Copy, Component)]
-    struct DummyComponent {
-        _value: f32,
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
     }
 
-    #[test]
-    fn targetable_change_detect() {
-        let mut c = DummyComponent::default();
-        let mut added = Tick::new(0);
-        let mut last_changed = Tick::new(0);
-        let mut caller = MaybeLocation::caller();
-        let mut target = Mut::new(
-            &mut c,
-            &mut added,
-            &mut last_changed,
-            Tick::new(0),
-            Tick::new(1),
-            caller.as_mut(),
-        );
+    fn visit_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        include_from: bool,
+        direction: PlaybackDirection,
+        visitor: &mut dyn FnMut(&Marker),
+    ) {
+        for marker in &self.markers {
+            if Marker::is_crossed(marker.time, from, to, include_from) {
+                visitor(marker);
+            }
+        }
+        for (tween, start) in self.tweens.iter().zip(&self.starts) {
+            visit_child_markers(
+                tween.as_ref(),
+                *start,
+                from,
+                to,
+                include_from,
+                direction,
+                visitor,
+            );
+        }
+    }
 
-        // No-op at start
-        assert!(!target.is_added());
-        assert!(!target.is_changed());
+    fn marker_time(&self, name: &str) -> Option<Duration> {
+        if let Some(marker) = self.markers.iter().find(|marker| marker.name == name) {
+            return Some(marker.time);
+        }
+        self.tweens
+            .iter()
+            .zip(&self.starts)
+            .find_map(|(tween, start)| tween.marker_time(name).map(|time| *start + time))
+    }
 
-        // Immutable deref doesn't trigger change detection
-        let _ = target.deref();
-        assert!(!target.is_added());
-        assert!(!target.is_changed());
+    fn cycles_completed(&self) -> u32 {
+        // The timeline is a single cycle, which may be of zero duration if empty.
+        if self.is_completed() {
+            1
+        } else {
+            0
+        }
+    }
 
-        // Mutable deref triggers change detection
-        let _ = target.deref_mut();
-        assert!(!target.is_added());
-        assert!(target.is_changed());
+    fn poll_conditions(&self, world: &World) {
+        // Only poll children overlapping the current time
+        for (tween, start) in self.tweens.iter().zip(&self.starts) {
+            let active = *start <= self.elapsed
+                && tween
+                    .total_duration()
+                    .as_finite()
+                    .is_none_or(|duration| self.elapsed <= *start + duration);
+            if active {
+                tween.poll_conditions(world);
+            }
+        }
     }
 
-    #[test]
-    fn into_repeat_count() {
-        let tween = Tween::new(
-            EaseMethod::default(),
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        )
-        .with_repeat_count(5);
-        assert_eq!(
-            tween.total_duration(),
-            TotalDuration::Finite(Duration::from_secs(5))
-        );
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Timeline", self.elapsed, self.playback_direction)
+            .with_children(self.tweens.iter().map(|tween| tween.save_state()).collect())
+    }
 
-        let tween = Tween::new(
-            EaseMethod::default(),
-            Duration::from_secs(1),
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
-        )
-        .with_repeat_count(Duration::from_secs(3));
-        assert_eq!(
-            tween.total_duration(),
-            TotalDuration::Finite(Duration::from_secs(3))
-        );
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(self.tweens.len())?;
+        self.set_playback_direction(snapshot.direction);
+        self.set_elapsed(snapshot.elapsed);
+        for (tween, child) in self.tweens.iter_mut().zip(&snapshot.children) {
+            tween.restore_state(child)?;
+        }
+        // Restoring a child may resolve its duration, which bounds the elapsed time
+        self.elapsed = match self.total_duration() {
+            TotalDuration::Finite(total_duration) => snapshot.elapsed.min(total_duration),
+            TotalDuration::Infinite => snapshot.elapsed,
+        };
+        Ok(())
     }
 
-    /// Test ticking of a single tween in isolation.
-    #[test]
-    fn tween_tick() {
-        for playback_direction in [PlaybackDirection::Forward, PlaybackDirection::Backward] {
-            for (count, strategy) in [
-                (RepeatCount::Finite(1), RepeatStrategy::default()),
-                (RepeatCount::Infinite, RepeatStrategy::Repeat),
-                (RepeatCount::Finite(2), RepeatStrategy::Repeat),
-                (RepeatCount::Infinite, RepeatStrategy::MirroredRepeat),
-                (RepeatCount::Finite(2), RepeatStrategy::MirroredRepeat),
-            ] {
-                println!(
-                    "TweeningType: playback={playback_direction:?} count={count:?} strategy={strategy:?}",
-                );
+    fn target_type_id(&self) -> Option<TypeId> {
+        // All children share the same target, so have the same type, with the
+        // exception of untyped ones (Delay). This is checked on insertion.
+        self.tweens.iter().find_map(|tween| tween.target_type_id())
+    }
 
-                // Create a linear tween over 1 second
-                let mut tween = make_test_tween()
-                    .with_playback_direction(playback_direction)
-                    .with_repeat_count(count)
-                    .with_repeat_strategy(strategy)
-                    .with_cycle_completed_event(true);
-                assert_eq!(tween.playback_direction(), playback_direction);
-                assert!(tween.send_cycle_completed_event);
+    fn visit_target_types(&self, visitor: &mut dyn FnMut(TypeId)) {
+        for tween in &self.tweens {
+            tween.visit_target_types(visitor);
+        }
+    }
+}
 
-                // Note: for infinite duration playing backward, we need to start *somewhere*
-                // since we can't start at infinity. So pick t=1s which is shorter than 11
-                // iterations of the 100ms test tween created by make_test_tween(), to ensure we
-                // reach t=0.
-                let backward_start_time = tween
-                    .total_duration()
-                    .as_finite()
-                    .unwrap_or(Duration::from_secs(1));
-                if playback_direction == PlaybackDirection::Backward {
-                    // Seek to end, so that backward playback actually does something
-                    tween.set_elapsed(backward_start_time);
-                }
+/// A wrapper repeating any tweenable animation.
+///
+/// The [`Repeat`] tweenable loops its inner tweenable a number of times given
+/// by a [`RepeatCount`], following a [`RepeatStrategy`]. This allows looping
+/// any tweenable, including a [`Sequence`] or a [`Delay`], unlike
+/// [`Tween::with_repeat()`] which only loops a single tween. The cycle
+/// duration of a [`Repeat`] is the total duration of its inner tweenable.
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::{Vec3, curve::EaseFunction};
+/// # use std::time::Duration;
+/// # let bob_up = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformPositionLens { start: Vec3::ZERO, end: Vec3::Y });
+/// # let bob_down = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformPositionLens { start: Vec3::Y, end: Vec3::ZERO });
+/// // Bob up, pause, bob down, pause, and repeat forever
+/// let pause = Duration::from_millis(500);
+/// let idle = bob_up
+///     .then(Delay::new(pause))
+///     .then(bob_down)
+///     .then(Delay::new(pause));
+/// let anim = Repeat::new(idle, RepeatCount::Infinite).with_cycle_completed_event(true);
+/// ```
+///
+/// With [`RepeatStrategy::MirroredRepeat`], every other cycle plays the inner
+/// tweenable backward, by changing its [`playback_direction()`]. Custom inner
+/// tweenables which only support forward playback are repeated instead.
+///
+/// If the inner tweenable has an unknown duration, like a [`WaitUntil`] or a
+/// [`Spring`], each cycle lasts until the inner tweenable completes, and is
+/// followed immediately by the next one. This allows for example to play an
+/// animation each time the user presses a button. Such cycles only play
+/// forward, and are never mirrored. Their total duration remains unknown until
+/// the last cycle completes, unless repeating for a fixed duration with
+/// [`RepeatCount::For`].
+///
+/// [`playback_direction()`]: Tweenable::playback_direction
+pub struct Repeat<T: Tweenable> {
+    tweenable: T,
+    clock: AnimClock,
+    /// Progress of the cycles, if their duration is unknown.
+    unbounded: Option<UnboundedCycles>,
+    send_cycle_completed_event: bool,
+    /// Time left to step the inner tweenable with, after it requested a
+    /// retarget.
+    pending_delta: Duration,
+    playback_direction: PlaybackDirection,
+    /// Whether the inner tweenable currently plays a mirrored cycle.
+    mirrored: bool,
+}
 
-                let (mut world, entity) = make_test_env();
-                let mut event_reader_system_state: SystemState<MessageReader<CycleCompletedEvent>> =
-                    SystemState::new(&mut world);
+impl<T: Tweenable> Repeat<T> {
+    /// Create a new repeating tweenable.
+    ///
+    /// The repeat strategy defaults to [`RepeatStrategy::Repeat`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total duration of `tweenable` is zero.
+    #[must_use]
+    pub fn new(tweenable: T, count: impl Into<RepeatCount>) -> Self {
+        let count = count.into();
+        let (clock, unbounded) = match tweenable.total_duration() {
+            TotalDuration::Finite(cycle_duration) => {
+                assert!(
+                    !cycle_duration.is_zero(),
+                    "Cannot repeat a tweenable of zero duration."
+                );
+                let mut clock = AnimClock::new(cycle_duration);
+                clock.total_duration = TotalDuration::from_cycles(cycle_duration, count);
+                (clock, None)
+            }
+            // The duration of each cycle is only known once the inner tweenable completes
+            TotalDuration::Infinite => (
+                AnimClock::new(Duration::MAX),
+                Some(UnboundedCycles::new(count)),
+            ),
+        };
+        Self {
+            tweenable,
+            clock,
+            unbounded,
+            send_cycle_completed_event: false,
+            pending_delta: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+            mirrored: false,
+        }
+    }
 
-                // Loop over 2.2 seconds, so greater than one ping-pong loop (2 cycles in
-                // MirroredRepeat)
-                let tick_duration = Duration::from_millis(200);
-                let tween_duration = Duration::from_secs(1);
-                for i in 1..=11 {
-                    // Calculate expected values
-                    let (elapsed_ms, factor, mut direction, expected_state, just_completed) =
-                        match count {
-                            RepeatCount::Finite(1) => {
-                                let (elapsed_ms, state) = if playback_direction.is_forward() {
-                                    if i < 5 {
-                                        (i * 200i32, TweenState::Active)
-                                    } else {
-                                        (1000i32, TweenState::Completed)
-                                    }
-                                } else if i < 5 {
-                                    (1000i32 - i * 200i32, TweenState::Active)
-                                } else {
-                                    (0i32, TweenState::Completed)
-                                };
-                                let just_completed = i == 5;
-                                (
-                                    elapsed_ms,
-                                    elapsed_ms as f32 / 1000.0,
-                                    PlaybackDirection::Forward,
-                                    state,
-                                    just_completed,
-                                )
-                            }
-                            RepeatCount::Finite(count) => {
-                                if strategy == RepeatStrategy::Repeat {
-                                    let just_completed = i % 5 == 0;
-                                    let elapsed_ms = if playback_direction.is_forward() {
-                                        // 0.2, 0.4, 0.6, 0.8, 0.0, 0.2, 0.4, ... (x count)
-                                        (i * 200) % 1000
-                                    } else {
-                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 0.8, 0.6, ... (x count)
-                                        800 - (((i - 1) * 200 - 1000) % 1000 + 1000) % 1000
-                                    };
-                                    let factor = if i >= 10 {
-                                        if playback_direction.is_forward() {
-                                            1.0
-                                        } else {
-                                            0.0
-                                        }
-                                    } else {
-                                        elapsed_ms as f32 / 1000.0
-                                    };
-                                    let total_duration_ms = count as i32 * 1000;
-                                    let elapsed_ms = if playback_direction.is_forward() {
-                                        (i * 200).min(total_duration_ms)
-                                    } else {
-                                        (total_duration_ms - i * 200).max(0)
-                                    };
-                                    // The test is calibrates such that at i==10 the forward tick
-                                    // reaches the total duration, and the backward tick reaches
-                                    // t=0, both of which yield a Completed state
-                                    let state = if i >= 10 {
-                                        TweenState::Completed
-                                    } else {
-                                        TweenState::Active
-                                    };
-                                    (
-                                        elapsed_ms,
-                                        factor,
-                                        PlaybackDirection::Forward,
-                                        state,
-                                        just_completed,
-                                    )
-                                } else {
-                                    let i5 = i % 5;
-                                    let just_completed = i5 == 0;
+    /// Configure how the cycles repeat.
+    ///
+    /// This enables or disables cycle mirroring. See [`RepeatStrategy`] for
+    /// details.
+    #[must_use]
+    pub fn with_repeat_strategy(mut self, strategy: RepeatStrategy) -> Self {
+        self.clock.strategy = strategy;
+        self
+    }
 
-                                    // Inifinite-repeat unclamped value
-                                    let elapsed_ms = if playback_direction.is_forward() {
-                                        // i    | 1    2    3    4    5    6    7    8    9    10
-                                        // t(s) | 0.2  0.4  0.6  0.8  1.0  0.8  0.6  0.4  0.2  0.0
-                                        ((i - 5) * 200).rem_euclid(2000) - 1000
-                                    } else {
-                                        // 0.0, 0.2, 0.4, 0.6, 0.8, 1.0, 0.8, 0.6, 0.4, 0.2, 0.0,
-                                        // ...
-                                        ((i + 5) * 200).rem_euclid(2000) - 1000
-                                    }
-                                    .abs();
+    /// Enable raising a event on cycle completion.
+    ///
+    /// If enabled, the [`Repeat`] will raise a [`CycleCompletedEvent`] each
+    /// time its inner tweenable completes a cycle. For mirrored repeats, a
+    /// cycle is one playback forward **or** backward, like for
+    /// [`Tween::with_cycle_completed_event()`]. This is independent of any
+    /// event raised by the inner tweenable itself.
+    #[must_use]
+    pub fn with_cycle_completed_event(mut self, send: bool) -> Self {
+        self.send_cycle_completed_event = send;
+        self
+    }
 
-                                    // Now clamp to 'count' repeats
-                                    let (elapsed_ms, state) = if i < 10 {
-                                        (elapsed_ms, TweenState::Active)
-                                    } else {
-                                        (0, TweenState::Completed)
-                                    };
-                                    let ratio = elapsed_ms as f32 / 1000.;
+    /// Set whether the [`Repeat`] emits [`CycleCompletedEvent`].
+    ///
+    /// See [`with_cycle_completed_event()`] for details.
+    ///
+    /// [`with_cycle_completed_event()`]: Self::with_cycle_completed_event
+    pub fn set_cycle_completed_event(&mut self, send: bool) {
+        self.send_cycle_completed_event = send;
+    }
 
-                                    let total_duration_ms = count as i32 * 1000;
-                                    let elapsed_ms = if playback_direction.is_forward() {
-                                        (i * 200).min(total_duration_ms)
-                                    } else {
-                                        (total_duration_ms - i * 200).max(0)
-                                    };
+    /// Set the playback direction of the repeat.
+    ///
+    /// When playing backward, the cycles play in reverse order, from the last
+    /// one to the first one, and the inner tweenable plays backward during
+    /// non-mirrored cycles.
+    ///
+    /// See [`Tweenable::set_playback_direction()`] for details.
+    #[must_use]
+    pub fn with_playback_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.set_playback_direction(direction);
+        self
+    }
 
-                                    // Once Completed, the direction doesn't change
-                                    let direction = if playback_direction.is_forward() {
-                                        //           v [completion]
-                                        // 2468X 86420 00
-                                        // ffffb bbbbb bb
-                                        if i >= 5 {
-                                            PlaybackDirection::Backward
-                                        } else {
-                                            PlaybackDirection::Forward
-                                        }
-                                    } else {
-                                        //           v [completion]
-                                        // 86420 2468X XX
-                                        // bbbbb fffff ff
-                                        if i <= 5 {
-                                            PlaybackDirection::Backward
-                                        } else {
-                                            PlaybackDirection::Forward
-                                        }
-                                    };
+    /// Chain another [`Tweenable`] after this tweenable, making a [`Sequence`]
+    /// with the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence
+    where
+        T: 'static,
+    {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
 
-                                    (elapsed_ms, ratio, direction, state, just_completed)
-                                }
-                            }
-                            RepeatCount::Infinite => {
-                                if strategy == RepeatStrategy::Repeat {
-                                    let just_completed = i % 5 == 0;
-                                    let elapsed_ms = if playback_direction.is_forward() {
-                                        // 0.2, 0.4, 0.6, 0.8, 0.0, 0.2, 0.4, ...
-                                        (i * 200) % 1000
-                                    } else {
-                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 0.8, 0.6, ...
-                                        800 - (((i - 1) * 200 - 1000) % 1000 + 1000) % 1000
-                                    };
-                                    (
-                                        elapsed_ms,
-                                        elapsed_ms as f32 / 1000.0,
-                                        PlaybackDirection::Forward,
-                                        TweenState::Active,
-                                        just_completed,
-                                    )
-                                } else {
-                                    let elapsed_ms = if playback_direction.is_forward() {
-                                        // 0.2, 0.4, 0.6, 0.8, 1.0, 0.8, 0.6, 0.4, 0.2, 0.0, 0.2,
-                                        // 0.4, ...
-                                        ((i - 5) * 200).rem_euclid(2000) - 1000
-                                    } else {
-                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 0.2, 0.4, 0.6, 0.8, 1.0, 0.8,
-                                        // 0.6, ...
-                                        (i * 200).rem_euclid(2000) - 1000
-                                    }
-                                    .abs();
-                                    let factor = elapsed_ms as f32 / 1000.0;
-                                    let elapsed_ms = if playback_direction.is_forward() {
-                                        // 0.2, 0.4, 0.6, 0.8, 1.0, 1.2, 1.4, 1.6, ... (seconds)
-                                        (i * 200).rem_euclid(2000)
-                                    } else {
-                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 1.8, 1.6, 1.4, ... (seconds)
-                                        (2000i32 - (i - 5) * 200).rem_euclid(2000)
-                                    };
-                                    let direction = if playback_direction.is_forward() {
-                                        // 2468X 86420 24
-                                        // ffffb bbbbf ff
-                                        if (i % 10) >= 5 {
-                                            PlaybackDirection::Backward
-                                        } else {
-                                            PlaybackDirection::Forward
-                                        }
-                                    } else {
-                                        // 86420 2468X 86
-                                        // bbbbb fffff bb
-                                        if ((i - 1) % 10) >= 5 {
-                                            PlaybackDirection::Backward
-                                        } else {
-                                            PlaybackDirection::Forward
-                                        }
-                                    };
-                                    let just_completed = (i % 5) == 0;
-                                    (
-                                        elapsed_ms,
-                                        factor,
-                                        direction,
-                                        TweenState::Active,
-                                        just_completed,
-                                    )
-                                }
-                            }
-                            RepeatCount::For(_) => panic!("Untested"),
-                        };
-                    if playback_direction.is_backward() {
-                        direction = !direction;
-                    }
-                    let expected_translation = Vec3::splat(factor);
-                    let elapsed = Duration::from_millis(elapsed_ms as u64);
-                    let cycles_completed = match count {
-                        RepeatCount::Infinite => elapsed,
-                        RepeatCount::Finite(count) => elapsed.min(tween_duration * count),
-                        RepeatCount::For(time) => elapsed.min(time),
-                    }
-                    .div_duration_f64(tween_duration)
-                        as u32;
-                    println!(
-                        "+ Expected: elapsed={:?} factor={} times_completed={} direction={:?} state={:?} just_completed={} translation={:?}",
-                        elapsed, factor, cycles_completed, direction, expected_state, just_completed, expected_translation
-                    );
+    /// Get the inner tweenable being repeated.
+    #[must_use]
+    pub fn tweenable(&self) -> &T {
+        &self.tweenable
+    }
 
-                    // Tick the tween
-                    let actual_state = manual_tick_component(
-                        Entity::PLACEHOLDER, // unused in this test
-                        tick_duration,
-                        &mut tween,
-                        &mut world,
-                        entity,
-                    );
+    /// Get the index of the current cycle, and the position of the inner
+    /// tweenable in that cycle, without mirroring.
+    fn cycle_position(&self) -> (u32, Duration) {
+        let cycle_duration = self.clock.cycle_duration;
+        let elapsed = self.clock.elapsed();
+        let mut index = (elapsed.as_nanos() / cycle_duration.as_nanos()) as u32;
+        // Once completed, stay at the end of the last cycle instead of wrapping around
+        // to the start of the next one.
+        let at_end =
+            matches!(self.clock.total_duration, TotalDuration::Finite(total) if elapsed >= total);
+        if at_end && index > 0 && elapsed <= cycle_duration * index {
+            index -= 1;
+        }
+        let local = elapsed
+            .saturating_sub(cycle_duration * index)
+            .min(cycle_duration);
+        (index, local)
+    }
 
-                    // Check actual values
-                    assert_eq!(tween.is_cycle_mirrored(), direction != playback_direction);
-                    assert_eq!(actual_state, expected_state);
-                    assert_eq!(tween.elapsed(), elapsed);
-                    assert_eq!(tween.cycles_completed(), cycles_completed);
-                    let transform = world.entity(entity).get::<Transform>().unwrap();
-                    assert_approx_eq!(expected_translation, transform.translation, 1e-5);
-                    assert_approx_eq!(Quat::IDENTITY, transform.rotation, 1e-5);
+    /// Get the position of the inner tweenable, accounting for mirroring.
+    fn inner_position(&self) -> (bool, Duration) {
+        let (index, local) = self.cycle_position();
+        if self.clock.is_cycle_mirrored(index) {
+            (true, self.clock.cycle_duration - local)
+        } else {
+            (false, local)
+        }
+    }
 
-                    // Messages are only sent when playing forward
-                    if playback_direction.is_forward() {
-                        let mut event_reader =
-                            event_reader_system_state.get_mut(&mut world).unwrap();
-                        let event = event_reader.read().next();
-                        if just_completed {
-                            assert!(event.is_some());
-                            if let Some(event) = event {
-                                let AnimTargetKind::Component {
-                                    entity: comp_target,
-                                } = &event.target
-                                else {
-                                    panic!("Expected AnimTargetKind::Component");
-                                };
-                                assert_eq!(*comp_target, entity);
-                            }
-                        } else {
-                            assert!(event.is_none());
-                        }
-                    }
-                }
+    /// Check if stepping the inner tweenable requires a different target type
+    /// than the current one.
+    fn needs_retarget(&self, target_type_id: &TypeId) -> bool {
+        self.tweenable
+            .target_type_id()
+            .is_some_and(|type_id| type_id != *target_type_id)
+    }
 
-                // Can't rewind infinite tweens moving backward, they don't have an endpoint
-                if tween.total_duration().is_finite()
-                    || (playback_direction != PlaybackDirection::Backward)
-                {
-                    // Rewind
-                    println!("+ Rewind");
-                    tween.rewind();
-                    assert_eq!(tween.playback_direction(), playback_direction); // does not change
-                    if playback_direction.is_forward() {
-                        assert_eq!(tween.elapsed(), Duration::ZERO);
-                        assert_eq!(tween.cycles_completed(), 0);
-                    } else {
-                        assert_eq!(tween.elapsed(), backward_start_time);
-                        let cycles_completed = match count {
-                            RepeatCount::Infinite => backward_start_time,
-                            RepeatCount::Finite(count) => {
-                                backward_start_time.min(tween_duration * count)
-                            }
-                            RepeatCount::For(time) => backward_start_time.min(time),
-                        }
-                        .div_duration_f64(tween_duration)
-                            as u32;
-                        assert_eq!(cycles_completed, tween.cycles_completed());
-                    }
+    /// Update the playback direction of the inner tweenable, which is reversed
+    /// during mirrored cycles.
+    ///
+    /// Returns `false` if the inner tweenable doesn't support that direction.
+    fn sync_inner_direction(&mut self) -> bool {
+        let direction = if self.mirrored {
+            !self.playback_direction
+        } else {
+            self.playback_direction
+        };
+        self.tweenable.set_playback_direction(direction);
+        self.tweenable.playback_direction() == direction
+    }
 
-                    // Dummy tick to update target
-                    let actual_state = manual_tick_component(
-                        Entity::PLACEHOLDER, // unused in this test
-                        Duration::ZERO,
-                        &mut tween,
-                        &mut world,
-                        entity,
-                    );
-                    assert_eq!(TweenState::Active, actual_state);
-                    let expected_translation = if playback_direction.is_backward()
-                        && strategy != RepeatStrategy::MirroredRepeat
-                    {
-                        Vec3::ONE
+    fn notify_cycles(&self, count: u32, notify_completed: &mut dyn FnMut()) {
+        if self.send_cycle_completed_event {
+            for _ in 0..count {
+                notify_completed();
+            }
+        }
+    }
+}
+
+/// Progress of a [`Repeat`] whose inner tweenable has an unknown duration.
+///
+/// Because the duration of each cycle is only known once the inner tweenable
+/// completes, the cycles are counted as they complete instead of being derived
+/// from the elapsed time.
+#[derive(Debug, Clone, Copy)]
+struct UnboundedCycles {
+    count: RepeatCount,
+    /// Number of cycles completed.
+    completed: u32,
+    /// Total elapsed time since the first cycle started.
+    elapsed: Duration,
+    /// Elapsed time when the current cycle started.
+    cycle_start: Duration,
+}
+
+impl UnboundedCycles {
+    fn new(count: RepeatCount) -> Self {
+        Self {
+            count,
+            completed: 0,
+            elapsed: Duration::ZERO,
+            cycle_start: Duration::ZERO,
+        }
+    }
+
+    fn is_completed(&self) -> bool {
+        match self.count {
+            RepeatCount::Finite(count) => self.completed >= count,
+            RepeatCount::For(duration) => self.elapsed >= duration,
+            RepeatCount::Infinite => false,
+        }
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        match self.count {
+            RepeatCount::Finite(count) if self.completed >= count => {
+                TotalDuration::Finite(self.elapsed)
+            }
+            RepeatCount::For(duration) => TotalDuration::Finite(duration),
+            RepeatCount::Finite(_) | RepeatCount::Infinite => TotalDuration::Infinite,
+        }
+    }
+}
+
+impl<T: Tweenable> Tweenable for Repeat<T> {
+    fn cycle_duration(&self) -> Duration {
+        if self.unbounded.is_some() {
+            // Only known once the current cycle completed
+            return self
+                .tweenable
+                .total_duration()
+                .as_finite()
+                .unwrap_or(Duration::MAX);
+        }
+        self.clock.cycle_duration
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        if let Some(cycles) = &self.unbounded {
+            return cycles.total_duration();
+        }
+        self.clock.total_duration
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        if let Some(cycles) = &mut self.unbounded {
+            // The start time of the cycles after the current one are unknown, so only
+            // seek within the first cycle.
+            *cycles = UnboundedCycles::new(cycles.count);
+            self.pending_delta = Duration::ZERO;
+            self.tweenable.rewind();
+            self.tweenable.set_elapsed(elapsed);
+            cycles.elapsed = self.tweenable.elapsed();
+            return;
+        }
+        self.clock.set_elapsed(elapsed, self.playback_direction);
+        self.pending_delta = Duration::ZERO;
+        let (mirrored, position) = self.inner_position();
+        self.mirrored = mirrored;
+        let _ = self.sync_inner_direction();
+        self.tweenable.set_elapsed(position);
+    }
+
+    fn elapsed(&self) -> Duration {
+        if let Some(cycles) = &self.unbounded {
+            return cycles.elapsed;
+        }
+        self.clock.elapsed()
+    }
+
+    fn step(
+        &mut self,
+        tween_id: Entity,
+        delta: Duration,
+        mut target: MutUntyped,
+        target_type_id: &TypeId,
+        notify_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        if let Some(cycles) = &mut self.unbounded {
+            if cycles.is_completed() {
+                return (TweenState::Completed, false);
+            }
+            let mut remaining = delta.saturating_add(std::mem::take(&mut self.pending_delta));
+            if let RepeatCount::For(duration) = cycles.count {
+                remaining = remaining.min(duration - cycles.elapsed);
+            }
+            let mut rewound = false;
+            loop {
+                let prev_elapsed = self.tweenable.elapsed();
+                let (inner_state, retarget) = self.tweenable.step(
+                    tween_id,
+                    remaining,
+                    target.reborrow(),
+                    target_type_id,
+                    notify_completed,
+                );
+                if retarget || inner_state == TweenState::Active {
+                    // The inner tweenable consumed the entire step, possibly keeping some of
+                    // it to continue after the caller retargeted.
+                    cycles.elapsed += remaining;
+                    let state = if cycles.is_completed() && !retarget {
+                        TweenState::Completed
                     } else {
-                        Vec3::ZERO
+                        TweenState::Active
                     };
-                    let transform = world.entity(entity).get::<Transform>().unwrap();
-                    assert_approx_eq!(expected_translation, transform.translation, 1e-5);
-                    assert_approx_eq!(Quat::IDENTITY, transform.rotation, 1e-5);
+                    return (state, retarget);
+                }
+
+                // The inner tweenable completed a cycle, which resolved its duration
+                let consumed = self
+                    .tweenable
+                    .total_duration()
+                    .as_finite()
+                    .unwrap_or_default()
+                    .saturating_sub(prev_elapsed)
+                    .min(remaining);
+                remaining -= consumed;
+                cycles.elapsed += consumed;
+                cycles.completed += 1;
+                if self.send_cycle_completed_event {
+                    notify_completed();
+                }
+                if cycles.is_completed() {
+                    return (TweenState::Completed, false);
+                }
+
+                // Loop around. If the inner tweenable then starts by animating another
+                // target type, ask the caller to retarget before continuing.
+                cycles.cycle_start = cycles.elapsed;
+                self.tweenable.rewind();
+                if self
+                    .tweenable
+                    .target_type_id()
+                    .is_some_and(|type_id| type_id != *target_type_id)
+                {
+                    self.pending_delta = remaining;
+                    return (TweenState::Active, true);
+                }
+
+                // A cycle completing without consuming any time would loop forever, so wait
+                // for the next step to start the next cycle.
+                if rewound && consumed.is_zero() {
+                    cycles.elapsed += remaining;
+                    return (TweenState::Active, false);
+                }
+                rewound = true;
+            }
+        }
+
+        if self.clock.state(self.playback_direction) == TweenState::Completed {
+            return (TweenState::Completed, false);
+        }
+
+        let prev_elapsed = self.clock.elapsed();
+        let (state, _) = if self.playback_direction.is_forward() {
+            self.clock.tick(delta)
+        } else {
+            self.clock.tick_back(delta)
+        };
+
+        // Step the inner tweenable by the time the clock moved, looping around each
+        // time it completes a cycle.
+        let consumed = match self.clock.total_duration {
+            TotalDuration::Finite(_) => self.clock.elapsed().abs_diff(prev_elapsed),
+            TotalDuration::Infinite => delta,
+        };
+        let mut remaining = consumed.saturating_add(std::mem::take(&mut self.pending_delta));
+        loop {
+            // Time left until the inner tweenable completes, in its own direction
+            let left = if self.tweenable.playback_direction().is_forward() {
+                self.clock
+                    .cycle_duration
+                    .saturating_sub(self.tweenable.elapsed())
+            } else {
+                self.tweenable.elapsed()
+            };
+            let step_delta = remaining.min(left);
+            let (inner_state, retarget) = self.tweenable.step(
+                tween_id,
+                step_delta,
+                target.reborrow(),
+                target_type_id,
+                notify_completed,
+            );
+            remaining -= step_delta;
+            if retarget {
+                self.pending_delta = remaining;
+                return (TweenState::Active, true);
+            }
+            if inner_state == TweenState::Active {
+                break;
+            }
+
+            // The inner tweenable completed one cycle
+            self.notify_cycles(1, notify_completed);
+            if remaining.is_zero() && state == TweenState::Completed {
+                break;
+            }
+
+            // Loop around, either by playing the inner tweenable in the other direction
+            // if mirrored, or by rewinding it otherwise. If the inner tweenable then
+            // starts by animating another target type, ask the caller to retarget
+            // before continuing.
+            let mirror = self.clock.strategy == RepeatStrategy::MirroredRepeat;
+            self.mirrored = mirror && !self.mirrored;
+            if !(mirror && self.sync_inner_direction()) {
+                // Inner tweenables which only play forward are repeated instead
+                self.mirrored = false;
+                self.tweenable.rewind();
+            }
+            if self.needs_retarget(target_type_id) {
+                self.pending_delta = remaining;
+                return (TweenState::Active, true);
+            }
+        }
+
+        (state, false)
+    }
+
+    fn rewind(&mut self) {
+        if let Some(cycles) = &mut self.unbounded {
+            *cycles = UnboundedCycles::new(cycles.count);
+            self.pending_delta = Duration::ZERO;
+            self.tweenable.rewind();
+            return;
+        }
+        self.clock.rewind(self.playback_direction);
+        self.pending_delta = Duration::ZERO;
+        let (mirrored, _) = self.inner_position();
+        self.mirrored = mirrored;
+        let _ = self.sync_inner_direction();
+        self.tweenable.rewind();
+    }
+
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        // Cycles of unknown duration only play forward
+        if self.unbounded.is_none() {
+            self.playback_direction = direction;
+            let _ = self.sync_inner_direction();
+        }
+    }
+
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
+    }
+
+    fn visit_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        include_from: bool,
+        direction: PlaybackDirection,
+        visitor: &mut dyn FnMut(&Marker),
+    ) {
+        if let Some(cycles) = &self.unbounded {
+            // Only the start of the current cycle is known, so visit the portion of
+            // the step overlapping that cycle.
+            if to >= cycles.cycle_start {
+                self.tweenable.visit_markers(
+                    from.saturating_sub(cycles.cycle_start),
+                    to - cycles.cycle_start,
+                    include_from || from < cycles.cycle_start,
+                    direction,
+                    visitor,
+                );
+            }
+            return;
+        }
+
+        let cycle_duration = self.clock.cycle_duration;
+        let mirrored_repeat = self.clock.strategy == RepeatStrategy::MirroredRepeat;
+        let (from, to) = if self.clock.total_duration.is_finite() {
+            (from, to)
+        } else {
+            let period = if mirrored_repeat {
+                cycle_duration * 2
+            } else {
+                cycle_duration
+            };
+            unwrap_elapsed(from, to, direction, period)
+        };
+
+        // Visit the inner tweenable over the portion of the step overlapping each cycle,
+        // in the direction the inner tweenable plays during that cycle.
+        for index in overlapping_cycles(from, to, cycle_duration) {
+            let start = cycle_duration * index as u32;
+            let end = start + cycle_duration;
+            if let TotalDuration::Finite(total_duration) = self.clock.total_duration {
+                if start >= total_duration && index > 0 {
+                    break;
+                }
+            }
+            let mirrored = self.clock.is_cycle_mirrored(index as u32);
+            let local_elapsed = |elapsed: Duration| {
+                let local_elapsed = elapsed.clamp(start, end) - start;
+                if mirrored {
+                    cycle_duration - local_elapsed
+                } else {
+                    local_elapsed
                 }
+            };
+            // If the step enters the cycle from outside, the endpoint it enters through is
+            // crossed. With mirroring though, that endpoint is the same point as the one
+            // the other cycle ends on, so only cross it once.
+            let outside = from < start || from > end;
+            let include_from = include_from || (outside && !mirrored_repeat);
+            let direction = if mirrored { !direction } else { direction };
+            self.tweenable.visit_markers(
+                local_elapsed(from),
+                local_elapsed(to),
+                include_from,
+                direction,
+                visitor,
+            );
+        }
+    }
+
+    fn marker_time(&self, name: &str) -> Option<Duration> {
+        self.tweenable.marker_time(name)
+    }
+
+    fn poll_conditions(&self, world: &World) {
+        self.tweenable.poll_conditions(world);
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        let snapshot = TweenableSnapshot::new("Repeat", self.elapsed(), self.playback_direction)
+            .with_children(vec![self.tweenable.save_state()]);
+        match &self.unbounded {
+            Some(cycles) => snapshot.with_index(cycles.completed as usize),
+            None => snapshot,
+        }
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(1)?;
+        if let Some(cycles) = &mut self.unbounded {
+            let completed = snapshot.index.ok_or(TweeningError::SnapshotMismatch)?;
+            self.tweenable.restore_state(&snapshot.children[0])?;
+            cycles.completed = completed as u32;
+            cycles.elapsed = snapshot.elapsed;
+            cycles.cycle_start = snapshot.elapsed.saturating_sub(self.tweenable.elapsed());
+            self.pending_delta = Duration::ZERO;
+            return Ok(());
+        }
+        self.set_playback_direction(snapshot.direction);
+        self.set_elapsed(snapshot.elapsed);
+        self.tweenable.restore_state(&snapshot.children[0])
+    }
+
+    fn cycles_completed(&self) -> u32 {
+        match &self.unbounded {
+            Some(cycles) => cycles.completed,
+            None => self.elapsed().div_duration_f64(self.cycle_duration()) as u32,
+        }
+    }
+
+    fn target_type_id(&self) -> Option<TypeId> {
+        self.tweenable.target_type_id()
+    }
+
+    fn visit_target_types(&self, visitor: &mut dyn FnMut(TypeId)) {
+        self.tweenable.visit_target_types(visitor);
+    }
+}
+
+impl<T: Tweenable + 'static> From<Repeat<T>> for BoxedTweenable {
+    fn from(t: Repeat<T>) -> Self {
+        Box::new(t)
+    }
+}
+
+/// A wrapper remapping the time of any tweenable animation.
+///
+/// The [`TimeWarp`] tweenable drives its inner tweenable through a time
+/// remapping function. Its own elapsed time, called the _outer_ time, is
+/// normalized over its duration, passed through the warp function, then
+/// scaled back to produce the elapsed time of the inner tweenable, called the
+/// _local_ time. This allows applying an ease curve to the clock of an entire
+/// [`Sequence`], unlike the [`EaseMethod`] of a [`Tween`] which only shapes that
+/// single tween.
+///
+/// The duration of a [`TimeWarp`] is the total duration of its inner tweenable,
+/// which therefore must be finite. The warp function should map `0` to `0` and
+/// `1` to `1`, so that the inner tweenable plays entirely.
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::{Vec3, curve::EaseFunction};
+/// # use std::time::Duration;
+/// # let jump = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformPositionLens { start: Vec3::ZERO, end: Vec3::Y });
+/// # let spin = Delay::new(Duration::from_secs(1));
+/// # let land = Tween::new(EaseFunction::Linear, Duration::from_secs(1), TransformPositionLens { start: Vec3::Y, end: Vec3::ZERO });
+/// // Play the middle third of the sequence in slow motion, during half of the
+/// // total duration.
+/// fn slow_motion(t: f32) -> f32 {
+///     if t < 0.25 {
+///         t * 4. / 3.
+///     } else if t < 0.75 {
+///         1. / 3. + (t - 0.25) * 2. / 3.
+///     } else {
+///         2. / 3. + (t - 0.75) * 4. / 3.
+///     }
+/// }
+/// let seq = jump.then(spin).then(land);
+/// let anim = TimeWarp::new(seq, EaseMethod::CustomFunction(slow_motion));
+/// ```
+///
+/// The warp function is generally monotonic. Where it decreases, the inner
+/// tweenable plays backward, which requires it to support backward playback.
+///
+/// [`EaseMethod`]: crate::EaseMethod
+pub struct TimeWarp<T: Tweenable> {
+    tweenable: T,
+    warp: EaseMethod,
+    duration: Duration,
+    /// Outer elapsed time.
+    elapsed: Duration,
+    /// Local elapsed time of the inner tweenable, mapped from the outer time.
+    local_elapsed: Duration,
+    playback_direction: PlaybackDirection,
+    /// Direction the inner tweenable was last stepped in.
+    inner_direction: PlaybackDirection,
+}
+
+impl<T: Tweenable> TimeWarp<T> {
+    /// Create a new time-warping tweenable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total duration of `tweenable` is infinite or zero.
+    #[must_use]
+    pub fn new(tweenable: T, warp: impl Into<EaseMethod>) -> Self {
+        let duration = tweenable.total_duration().as_finite().expect(
+            "Cannot warp the time of a tweenable of infinite duration. Its total duration must be finite.",
+        );
+        assert!(
+            !duration.is_zero(),
+            "Cannot warp the time of a tweenable of zero duration."
+        );
+        Self {
+            tweenable,
+            warp: warp.into(),
+            duration,
+            elapsed: Duration::ZERO,
+            local_elapsed: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+            inner_direction: PlaybackDirection::Forward,
+        }
+    }
+
+    /// Chain another [`Tweenable`] after this tweenable, making a [`Sequence`]
+    /// with the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence
+    where
+        T: 'static,
+    {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+
+    /// Set the playback direction of the time warp.
+    ///
+    /// See [`Tweenable::set_playback_direction()`] for details.
+    #[must_use]
+    pub fn with_playback_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.set_playback_direction(direction);
+        self
+    }
+
+    /// Get the time remapping function.
+    pub fn warp(&self) -> &EaseMethod {
+        &self.warp
+    }
+
+    /// Get the inner tweenable.
+    pub fn tweenable(&self) -> &T {
+        &self.tweenable
+    }
+
+    /// Get the local elapsed time of the inner tweenable.
+    ///
+    /// This is the [`elapsed()`] time of the time warp, remapped through the
+    /// warp function.
+    ///
+    /// [`elapsed()`]: Tweenable::elapsed
+    pub fn local_elapsed(&self) -> Duration {
+        self.local_elapsed
+    }
+
+    /// Map an outer time to the local time of the inner tweenable.
+    fn local_time(&self, elapsed: Duration) -> Duration {
+        let ratio = elapsed.div_duration_f64(self.duration) as f32;
+        let local_ratio = self.warp.sample(ratio).clamp(0., 1.);
+        self.duration.mul_f64(local_ratio as f64)
+    }
+
+    /// Map a local time of the inner tweenable back to the outer time.
+    ///
+    /// This searches the earliest outer time reaching the local time, assuming
+    /// the warp function is monotonic.
+    fn outer_time(&self, local_elapsed: Duration) -> Duration {
+        let target = local_elapsed.div_duration_f64(self.duration) as f32;
+        let (mut lo, mut hi) = (0_f32, 1_f32);
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.;
+            if self.warp.sample(mid) < target {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        self.duration.mul_f64(hi as f64)
+    }
+
+    /// Set the playback direction of the inner tweenable, if it changed.
+    fn set_inner_direction(&mut self, direction: PlaybackDirection) {
+        if self.inner_direction != direction {
+            self.inner_direction = direction;
+            self.tweenable.set_playback_direction(direction);
+        }
+    }
+}
+
+impl<T: Tweenable> Tweenable for TimeWarp<T> {
+    fn cycle_duration(&self) -> Duration {
+        self.duration
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        TotalDuration::Finite(self.duration)
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration);
+        self.local_elapsed = self.local_time(self.elapsed);
+        self.tweenable.set_elapsed(self.local_elapsed);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn step(
+        &mut self,
+        tween_id: Entity,
+        delta: Duration,
+        target: MutUntyped,
+        target_type_id: &TypeId,
+        notify_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        self.elapsed = if self.playback_direction.is_forward() {
+            self.elapsed.saturating_add(delta).min(self.duration)
+        } else {
+            self.elapsed.saturating_sub(delta)
+        };
+
+        // Step the inner tweenable by the time its local clock moved, in whichever
+        // direction it moved. If the inner tweenable requests a retarget, it saved
+        // any time left internally, and the next step with a zero delta consumes it.
+        let local_elapsed = self.local_time(self.elapsed);
+        if local_elapsed != self.local_elapsed {
+            self.set_inner_direction(if local_elapsed > self.local_elapsed {
+                PlaybackDirection::Forward
+            } else {
+                PlaybackDirection::Backward
+            });
+        }
+        let local_delta = local_elapsed.abs_diff(self.local_elapsed);
+        self.local_elapsed = local_elapsed;
+        let (_, retarget) = self.tweenable.step(
+            tween_id,
+            local_delta,
+            target,
+            target_type_id,
+            notify_completed,
+        );
+        if retarget {
+            return (TweenState::Active, true);
+        }
+
+        let completed = match self.playback_direction {
+            PlaybackDirection::Forward => self.elapsed >= self.duration,
+            PlaybackDirection::Backward => self.elapsed.is_zero(),
+        };
+        if completed {
+            (TweenState::Completed, false)
+        } else {
+            (TweenState::Active, false)
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.elapsed = match self.playback_direction {
+            PlaybackDirection::Forward => Duration::ZERO,
+            PlaybackDirection::Backward => self.duration,
+        };
+        self.local_elapsed = self.local_time(self.elapsed);
+        self.set_inner_direction(self.playback_direction);
+        self.tweenable.rewind();
+    }
+
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.playback_direction = direction;
+        self.set_inner_direction(direction);
+    }
+
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
+    }
+
+    fn visit_markers(
+        &self,
+        from: Duration,
+        to: Duration,
+        include_from: bool,
+        direction: PlaybackDirection,
+        visitor: &mut dyn FnMut(&Marker),
+    ) {
+        let local_from = self.local_time(from);
+        let local_to = self.local_time(to);
+        let direction = match local_from.cmp(&local_to) {
+            Ordering::Less => PlaybackDirection::Forward,
+            Ordering::Greater => PlaybackDirection::Backward,
+            Ordering::Equal => direction,
+        };
+        self.tweenable
+            .visit_markers(local_from, local_to, include_from, direction, visitor);
+    }
+
+    fn marker_time(&self, name: &str) -> Option<Duration> {
+        self.tweenable
+            .marker_time(name)
+            .map(|local_elapsed| self.outer_time(local_elapsed))
+    }
+
+    fn poll_conditions(&self, world: &World) {
+        self.tweenable.poll_conditions(world);
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("TimeWarp", self.elapsed(), self.playback_direction)
+            .with_children(vec![self.tweenable.save_state()])
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(1)?;
+        self.set_playback_direction(snapshot.direction);
+        self.set_elapsed(snapshot.elapsed);
+        self.tweenable.restore_state(&snapshot.children[0])
+    }
+
+    fn target_type_id(&self) -> Option<TypeId> {
+        self.tweenable.target_type_id()
+    }
+
+    fn visit_target_types(&self, visitor: &mut dyn FnMut(TypeId)) {
+        self.tweenable.visit_target_types(visitor);
+    }
+}
+
+impl<T: Tweenable + 'static> From<TimeWarp<T>> for BoxedTweenable {
+    fn from(t: TimeWarp<T>) -> Self {
+        Box::new(t)
+    }
+}
+
+/// Advance a damped harmonic oscillator by the given time, in seconds.
+///
+/// This takes the displacement `x0` from the rest position and the velocity
+/// `v0`, and returns the new displacement and velocity. This uses the analytic
+/// solution of the oscillator, so the motion doesn't depend on how time is
+/// split between steps.
+fn damped_spring(x0: f32, v0: f32, stiffness: f32, damping: f32, mass: f32, dt: f32) -> (f32, f32) {
+    let omega = (stiffness / mass).sqrt();
+    let zeta = damping / (2. * (stiffness * mass).sqrt());
+    if (zeta - 1.).abs() < 1e-4 {
+        // Critically damped
+        let decay = (-omega * dt).exp();
+        let b = v0 + omega * x0;
+        ((x0 + b * dt) * decay, (v0 - omega * b * dt) * decay)
+    } else if zeta < 1. {
+        // Under-damped; oscillates around the rest position
+        let omega_d = omega * (1. - zeta * zeta).sqrt();
+        let decay = (-zeta * omega * dt).exp();
+        let (sin, cos) = (omega_d * dt).sin_cos();
+        let x = decay * (x0 * cos + (v0 + zeta * omega * x0) / omega_d * sin);
+        let v = decay * (v0 * cos - (x0 * omega * omega + zeta * omega * v0) / omega_d * sin);
+        (x, v)
+    } else {
+        // Over-damped
+        let root = (zeta * zeta - 1.).sqrt();
+        let r1 = -omega * (zeta - root);
+        let r2 = -omega * (zeta + root);
+        let c1 = (v0 - r2 * x0) / (r1 - r2);
+        let c2 = x0 - c1;
+        let (e1, e2) = ((r1 * dt).exp(), (r2 * dt).exp());
+        (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+    }
+}
+
+/// A physics-based damped spring animation.
+///
+/// The [`Spring`] tweenable simulates a damped harmonic oscillator, and applies
+/// its position to the animation target through a [`Lens`], like a [`Tween`]
+/// does with its eased cycle fraction. Instead of a duration and an ease
+/// method, the motion is configured by the spring's stiffness, its damping,
+/// and the mass it moves. The position is a lens ratio; the spring starts at
+/// `0` and moves toward a target position of `1`, which corresponds to the
+/// end value of the lens. Depending on its damping, the spring may overshoot
+/// its target, producing ratios outside of `[0:1]`.
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::Vec3;
+/// let spring = Spring::new(TransformScaleLens {
+///     start: Vec3::ONE,
+///     end: Vec3::splat(1.2),
+/// })
+/// .with_stiffness(300.)
+/// .with_damping(10.);
+/// ```
+///
+/// The spring completes once it settled, when both its distance to the
+/// target and its velocity are below the rest threshold. Until then, its total
+/// duration is [`TotalDuration::Infinite`]. Once settled, its total duration
+/// is the time it took to settle, so a [`Sequence`] continues with its next
+/// tweenable.
+///
+/// The target can be changed while the spring moves with [`set_target()`],
+/// which keeps the current position and velocity, so the motion stays smooth.
+/// To retarget a spring animated by a [`TweenAnim`], access it with
+/// [`TweenAnim::tweenable_mut()`]. For example, a hover effect can spring
+/// toward `1` when hovered, and back toward `0` when not, even while the
+/// previous motion is still ongoing.
+///
+/// Seeking with [`set_elapsed()`] simulates the spring from its initial state,
+/// toward its current target.
+///
+/// [`set_target()`]: Self::set_target
+/// [`set_elapsed()`]: Tweenable::set_elapsed
+/// [`TweenAnim`]: crate::TweenAnim
+/// [`TweenAnim::tweenable_mut()`]: crate::TweenAnim::tweenable_mut
+pub struct Spring {
+    action: LensAction,
+    /// Type ID of the target.
+    type_id: TypeId,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    rest_threshold: f32,
+    initial_velocity: f32,
+    target: f32,
+    position: f32,
+    velocity: f32,
+    elapsed: Duration,
+    settled: bool,
+}
+
+impl Spring {
+    /// Create a new spring animation with default parameters.
+    ///
+    /// The default spring has a stiffness of `170`, a damping of `26`, and a
+    /// mass of `1`, which settles in about half a second with a barely visible
+    /// overshoot. The target type is implicitly determined by the type `T` of
+    /// the [`Lens<T>`] argument.
+    #[must_use]
+    pub fn new<T, L>(lens: L) -> Self
+    where
+        T: 'static,
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        Self {
+            action: LensAction::new(lens),
+            type_id: TypeId::of::<T>(),
+            stiffness: 170.,
+            damping: 26.,
+            mass: 1.,
+            rest_threshold: 1e-3,
+            initial_velocity: 0.,
+            target: 1.,
+            position: 0.,
+            velocity: 0.,
+            elapsed: Duration::ZERO,
+            settled: false,
+        }
+    }
+
+    /// Chain another [`Tweenable`] after this spring, making a [`Sequence`]
+    /// with the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+
+    /// Set the stiffness of the spring.
+    ///
+    /// A stiffer spring moves faster toward its target.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stiffness is not strictly positive.
+    #[must_use]
+    pub fn with_stiffness(mut self, stiffness: f32) -> Self {
+        assert!(
+            stiffness > 0.,
+            "Spring stiffness must be strictly positive."
+        );
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Set the damping of the spring.
+    ///
+    /// A spring with less damping oscillates more around its target before
+    /// settling. A spring without any damping never settles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the damping is negative.
+    #[must_use]
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        assert!(damping >= 0., "Spring damping must be positive or zero.");
+        self.damping = damping;
+        self
+    }
+
+    /// Set the mass moved by the spring.
+    ///
+    /// A heavier mass moves slower, and oscillates longer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mass is not strictly positive.
+    #[must_use]
+    pub fn with_mass(mut self, mass: f32) -> Self {
+        assert!(mass > 0., "Spring mass must be strictly positive.");
+        self.mass = mass;
+        self
+    }
+
+    /// Set the initial velocity of the spring, in lens ratio per second.
+    #[must_use]
+    pub fn with_initial_velocity(mut self, velocity: f32) -> Self {
+        self.initial_velocity = velocity;
+        self.velocity = velocity;
+        self
+    }
+
+    /// Set the rest threshold of the spring.
+    ///
+    /// The spring settles once both its distance to the target and its
+    /// velocity are below this threshold, in lens ratio and lens ratio per
+    /// second respectively. The default is `0.001`.
+    #[must_use]
+    pub fn with_rest_threshold(mut self, threshold: f32) -> Self {
+        self.rest_threshold = threshold;
+        self
+    }
+
+    /// Get the current target position of the spring, as a lens ratio.
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Change the target position of the spring, as a lens ratio.
+    ///
+    /// The spring continues from its current position and velocity, so the
+    /// motion stays smooth. A settled spring starts moving again, which for a
+    /// spring animated by a [`TweenAnim`] requires the animation to not be
+    /// destroyed on completion.
+    ///
+    /// [`TweenAnim`]: crate::TweenAnim
+    pub fn set_target(&mut self, target: f32) {
+        if target != self.target {
+            self.target = target;
+            self.settled = false;
+        }
+    }
+
+    /// Get the current position of the spring, as a lens ratio.
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// Get the current velocity of the spring, in lens ratio per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Check if the spring settled at its target.
+    pub fn is_settled(&self) -> bool {
+        self.settled
+    }
+
+    /// Advance the simulation by the given time, in seconds.
+    fn advance(&mut self, dt: f32) {
+        let (x, v) = damped_spring(
+            self.position - self.target,
+            self.velocity,
+            self.stiffness,
+            self.damping,
+            self.mass,
+            dt,
+        );
+        self.position = self.target + x;
+        self.velocity = v;
+
+        if x.abs() < self.rest_threshold && v.abs() < self.rest_threshold {
+            self.position = self.target;
+            self.velocity = 0.;
+            self.settled = true;
+        }
+    }
+}
+
+impl Tweenable for Spring {
+    fn cycle_duration(&self) -> Duration {
+        self.total_duration().as_finite().unwrap_or(Duration::MAX)
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        if self.settled {
+            TotalDuration::Finite(self.elapsed)
+        } else {
+            TotalDuration::Infinite
+        }
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.position = 0.;
+        self.velocity = self.initial_velocity;
+        self.settled = false;
+        self.elapsed = elapsed;
+        self.advance(elapsed.as_secs_f32());
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn step(
+        &mut self,
+        _tween_id: Entity,
+        delta: Duration,
+        target: MutUntyped,
+        target_type_id: &TypeId,
+        _notify_cycle_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        debug_assert_eq!(self.type_id, *target_type_id);
+
+        if self.settled {
+            return (TweenState::Completed, false);
+        }
+
+        self.elapsed = self.elapsed.saturating_add(delta);
+        self.advance(delta.as_secs_f32());
+        self.action.apply(target, self.position);
+
+        if self.settled {
+            (TweenState::Completed, false)
+        } else {
+            (TweenState::Active, false)
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.position = 0.;
+        self.velocity = self.initial_velocity;
+        self.elapsed = Duration::ZERO;
+        self.settled = false;
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Spring", self.elapsed, PlaybackDirection::Forward).with_simulation(
+            SimulationSnapshot {
+                position: self.position,
+                velocity: self.velocity,
+                target: Some(self.target),
+                at_rest: self.settled,
+            },
+        )
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(0)?;
+        // The simulation can't be replayed from the elapsed time alone, since the
+        // target may have changed while moving.
+        let Some(SimulationSnapshot {
+            position,
+            velocity,
+            target: Some(target),
+            at_rest,
+        }) = snapshot.simulation
+        else {
+            return Err(TweeningError::SnapshotMismatch);
+        };
+        self.position = position;
+        self.velocity = velocity;
+        self.target = target;
+        self.settled = at_rest;
+        self.elapsed = snapshot.elapsed;
+        Ok(())
+    }
+
+    fn target_type_id(&self) -> Option<TypeId> {
+        Some(self.type_id)
+    }
+}
+
+/// A physics-based inertia animation, decelerating until it stops.
+///
+/// The [`Decay`] tweenable starts from an initial velocity, and decelerates
+/// with an exponential friction until it stops, like a list scrolling after a
+/// fling gesture. It applies its position through a [`Lens`], like a [`Tween`]
+/// does with its eased cycle fraction. The position is a lens ratio starting at
+/// `0`, and the velocity is in lens ratio per second. Using a lens whose end
+/// value is one unit away from its start value makes the ratio directly
+/// measure the animated value, for example in pixels.
+///
+/// ```
+/// # use bevy_tweening::{lens::*, *};
+/// # use bevy::math::Vec3;
+/// # let fling_velocity = 800.;
+/// // Scroll along the Y axis after a fling, within a 1200 pixels high list
+/// let decay = Decay::new(
+///     TransformPositionLens {
+///         start: Vec3::ZERO,
+///         end: Vec3::Y,
+///     },
+///     fling_velocity,
+/// )
+/// .with_bounds(0., 1200.);
+/// ```
+///
+/// Without bounds, the decay stops at a distance of `velocity / friction`
+/// from its start. With bounds, when the position goes out of bounds, it's
+/// pulled back to the crossed bound by a critically damped spring, producing a
+/// rubber-band overshoot.
+///
+/// The decay completes once stopped, when its velocity drops below the rest
+/// threshold, and when out of bounds once back to the bound. Until then, its
+/// total duration is [`TotalDuration::Infinite`]. Once stopped, its total
+/// duration is the time it took to stop, so a [`Sequence`] continues with its
+/// next tweenable. Like any other tweenable, a [`TweenAnim`] raises an
+/// [`AnimCompletedEvent`] when the decay completes.
+///
+/// [`TweenAnim`]: crate::TweenAnim
+/// [`AnimCompletedEvent`]: crate::AnimCompletedEvent
+pub struct Decay {
+    action: LensAction,
+    /// Type ID of the target.
+    type_id: TypeId,
+    initial_velocity: f32,
+    friction: f32,
+    bounds: Option<(f32, f32)>,
+    bounce_stiffness: f32,
+    rest_threshold: f32,
+    position: f32,
+    velocity: f32,
+    /// Bound the position is being pulled back to, if it went out of bounds.
+    bounce: Option<f32>,
+    elapsed: Duration,
+    stopped: bool,
+}
+
+impl Decay {
+    /// Create a new decay animation starting with the given velocity, in lens
+    /// ratio per second.
+    ///
+    /// The default friction is `2`, so the velocity decreases by a factor `e`
+    /// every half second. The target type is implicitly determined by the type
+    /// `T` of the [`Lens<T>`] argument.
+    #[must_use]
+    pub fn new<T, L>(lens: L, velocity: f32) -> Self
+    where
+        T: 'static,
+        L: Lens<T> + Send + Sync + 'static,
+    {
+        Self {
+            action: LensAction::new(lens),
+            type_id: TypeId::of::<T>(),
+            initial_velocity: velocity,
+            friction: 2.,
+            bounds: None,
+            bounce_stiffness: 200.,
+            rest_threshold: 1e-3,
+            position: 0.,
+            velocity,
+            bounce: None,
+            elapsed: Duration::ZERO,
+            stopped: false,
+        }
+    }
+
+    /// Chain another [`Tweenable`] after this decay, making a [`Sequence`] with
+    /// the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+
+    /// Set the friction coefficient, in inverse seconds.
+    ///
+    /// A higher friction stops the motion faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the friction is not strictly positive.
+    #[must_use]
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        assert!(friction > 0., "Decay friction must be strictly positive.");
+        self.friction = friction;
+        self
+    }
+
+    /// Set the bounds of the position, as lens ratios.
+    ///
+    /// When the position goes out of bounds, it's pulled back to the crossed
+    /// bound with a rubber-band effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    #[must_use]
+    pub fn with_bounds(mut self, min: f32, max: f32) -> Self {
+        assert!(min <= max, "Decay bounds must be ordered.");
+        self.bounds = Some((min, max));
+        self
+    }
+
+    /// Set the stiffness of the rubber band pulling the position back within
+    /// bounds.
+    ///
+    /// A stiffer rubber band produces a smaller overshoot, and returns faster
+    /// to the bound. The default is `200`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stiffness is not strictly positive.
+    #[must_use]
+    pub fn with_bounce_stiffness(mut self, stiffness: f32) -> Self {
+        assert!(
+            stiffness > 0.,
+            "Decay bounce stiffness must be strictly positive."
+        );
+        self.bounce_stiffness = stiffness;
+        self
+    }
+
+    /// Set the rest threshold of the decay.
+    ///
+    /// The decay stops once its velocity is below this threshold, in lens
+    /// ratio per second. When pulled back within bounds, its distance to the
+    /// bound must also be below this threshold. The default is `0.001`.
+    #[must_use]
+    pub fn with_rest_threshold(mut self, threshold: f32) -> Self {
+        self.rest_threshold = threshold;
+        self
+    }
+
+    /// Get the current position, as a lens ratio.
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// Get the current velocity, in lens ratio per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Check if the decay stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Advance the simulation by the given time, in seconds.
+    fn advance(&mut self, mut dt: f32) {
+        while dt > 0. && !self.stopped {
+            if let Some(bound) = self.bounce {
+                // Pulled back to the bound by a critically damped spring
+                let damping = 2. * self.bounce_stiffness.sqrt();
+                let (x, v) = damped_spring(
+                    self.position - bound,
+                    self.velocity,
+                    self.bounce_stiffness,
+                    damping,
+                    1.,
+                    dt,
+                );
+                self.position = bound + x;
+                self.velocity = v;
+                if x.abs() < self.rest_threshold && v.abs() < self.rest_threshold {
+                    self.position = bound;
+                    self.velocity = 0.;
+                    self.stopped = true;
+                }
+                return;
+            }
+
+            // Check if the position goes out of bounds during this step, and if so
+            // continue from that point with the rubber band.
+            let decay = (-self.friction * dt).exp();
+            let position = self.position + self.velocity / self.friction * (1. - decay);
+            if let Some((min, max)) = self.bounds {
+                let bound = if position > max {
+                    Some(max)
+                } else if position < min {
+                    Some(min)
+                } else {
+                    None
+                };
+                if let Some(bound) = bound {
+                    // Solve for the time t where the position reaches the bound, and continue
+                    // from the bound. If already out of bounds, that's immediately.
+                    let t = if self.position.clamp(min, max) != self.position {
+                        self.position = self.position.clamp(min, max);
+                        0.
+                    } else {
+                        let ratio = 1. - (bound - self.position) * self.friction / self.velocity;
+                        self.position = bound;
+                        (-ratio.max(f32::MIN_POSITIVE).ln() / self.friction).clamp(0., dt)
+                    };
+                    self.velocity *= (-self.friction * t).exp();
+                    self.bounce = Some(bound);
+                    dt -= t;
+                    continue;
+                }
+            }
+
+            self.position = position;
+            self.velocity *= decay;
+            if self.velocity.abs() < self.rest_threshold {
+                self.velocity = 0.;
+                self.stopped = true;
+            }
+            return;
+        }
+    }
+}
+
+impl Tweenable for Decay {
+    fn cycle_duration(&self) -> Duration {
+        self.total_duration().as_finite().unwrap_or(Duration::MAX)
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        if self.stopped {
+            TotalDuration::Finite(self.elapsed)
+        } else {
+            TotalDuration::Infinite
+        }
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.rewind();
+        self.elapsed = elapsed;
+        self.advance(elapsed.as_secs_f32());
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn step(
+        &mut self,
+        _tween_id: Entity,
+        delta: Duration,
+        target: MutUntyped,
+        target_type_id: &TypeId,
+        _notify_cycle_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        debug_assert_eq!(self.type_id, *target_type_id);
+
+        if self.stopped {
+            return (TweenState::Completed, false);
+        }
+
+        self.elapsed = self.elapsed.saturating_add(delta);
+        self.advance(delta.as_secs_f32());
+        self.action.apply(target, self.position);
+
+        if self.stopped {
+            (TweenState::Completed, false)
+        } else {
+            (TweenState::Active, false)
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.position = 0.;
+        self.velocity = self.initial_velocity;
+        self.bounce = None;
+        self.elapsed = Duration::ZERO;
+        self.stopped = false;
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Decay", self.elapsed, PlaybackDirection::Forward).with_simulation(
+            SimulationSnapshot {
+                position: self.position,
+                velocity: self.velocity,
+                target: self.bounce,
+                at_rest: self.stopped,
+            },
+        )
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(0)?;
+        let Some(simulation) = snapshot.simulation else {
+            return Err(TweeningError::SnapshotMismatch);
+        };
+        self.position = simulation.position;
+        self.velocity = simulation.velocity;
+        self.bounce = simulation.target;
+        self.stopped = simulation.at_rest;
+        self.elapsed = snapshot.elapsed;
+        Ok(())
+    }
+
+    fn target_type_id(&self) -> Option<TypeId> {
+        Some(self.type_id)
+    }
+}
+
+/// A time delay that doesn't animate anything.
+///
+/// This is generally useful for combining with other tweenables into sequences
+/// and tracks, for example to delay the start of a tween in a track relative to
+/// another track. The `menu` example (`examples/menu.rs`) uses this technique
+/// to delay the animation of its buttons.
+#[derive(Debug)]
+pub struct Delay {
+    timer: Timer,
+    playback_direction: PlaybackDirection,
+}
+
+impl Delay {
+    /// Chain another [`Tweenable`] after this tween, making a [`Sequence`] with
+    /// the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+
+    /// Create a new [`Delay`] with a given duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is zero.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        assert!(!duration.is_zero());
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            playback_direction: PlaybackDirection::Forward,
+        }
+    }
+
+    /// Set the playback direction of the delay.
+    ///
+    /// See [`Tweenable::set_playback_direction()`] for details.
+    #[must_use]
+    pub fn with_playback_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.playback_direction = direction;
+        self
+    }
+
+    /// Check if the delay completed.
+    ///
+    /// When playing backward, the delay completes once its elapsed time reaches
+    /// back zero.
+    pub fn is_completed(&self) -> bool {
+        match self.playback_direction {
+            PlaybackDirection::Forward => self.timer.is_finished(),
+            PlaybackDirection::Backward => self.timer.elapsed().is_zero(),
+        }
+    }
+
+    /// Get the current tweenable state.
+    pub fn state(&self) -> TweenState {
+        if self.is_completed() {
+            TweenState::Completed
+        } else {
+            TweenState::Active
+        }
+    }
+}
+
+impl Tweenable for Delay {
+    fn cycle_duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        TotalDuration::Finite(self.cycle_duration())
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        // need to reset() to clear is_finished() unfortunately
+        self.timer.reset();
+        self.timer.set_elapsed(elapsed);
+        // set_elapsed() does not update finished() etc. which we rely on
+        self.timer.tick(Duration::ZERO);
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    fn step(
+        &mut self,
+        _tween_id: Entity,
+        delta: Duration,
+        _target: MutUntyped,
+        _target_type_id: &TypeId,
+        _notify_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        if self.playback_direction.is_forward() {
+            self.timer.tick(delta);
+        } else {
+            let elapsed = self.timer.elapsed().saturating_sub(delta);
+            self.set_elapsed(elapsed);
+        }
+
+        let state = self.state();
+
+        // // If completed this frame, notify the user
+        // if (state == TweenState::Completed) && !was_completed {
+        //     if self.send_completed_events {
+        //         let event = CycleCompletedEvent {
+        //             id: tween_id,
+        //             entity,
+        //             progress,
+        //         };
+
+        //         // send regular event
+        //         events.send(event);
+
+        //         // trigger all entity-scoped observers
+        //         //commands.trigger_targets(event, entity);
+        //     }
+        //     if let Some(system_id) = &self.system_id {
+        //         commands.run_system(*system_id);
+        //     }
+        // }
+
+        (state, false)
+    }
+
+    fn rewind(&mut self) {
+        match self.playback_direction {
+            PlaybackDirection::Forward => self.timer.reset(),
+            PlaybackDirection::Backward => self.set_elapsed(self.timer.duration()),
+        }
+    }
+
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.playback_direction = direction;
+    }
+
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("Delay", self.elapsed(), self.playback_direction)
+    }
+
+    fn target_type_id(&self) -> Option<TypeId> {
+        None
+    }
+}
+
+type WaitCondition = dyn Fn(&World) -> bool + Send + Sync + 'static;
+
+/// A wait on a runtime condition that doesn't animate anything.
+///
+/// This is the runtime counterpart of a [`Delay`]. Instead of waiting a fixed
+/// duration, it waits until a condition evaluated on the ECS `World` is met,
+/// then completes. This is generally useful inside a [`Sequence`], to hold the
+/// rest of the sequence until for example the user pressed a button, without
+/// splitting the animation into multiple [`TweenAnim`]s.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_tweening::{lens::*, *};
+/// # use std::time::Duration;
+/// #[derive(Resource)]
+/// struct DialogClosed;
+///
+/// # let tween1 = Delay::new(Duration::from_secs(1));
+/// # let tween2 = Delay::new(Duration::from_secs(1));
+/// // Play tween1, then wait until the DialogClosed resource is inserted to play tween2
+/// let seq = tween1
+///     .then(WaitUntil::new(|world| world.contains_resource::<DialogClosed>()))
+///     .then(tween2);
+/// ```
+///
+/// The condition is evaluated once per frame, before any animation is stepped,
+/// and only once the playback reached the [`WaitUntil`]. So it can access any
+/// resource, including the messages raised by the animations, like an
+/// [`AnimCompletedEvent`] to wait until another animation completed. When the condition is
+/// met, the [`WaitUntil`] completes immediately, and any time left in the
+/// current frame is used to step the next tweenable of the sequence. Until
+/// then, its total duration is [`TotalDuration::Infinite`]. Once resolved, its
+/// duration is the time it waited, so seeking and backward playback replay
+/// that wait like a [`Delay`]. Rewinding a forward playback resets it, so the
+/// condition is evaluated again on the next playback.
+///
+/// A [`Repeat`] of a [`WaitUntil`], directly or inside a [`Sequence`], waits
+/// for the condition again on each cycle, since each cycle rewinds it.
+///
+/// [`AnimCompletedEvent`]: crate::AnimCompletedEvent
+/// [`TweenAnim`]: crate::TweenAnim
+pub struct WaitUntil {
+    condition: Box<WaitCondition>,
+    /// Whether the condition was met the last time it was polled.
+    condition_met: AtomicBool,
+    /// Duration of the wait, once resolved.
+    duration: Option<Duration>,
+    elapsed: Duration,
+    playback_direction: PlaybackDirection,
+}
+
+impl WaitUntil {
+    /// Chain another [`Tweenable`] after this wait, making a [`Sequence`] with
+    /// the two.
+    #[must_use]
+    pub fn then(self, tween: impl Tweenable + 'static) -> Sequence {
+        Sequence::with_capacity(2).then(self).then(tween)
+    }
+
+    /// Create a new [`WaitUntil`] waiting until the given condition returns
+    /// `true`.
+    #[must_use]
+    pub fn new(condition: impl Fn(&World) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            condition: Box::new(condition),
+            condition_met: AtomicBool::new(false),
+            duration: None,
+            elapsed: Duration::ZERO,
+            playback_direction: PlaybackDirection::Forward,
+        }
+    }
+
+    /// Create a new [`WaitUntil`] waiting until a component of type `C` is
+    /// present on the given entity.
+    #[must_use]
+    pub fn component_added<C: Component>(entity: Entity) -> Self {
+        Self::new(move |world| world.get::<C>(entity).is_some())
+    }
+
+    /// Create a new [`WaitUntil`] waiting until a resource of type `R` exists
+    /// and satisfies the given predicate.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_tweening::*;
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// let wait = WaitUntil::resource_matches(|score: &Score| score.0 >= 100);
+    /// ```
+    ///
+    /// This can also be used to wait for a Bevy state to be entered, by
+    /// testing the `State<S>` resource with a predicate like
+    /// `|state: &State<GameState>| *state.get() == GameState::Playing`.
+    #[must_use]
+    pub fn resource_matches<R: Resource>(
+        predicate: impl Fn(&R) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(move |world| world.get_resource::<R>().is_some_and(&predicate))
+    }
+
+    /// Create a new [`WaitUntil`] waiting until a message of type `M` is
+    /// received.
+    ///
+    /// The message must be registered with `App::add_message()`. Any message
+    /// still buffered when the [`WaitUntil`] is reached is also received,
+    /// which includes messages written during the previous frame.
+    #[must_use]
+    pub fn message_received<M: Message>() -> Self {
+        let cursor = Mutex::new(MessageCursor::<M>::default());
+        Self::new(move |world| {
+            let Some(messages) = world.get_resource::<Messages<M>>() else {
+                return false;
+            };
+            let mut cursor = cursor.lock().unwrap();
+            cursor.read(messages).count() > 0
+        })
+    }
+
+    /// Set the playback direction of the wait.
+    ///
+    /// See [`Tweenable::set_playback_direction()`] for details.
+    #[must_use]
+    pub fn with_playback_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.playback_direction = direction;
+        self
+    }
+
+    /// Check if the condition was met, which resolved the duration of the wait.
+    pub fn is_resolved(&self) -> bool {
+        self.duration.is_some()
+    }
+}
+
+impl std::fmt::Debug for WaitUntil {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WaitUntil")
+            .field("condition_met", &self.condition_met)
+            .field("duration", &self.duration)
+            .field("elapsed", &self.elapsed)
+            .field("playback_direction", &self.playback_direction)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Tweenable for WaitUntil {
+    fn cycle_duration(&self) -> Duration {
+        self.duration.unwrap_or(Duration::MAX)
+    }
+
+    fn total_duration(&self) -> TotalDuration {
+        match self.duration {
+            Some(duration) => TotalDuration::Finite(duration),
+            None => TotalDuration::Infinite,
+        }
+    }
+
+    fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = match self.duration {
+            Some(duration) => elapsed.min(duration),
+            None => elapsed,
+        };
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn step(
+        &mut self,
+        _tween_id: Entity,
+        delta: Duration,
+        _target: MutUntyped,
+        _target_type_id: &TypeId,
+        _notify_completed: &mut dyn FnMut(),
+    ) -> (TweenState, bool) {
+        let Some(duration) = self.duration else {
+            // Still waiting. The condition was polled at the start of the frame, so if
+            // it was met the wait ends now, without consuming any of this step.
+            if self.condition_met.load(AtomicOrdering::Relaxed) {
+                self.duration = Some(self.elapsed);
+                return (TweenState::Completed, false);
+            }
+            if self.playback_direction.is_forward() {
+                self.elapsed = self.elapsed.saturating_add(delta);
+            }
+            return (TweenState::Active, false);
+        };
+
+        // Resolved; replay the wait like a Delay
+        let completed = if self.playback_direction.is_forward() {
+            self.elapsed = self.elapsed.saturating_add(delta).min(duration);
+            self.elapsed >= duration
+        } else {
+            self.elapsed = self.elapsed.saturating_sub(delta);
+            self.elapsed.is_zero()
+        };
+        if completed {
+            (TweenState::Completed, false)
+        } else {
+            (TweenState::Active, false)
+        }
+    }
+
+    fn rewind(&mut self) {
+        match self.playback_direction {
+            PlaybackDirection::Forward => {
+                self.duration = None;
+                self.elapsed = Duration::ZERO;
+                self.condition_met.store(false, AtomicOrdering::Relaxed);
+            }
+            PlaybackDirection::Backward => {
+                self.elapsed = self.duration.unwrap_or(Duration::ZERO);
+            }
+        }
+    }
+
+    fn set_playback_direction(&mut self, direction: PlaybackDirection) {
+        self.playback_direction = direction;
+    }
+
+    fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
+    }
+
+    fn poll_conditions(&self, world: &World) {
+        if self.duration.is_none()
+            && !self.condition_met.load(AtomicOrdering::Relaxed)
+            && (self.condition)(world)
+        {
+            self.condition_met.store(true, AtomicOrdering::Relaxed);
+        }
+    }
+
+    fn save_state(&self) -> TweenableSnapshot {
+        TweenableSnapshot::new("WaitUntil", self.elapsed, self.playback_direction)
+            .with_resolved_duration(self.duration)
+    }
+
+    fn restore_state(&mut self, snapshot: &TweenableSnapshot) -> Result<(), TweeningError> {
+        snapshot.check_children(0)?;
+        self.duration = snapshot.resolved_duration;
+        self.condition_met
+            .store(self.duration.is_some(), AtomicOrdering::Relaxed);
+        self.set_playback_direction(snapshot.direction);
+        self.set_elapsed(snapshot.elapsed);
+        Ok(())
+    }
+
+    fn target_type_id(&self) -> Option<TypeId> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // use std::sync::{Arc, Mutex};
+
+    use std::ops::{Deref as _, DerefMut as _};
+
+    use bevy::ecs::{change_detection::MaybeLocation, change_detection::Tick, system::SystemState};
+
+    use super::*;
+    use crate::{lens::*, test_utils::assert_approx_eq};
+
+    // #[derive(Default, Copy, Clone)]
+    // struct CallbackMonitor {
+    //     invoke_count: u64,
+    //     last_reported_count: u32,
+    // }
+
+    // Check the behavior of the functions calculating cycle information
+    #[test]
+    fn anim_clock_cycles() {
+        // The direction passed to AnimClock::set_elapsed() only affects the returned
+        // state, which is not tested in this test, so passing any value should produce
+        // the same test result here.
+        for dummy in [PlaybackDirection::Forward, PlaybackDirection::Backward] {
+            let cycle_duration = Duration::from_millis(100);
+            let repeat_count = 4;
+            let total_duration = cycle_duration * repeat_count;
+            let mut clock = AnimClock::new(cycle_duration);
+            clock.total_duration = TotalDuration::Finite(total_duration);
+
+            assert_eq!(cycle_duration, clock.cycle_duration);
+            assert_eq!(RepeatStrategy::Repeat, clock.strategy);
+
+            assert_eq!(Duration::ZERO, clock.elapsed());
+            assert_eq!(0, clock.cycle_index());
+            assert_approx_eq!(0.0, clock.cycle_fraction());
+            assert_approx_eq!(0.0, clock.mirrored_cycle_fraction());
+
+            let dt = Duration::from_millis(30);
+            clock.set_elapsed(dt, dummy);
+            assert_eq!(dt, clock.elapsed());
+            assert_eq!(0, clock.cycle_index());
+            assert_approx_eq!(0.3, clock.cycle_fraction());
+            assert_approx_eq!(0.3, clock.mirrored_cycle_fraction());
+
+            let dt = Duration::from_millis(110);
+            clock.set_elapsed(dt, dummy);
+            assert_eq!(dt, clock.elapsed());
+            assert_eq!(1, clock.cycle_index());
+            assert_approx_eq!(0.1, clock.cycle_fraction());
+            assert_approx_eq!(0.1, clock.mirrored_cycle_fraction());
+
+            let dt = Duration::from_millis(400);
+            clock.set_elapsed(dt, dummy);
+            assert_eq!(dt, clock.elapsed());
+            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
+            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
+            assert_approx_eq!(1.0, clock.mirrored_cycle_fraction());
+
+            let dt = Duration::from_millis(410); // > total_duration; clamped
+            clock.set_elapsed(dt, dummy);
+            assert_eq!(total_duration, clock.elapsed()); // clamped
+            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
+            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
+            assert_approx_eq!(1.0, clock.mirrored_cycle_fraction());
+
+            clock.strategy = RepeatStrategy::MirroredRepeat;
+
+            let dt = Duration::from_millis(110);
+            clock.set_elapsed(dt, dummy);
+            assert_eq!(dt, clock.elapsed());
+            assert_eq!(1, clock.cycle_index());
+            assert_approx_eq!(0.1, clock.cycle_fraction());
+            assert_approx_eq!(0.9, clock.mirrored_cycle_fraction()); // mirrored
+
+            let dt = Duration::from_millis(400);
+            clock.set_elapsed(dt, dummy);
+            assert_eq!(dt, clock.elapsed());
+            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
+            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
+            assert_approx_eq!(0.0, clock.mirrored_cycle_fraction()); // mirrored
+
+            let dt = Duration::from_millis(410); // > total_duration; clamped
+            clock.set_elapsed(dt, dummy);
+            assert_eq!(total_duration, clock.elapsed()); // clamped
+            assert_eq!(3, clock.cycle_index()); // doesn't loop at end
+            assert_approx_eq!(1.0, clock.cycle_fraction()); // doesn't loop at end
+            assert_approx_eq!(0.0, clock.mirrored_cycle_fraction()); // mirrored
+        }
+    }
+
+    // Check the accumulated error of the clock over a long period
+    #[test]
+    fn anim_clock_precision() {
+        let cycle_duration = Duration::from_millis(1);
+        let mut clock = AnimClock::new(cycle_duration);
+        clock.total_duration = TotalDuration::Infinite;
+
+        let test_ticks = [
+            Duration::from_micros(123),
+            Duration::from_millis(1),
+            Duration::from_secs_f32(1. / 24.),
+            Duration::from_secs_f32(1. / 30.),
+            Duration::from_secs_f32(1. / 60.),
+            Duration::from_secs_f32(1. / 120.),
+            Duration::from_secs_f32(1. / 144.),
+            Duration::from_secs_f32(1. / 240.),
+        ];
+
+        let mut times_completed = 0;
+        let mut total_duration = Duration::ZERO;
+        let num_iter = 100_000_000; // 100m ms, >27h
+        for i in 0..num_iter {
+            let tick = test_ticks[i % test_ticks.len()];
+            times_completed += clock.tick(tick).1;
+            total_duration += tick;
+        }
+
+        assert_eq!(
+            total_duration.div_duration_f64(cycle_duration) as i32,
+            times_completed
+        );
+    }
+
+    /// Utility to create a tween for testing.
+    fn make_test_tween() -> Tween {
+        Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+    }
+
+    /// Utility to create a test environment to tick a tween.
+    fn make_test_env() -> (World, Entity) {
+        let mut world = World::new();
+        world.init_resource::<Messages<CycleCompletedEvent>>();
+        let entity = world.spawn(Transform::default()).id();
+        (world, entity)
+    }
+
+    /// Manually tick a test tweenable targeting a component.
+    fn manual_tick_component(
+        anim_entity: Entity,
+        duration: Duration,
+        tween: &mut dyn Tweenable,
+        world: &mut World,
+        entity: Entity,
+    ) -> TweenState {
+        // Tick the given tween and apply its state to the given entity target
+        let target_type_id = TypeId::of::<Transform>();
+        let ret = world.resource_scope(
+            |world: &mut World, mut events: Mut<Messages<CycleCompletedEvent>>| {
+                let component_id = world.component_id::<Transform>().unwrap();
+                let entity_mut = &mut world.get_entity_mut([entity]).unwrap()[0];
+                if let Ok(mut target) = entity_mut.get_mut_by_id(component_id) {
+                    let world_target = AnimTargetKind::Component { entity };
+                    let mut notify_completed = || {
+                        events.write(CycleCompletedEvent {
+                            anim_entity,
+                            target: world_target,
+                        });
+                    };
+                    tween.step(
+                        anim_entity,
+                        duration,
+                        target.reborrow(),
+                        &target_type_id,
+                        &mut notify_completed,
+                    )
+                } else {
+                    (TweenState::Completed, false)
+                }
+            },
+        );
+
+        // Propagate events
+        {
+            let mut events = world.resource_mut::<Messages<CycleCompletedEvent>>();
+            events.update();
+        }
+
+        ret.0
+    }
+
+    #[derive(Debug, Default, Clone, Copy, Component)]
+    struct DummyComponent {
+        _value: f32,
+    }
+
+    #[test]
+    fn targetable_change_detect() {
+        let mut c = DummyComponent::default();
+        let mut added = Tick::new(0);
+        let mut last_changed = Tick::new(0);
+        let mut caller = MaybeLocation::caller();
+        let mut target = Mut::new(
+            &mut c,
+            &mut added,
+            &mut last_changed,
+            Tick::new(0),
+            Tick::new(1),
+            caller.as_mut(),
+        );
+
+        // No-op at start
+        assert!(!target.is_added());
+        assert!(!target.is_changed());
+
+        // Immutable deref doesn't trigger change detection
+        let _ = target.deref();
+        assert!(!target.is_added());
+        assert!(!target.is_changed());
+
+        // Mutable deref triggers change detection
+        let _ = target.deref_mut();
+        assert!(!target.is_added());
+        assert!(target.is_changed());
+    }
+
+    #[test]
+    fn into_repeat_count() {
+        let tween = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(5);
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(5))
+        );
+
+        let tween = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_repeat_count(Duration::from_secs(3));
+        assert_eq!(
+            tween.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(3))
+        );
+    }
+
+    /// Test ticking of a single tween in isolation.
+    #[test]
+    fn tween_tick() {
+        for playback_direction in [PlaybackDirection::Forward, PlaybackDirection::Backward] {
+            for (count, strategy) in [
+                (RepeatCount::Finite(1), RepeatStrategy::default()),
+                (RepeatCount::Infinite, RepeatStrategy::Repeat),
+                (RepeatCount::Finite(2), RepeatStrategy::Repeat),
+                (RepeatCount::Infinite, RepeatStrategy::MirroredRepeat),
+                (RepeatCount::Finite(2), RepeatStrategy::MirroredRepeat),
+            ] {
+                println!(
+                    "TweeningType: playback={playback_direction:?} count={count:?} strategy={strategy:?}",
+                );
+
+                // Create a linear tween over 1 second
+                let mut tween = make_test_tween()
+                    .with_playback_direction(playback_direction)
+                    .with_repeat_count(count)
+                    .with_repeat_strategy(strategy)
+                    .with_cycle_completed_event(true);
+                assert_eq!(tween.playback_direction(), playback_direction);
+                assert!(tween.send_cycle_completed_event);
+
+                // Note: for infinite duration playing backward, we need to start *somewhere*
+                // since we can't start at infinity. So pick t=1s which is shorter than 11
+                // iterations of the 100ms test tween created by make_test_tween(), to ensure we
+                // reach t=0.
+                let backward_start_time = tween
+                    .total_duration()
+                    .as_finite()
+                    .unwrap_or(Duration::from_secs(1));
+                if playback_direction == PlaybackDirection::Backward {
+                    // Seek to end, so that backward playback actually does something
+                    tween.set_elapsed(backward_start_time);
+                }
+
+                let (mut world, entity) = make_test_env();
+                let mut event_reader_system_state: SystemState<MessageReader<CycleCompletedEvent>> =
+                    SystemState::new(&mut world);
+
+                // Loop over 2.2 seconds, so greater than one ping-pong loop (2 cycles in
+                // MirroredRepeat)
+                let tick_duration = Duration::from_millis(200);
+                let tween_duration = Duration::from_secs(1);
+                for i in 1..=11 {
+                    // Calculate expected values
+                    let (elapsed_ms, factor, mut direction, expected_state, just_completed) =
+                        match count {
+                            RepeatCount::Finite(1) => {
+                                let (elapsed_ms, state) = if playback_direction.is_forward() {
+                                    if i < 5 {
+                                        (i * 200i32, TweenState::Active)
+                                    } else {
+                                        (1000i32, TweenState::Completed)
+                                    }
+                                } else if i < 5 {
+                                    (1000i32 - i * 200i32, TweenState::Active)
+                                } else {
+                                    (0i32, TweenState::Completed)
+                                };
+                                let just_completed = i == 5;
+                                (
+                                    elapsed_ms,
+                                    elapsed_ms as f32 / 1000.0,
+                                    PlaybackDirection::Forward,
+                                    state,
+                                    just_completed,
+                                )
+                            }
+                            RepeatCount::Finite(count) => {
+                                if strategy == RepeatStrategy::Repeat {
+                                    let just_completed = i % 5 == 0;
+                                    let elapsed_ms = if playback_direction.is_forward() {
+                                        // 0.2, 0.4, 0.6, 0.8, 0.0, 0.2, 0.4, ... (x count)
+                                        (i * 200) % 1000
+                                    } else {
+                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 0.8, 0.6, ... (x count)
+                                        800 - (((i - 1) * 200 - 1000) % 1000 + 1000) % 1000
+                                    };
+                                    let factor = if i >= 10 {
+                                        if playback_direction.is_forward() {
+                                            1.0
+                                        } else {
+                                            0.0
+                                        }
+                                    } else {
+                                        elapsed_ms as f32 / 1000.0
+                                    };
+                                    let total_duration_ms = count as i32 * 1000;
+                                    let elapsed_ms = if playback_direction.is_forward() {
+                                        (i * 200).min(total_duration_ms)
+                                    } else {
+                                        (total_duration_ms - i * 200).max(0)
+                                    };
+                                    // The test is calibrates such that at i==10 the forward tick
+                                    // reaches the total duration, and the backward tick reaches
+                                    // t=0, both of which yield a Completed state
+                                    let state = if i >= 10 {
+                                        TweenState::Completed
+                                    } else {
+                                        TweenState::Active
+                                    };
+                                    (
+                                        elapsed_ms,
+                                        factor,
+                                        PlaybackDirection::Forward,
+                                        state,
+                                        just_completed,
+                                    )
+                                } else {
+                                    let i5 = i % 5;
+                                    let just_completed = i5 == 0;
+
+                                    // Inifinite-repeat unclamped value
+                                    let elapsed_ms = if playback_direction.is_forward() {
+                                        // i    | 1    2    3    4    5    6    7    8    9    10
+                                        // t(s) | 0.2  0.4  0.6  0.8  1.0  0.8  0.6  0.4  0.2  0.0
+                                        ((i - 5) * 200).rem_euclid(2000) - 1000
+                                    } else {
+                                        // 0.0, 0.2, 0.4, 0.6, 0.8, 1.0, 0.8, 0.6, 0.4, 0.2, 0.0,
+                                        // ...
+                                        ((i + 5) * 200).rem_euclid(2000) - 1000
+                                    }
+                                    .abs();
+
+                                    // Now clamp to 'count' repeats
+                                    let (elapsed_ms, state) = if i < 10 {
+                                        (elapsed_ms, TweenState::Active)
+                                    } else {
+                                        (0, TweenState::Completed)
+                                    };
+                                    let ratio = elapsed_ms as f32 / 1000.;
+
+                                    let total_duration_ms = count as i32 * 1000;
+                                    let elapsed_ms = if playback_direction.is_forward() {
+                                        (i * 200).min(total_duration_ms)
+                                    } else {
+                                        (total_duration_ms - i * 200).max(0)
+                                    };
+
+                                    // Once Completed, the direction doesn't change
+                                    let direction = if playback_direction.is_forward() {
+                                        //           v [completion]
+                                        // 2468X 86420 00
+                                        // ffffb bbbbb bb
+                                        if i >= 5 {
+                                            PlaybackDirection::Backward
+                                        } else {
+                                            PlaybackDirection::Forward
+                                        }
+                                    } else {
+                                        //           v [completion]
+                                        // 86420 2468X XX
+                                        // bbbbb fffff ff
+                                        if i <= 5 {
+                                            PlaybackDirection::Backward
+                                        } else {
+                                            PlaybackDirection::Forward
+                                        }
+                                    };
+
+                                    (elapsed_ms, ratio, direction, state, just_completed)
+                                }
+                            }
+                            RepeatCount::Infinite => {
+                                if strategy == RepeatStrategy::Repeat {
+                                    let just_completed = i % 5 == 0;
+                                    let elapsed_ms = if playback_direction.is_forward() {
+                                        // 0.2, 0.4, 0.6, 0.8, 0.0, 0.2, 0.4, ...
+                                        (i * 200) % 1000
+                                    } else {
+                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 0.8, 0.6, ...
+                                        800 - (((i - 1) * 200 - 1000) % 1000 + 1000) % 1000
+                                    };
+                                    (
+                                        elapsed_ms,
+                                        elapsed_ms as f32 / 1000.0,
+                                        PlaybackDirection::Forward,
+                                        TweenState::Active,
+                                        just_completed,
+                                    )
+                                } else {
+                                    let elapsed_ms = if playback_direction.is_forward() {
+                                        // 0.2, 0.4, 0.6, 0.8, 1.0, 0.8, 0.6, 0.4, 0.2, 0.0, 0.2,
+                                        // 0.4, ...
+                                        ((i - 5) * 200).rem_euclid(2000) - 1000
+                                    } else {
+                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 0.2, 0.4, 0.6, 0.8, 1.0, 0.8,
+                                        // 0.6, ...
+                                        (i * 200).rem_euclid(2000) - 1000
+                                    }
+                                    .abs();
+                                    let factor = elapsed_ms as f32 / 1000.0;
+                                    let elapsed_ms = if playback_direction.is_forward() {
+                                        // 0.2, 0.4, 0.6, 0.8, 1.0, 1.2, 1.4, 1.6, ... (seconds)
+                                        (i * 200).rem_euclid(2000)
+                                    } else {
+                                        // 0.8, 0.6, 0.4, 0.2, 0.0, 1.8, 1.6, 1.4, ... (seconds)
+                                        (2000i32 - (i - 5) * 200).rem_euclid(2000)
+                                    };
+                                    let direction = if playback_direction.is_forward() {
+                                        // 2468X 86420 24
+                                        // ffffb bbbbf ff
+                                        if (i % 10) >= 5 {
+                                            PlaybackDirection::Backward
+                                        } else {
+                                            PlaybackDirection::Forward
+                                        }
+                                    } else {
+                                        // 86420 2468X 86
+                                        // bbbbb fffff bb
+                                        if ((i - 1) % 10) >= 5 {
+                                            PlaybackDirection::Backward
+                                        } else {
+                                            PlaybackDirection::Forward
+                                        }
+                                    };
+                                    let just_completed = (i % 5) == 0;
+                                    (
+                                        elapsed_ms,
+                                        factor,
+                                        direction,
+                                        TweenState::Active,
+                                        just_completed,
+                                    )
+                                }
+                            }
+                            RepeatCount::For(_) => panic!("Untested"),
+                        };
+                    if playback_direction.is_backward() {
+                        direction = !direction;
+                    }
+                    let expected_translation = Vec3::splat(factor);
+                    let elapsed = Duration::from_millis(elapsed_ms as u64);
+                    let cycles_completed = match count {
+                        RepeatCount::Infinite => elapsed,
+                        RepeatCount::Finite(count) => elapsed.min(tween_duration * count),
+                        RepeatCount::For(time) => elapsed.min(time),
+                    }
+                    .div_duration_f64(tween_duration)
+                        as u32;
+                    println!(
+                        "+ Expected: elapsed={:?} factor={} times_completed={} direction={:?} state={:?} just_completed={} translation={:?}",
+                        elapsed, factor, cycles_completed, direction, expected_state, just_completed, expected_translation
+                    );
+
+                    // Tick the tween
+                    let actual_state = manual_tick_component(
+                        Entity::PLACEHOLDER, // unused in this test
+                        tick_duration,
+                        &mut tween,
+                        &mut world,
+                        entity,
+                    );
+
+                    // Check actual values
+                    assert_eq!(tween.is_cycle_mirrored(), direction != playback_direction);
+                    assert_eq!(actual_state, expected_state);
+                    assert_eq!(tween.elapsed(), elapsed);
+                    assert_eq!(tween.cycles_completed(), cycles_completed);
+                    let transform = world.entity(entity).get::<Transform>().unwrap();
+                    assert_approx_eq!(expected_translation, transform.translation, 1e-5);
+                    assert_approx_eq!(Quat::IDENTITY, transform.rotation, 1e-5);
+
+                    // Messages are only sent when playing forward
+                    if playback_direction.is_forward() {
+                        let mut event_reader =
+                            event_reader_system_state.get_mut(&mut world).unwrap();
+                        let event = event_reader.read().next();
+                        if just_completed {
+                            assert!(event.is_some());
+                            if let Some(event) = event {
+                                let AnimTargetKind::Component {
+                                    entity: comp_target,
+                                } = &event.target
+                                else {
+                                    panic!("Expected AnimTargetKind::Component");
+                                };
+                                assert_eq!(*comp_target, entity);
+                            }
+                        } else {
+                            assert!(event.is_none());
+                        }
+                    }
+                }
+
+                // Can't rewind infinite tweens moving backward, they don't have an endpoint
+                if tween.total_duration().is_finite()
+                    || (playback_direction != PlaybackDirection::Backward)
+                {
+                    // Rewind
+                    println!("+ Rewind");
+                    tween.rewind();
+                    assert_eq!(tween.playback_direction(), playback_direction); // does not change
+                    if playback_direction.is_forward() {
+                        assert_eq!(tween.elapsed(), Duration::ZERO);
+                        assert_eq!(tween.cycles_completed(), 0);
+                    } else {
+                        assert_eq!(tween.elapsed(), backward_start_time);
+                        let cycles_completed = match count {
+                            RepeatCount::Infinite => backward_start_time,
+                            RepeatCount::Finite(count) => {
+                                backward_start_time.min(tween_duration * count)
+                            }
+                            RepeatCount::For(time) => backward_start_time.min(time),
+                        }
+                        .div_duration_f64(tween_duration)
+                            as u32;
+                        assert_eq!(cycles_completed, tween.cycles_completed());
+                    }
+
+                    // Dummy tick to update target
+                    let actual_state = manual_tick_component(
+                        Entity::PLACEHOLDER, // unused in this test
+                        Duration::ZERO,
+                        &mut tween,
+                        &mut world,
+                        entity,
+                    );
+                    assert_eq!(TweenState::Active, actual_state);
+                    let expected_translation = if playback_direction.is_backward()
+                        && strategy != RepeatStrategy::MirroredRepeat
+                    {
+                        Vec3::ONE
+                    } else {
+                        Vec3::ZERO
+                    };
+                    let transform = world.entity(entity).get::<Transform>().unwrap();
+                    assert_approx_eq!(expected_translation, transform.translation, 1e-5);
+                    assert_approx_eq!(Quat::IDENTITY, transform.rotation, 1e-5);
+                }
+
+                // Clear event sending
+                tween.set_cycle_completed_event(false);
+                assert!(!tween.send_cycle_completed_event);
+            }
+        }
+    }
+
+    #[test]
+    fn tween_dir() {
+        let mut tween = make_test_tween();
+
+        // Default
+        assert_eq!(tween.playback_direction(), PlaybackDirection::Forward);
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+
+        // no-op
+        tween.set_playback_direction(PlaybackDirection::Forward);
+        assert_eq!(tween.playback_direction(), PlaybackDirection::Forward);
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+
+        // Backward
+        tween.set_playback_direction(PlaybackDirection::Backward);
+        assert_eq!(tween.playback_direction(), PlaybackDirection::Backward);
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+
+        // Elapsed-invariant
+        let d300 = Duration::from_millis(300);
+        tween.set_playback_direction(PlaybackDirection::Forward);
+        tween.set_elapsed(d300);
+        assert_eq!(tween.elapsed(), d300);
+        tween.set_playback_direction(PlaybackDirection::Backward);
+        assert_eq!(tween.elapsed(), d300);
+
+        let (mut world, entity) = make_test_env();
+
+        // Progress always increases alongside the current direction
+        tween.set_playback_direction(PlaybackDirection::Backward);
+        assert_eq!(tween.elapsed(), d300);
+        manual_tick_component(
+            Entity::PLACEHOLDER, // unused in this test
+            Duration::from_millis(100),
+            &mut tween,
+            &mut world,
+            entity,
+        );
+        assert_eq!(tween.elapsed(), Duration::from_millis(200)); // 300 - 100
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(Vec3::splat(0.2), transform.translation, 1e-5);
+    }
+
+    #[test]
+    fn tween_elapsed() {
+        let mut tween = make_test_tween();
+
+        let cycle_duration = tween.cycle_duration();
+        let elapsed = tween.elapsed();
+
+        assert_eq!(elapsed, Duration::ZERO);
+        assert_eq!(cycle_duration, Duration::from_secs(1));
+
+        for ms in [0, 1, 500, 100, 300, 999, 847, 1000, 900] {
+            let elapsed = Duration::from_millis(ms);
+            tween.set_elapsed(elapsed);
+            assert_eq!(tween.elapsed(), elapsed);
+
+            let times_completed = u32::from(ms == 1000);
+            assert_eq!(tween.cycles_completed(), times_completed);
+        }
+    }
+
+    /// Collect the names of the markers crossed by a step.
+    fn crossed_markers(
+        tweenable: &dyn Tweenable,
+        from_ms: u64,
+        to_ms: u64,
+        include_from: bool,
+        direction: PlaybackDirection,
+    ) -> Vec<String> {
+        let mut names = vec![];
+        tweenable.visit_markers(
+            Duration::from_millis(from_ms),
+            Duration::from_millis(to_ms),
+            include_from,
+            direction,
+            &mut |marker| names.push(marker.name.to_string()),
+        );
+        names
+    }
+
+    #[test]
+    fn tween_markers() {
+        use PlaybackDirection::{Backward, Forward};
+
+        let tween = make_test_tween()
+            .with_repeat(RepeatCount::Finite(2), RepeatStrategy::Repeat)
+            .with_marker("start", Duration::ZERO)
+            .with_marker("impact", Duration::from_millis(300));
+        assert_eq!(tween.markers().len(), 2);
+        assert_eq!(
+            tween.marker_time("impact"),
+            Some(Duration::from_millis(300))
+        );
+        assert_eq!(tween.marker_time("missing"), None);
+
+        assert_eq!(
+            crossed_markers(&tween, 0, 500, true, Forward),
+            ["start", "impact"]
+        );
+        assert_eq!(crossed_markers(&tween, 0, 500, false, Forward), ["impact"]);
+        assert!(crossed_markers(&tween, 300, 300, false, Forward).is_empty());
+        // Each cycle crosses the markers
+        assert_eq!(
+            crossed_markers(&tween, 300, 1300, false, Forward),
+            ["start", "impact"]
+        );
+        assert_eq!(
+            crossed_markers(&tween, 1300, 300, false, Backward),
+            ["impact", "start"]
+        );
+
+        // Mirrored cycles cross the markers at the mirrored time, and the end of a
+        // cycle is crossed only once when it's the start of the next one.
+        let tween = make_test_tween()
+            .with_repeat(RepeatCount::Infinite, RepeatStrategy::MirroredRepeat)
+            .with_marker("end", Duration::from_secs(1))
+            .with_marker("impact", Duration::from_millis(300));
+        assert_eq!(crossed_markers(&tween, 500, 1500, false, Forward), ["end"]);
+        assert_eq!(
+            crossed_markers(&tween, 1500, 1800, false, Forward),
+            ["impact"]
+        );
+        // Loop around after 2 cycles
+        assert_eq!(
+            crossed_markers(&tween, 1800, 500, false, Forward),
+            ["impact"]
+        );
+        assert_eq!(
+            crossed_markers(&tween, 500, 1800, false, Backward),
+            ["impact"]
+        );
+    }
+
+    /// Test ticking a sequence of tweens.
+    #[test]
+    fn seq_tick() {
+        let tween1 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let tween2 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformRotationLens {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_x(90_f32.to_radians()),
+            },
+        );
+        let mut seq = tween1.then(tween2);
+
+        let (mut world, entity) = make_test_env();
+
+        for i in 1..=16 {
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER, // unused in this test
+                Duration::from_millis(200),
+                &mut seq,
+                &mut world,
+                entity,
+            );
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            if i < 5 {
+                assert_eq!(state, TweenState::Active);
+                let r = i as f32 * 0.2;
+                assert_approx_eq!(Transform::from_translation(Vec3::splat(r)), *transform);
+            } else if i < 10 {
+                assert_eq!(state, TweenState::Active);
+                let alpha_deg = (18 * (i - 5)) as f32;
+                assert_approx_eq!(Vec3::ONE, transform.translation);
+                assert_approx_eq!(
+                    Quat::from_rotation_x(alpha_deg.to_radians()),
+                    transform.rotation
+                );
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert_approx_eq!(Vec3::ONE, transform.translation);
+                assert_approx_eq!(
+                    Quat::from_rotation_x(90_f32.to_radians()),
+                    transform.rotation
+                );
+            }
+        }
+    }
+
+    /// Test crossing tween boundaries in one tick.
+    #[test]
+    fn seq_tick_boundaries() {
+        let mut seq = Sequence::new((0..3).map(|i| {
+            Tween::new(
+                EaseMethod::default(),
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(i as f32),
+                    end: Vec3::splat((i + 1) as f32),
+                },
+            )
+            .with_repeat_count(RepeatCount::Finite(1))
+        }));
+
+        let (mut world, entity) = make_test_env();
+
+        // Tick halfway through the first tween, then in one tick:
+        // - Finish the first tween
+        // - Start and finish the second tween
+        // - Start the third tween
+        for delta_ms in [500, 2000] {
+            manual_tick_component(
+                Entity::PLACEHOLDER, // unused in this test
+                Duration::from_millis(delta_ms),
+                &mut seq,
+                &mut world,
+                entity,
+            );
+        }
+        assert_eq!(seq.index(), 2);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert!(transform.translation.abs_diff_eq(Vec3::splat(2.5), 1e-5));
+    }
+
+    /// Sequence::new() and various Sequence-specific methods
+    #[test]
+    fn seq_iter() {
+        let mut seq = Sequence::new((1..5).map(|i| {
+            Tween::new(
+                EaseMethod::default(),
+                Duration::from_millis(200 * i),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        }));
+
+        let mut time = Duration::ZERO;
+        for i in 1..5 {
+            assert_eq!(seq.index(), i - 1);
+            assert_eq!(time, seq.elapsed());
+            let dt = Duration::from_millis(200 * i as u64);
+            assert_eq!(dt, seq.current().cycle_duration());
+            time += dt;
+            seq.set_elapsed(time);
+            assert_eq!(seq.cycles_completed(), u32::from(i == 4));
+        }
+
+        seq.rewind();
+        assert_eq!(Duration::ZERO, seq.elapsed());
+        assert_eq!(0, seq.cycles_completed());
+    }
+
+    /// Sequence::from_single()
+    #[test]
+    fn seq_from_single() {
+        let dt = Duration::from_secs(1);
+        let tween = Tween::new(
+            EaseMethod::default(),
+            dt,
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let seq = Sequence::from_single(tween);
+
+        assert_eq!(1, seq.tweens.len());
+        assert_eq!(dt, seq.cycle_duration());
+        assert_eq!(TotalDuration::Finite(dt), seq.total_duration());
+    }
+
+    #[test]
+    fn seq_elapsed() {
+        let mut seq = Sequence::new((1..5).map(|i| {
+            Tween::new(
+                EaseMethod::default(),
+                Duration::from_millis(200 * i),
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+            )
+        }));
+
+        let mut elapsed = Duration::ZERO;
+        for i in 1..5 {
+            assert_eq!(seq.index(), i - 1);
+            assert_eq!(seq.elapsed(), elapsed);
+            let duration = Duration::from_millis(200 * i as u64);
+            assert_eq!(seq.current().cycle_duration(), duration);
+            elapsed += duration;
+            seq.set_elapsed(elapsed);
+            assert_eq!(seq.cycles_completed(), u32::from(i == 4));
+        }
+    }
+
+    /// Markers of a sequence and its children, and of a repeat of that sequence.
+    #[test]
+    fn seq_markers() {
+        use PlaybackDirection::{Backward, Forward};
+
+        let seq = Delay::new(Duration::from_secs(1))
+            .then(
+                make_test_tween()
+                    .with_marker("start", Duration::ZERO)
+                    .with_marker("impact", Duration::from_millis(300)),
+            )
+            .with_marker("mid", Duration::from_secs(1));
+        assert_eq!(seq.marker_time("mid"), Some(Duration::from_secs(1)));
+        assert_eq!(seq.marker_time("impact"), Some(Duration::from_millis(1300)));
+
+        // Entering a child crosses its start
+        assert_eq!(
+            crossed_markers(&seq, 0, 1000, true, Forward),
+            ["mid", "start"]
+        );
+        assert_eq!(
+            crossed_markers(&seq, 1000, 1500, false, Forward),
+            ["impact"]
+        );
+        assert_eq!(
+            crossed_markers(&seq, 2000, 500, false, Backward),
+            ["mid", "start", "impact"]
+        );
+
+        let repeat = Repeat::new(seq, 2);
+        assert_eq!(
+            repeat.marker_time("impact"),
+            Some(Duration::from_millis(1300))
+        );
+        assert_eq!(
+            crossed_markers(&repeat, 1500, 3500, false, Forward),
+            ["mid", "start", "impact"]
+        );
+        assert_eq!(
+            crossed_markers(&repeat, 3500, 2500, false, Backward),
+            ["mid", "start", "impact"]
+        );
+    }
+
+    /// Play a sequence backward, including a nested sequence.
+    #[test]
+    fn seq_backward() {
+        let make_tween = |i: usize| {
+            Tween::new(
+                EaseMethod::default(),
+                Duration::from_secs(1),
+                TransformPositionLens {
+                    start: Vec3::splat(i as f32),
+                    end: Vec3::splat((i + 1) as f32),
+                },
+            )
+        };
+        let mut seq = make_tween(0)
+            .then(Sequence::new([make_tween(1), make_tween(2)]))
+            .with_playback_direction(PlaybackDirection::Backward);
+        assert_eq!(seq.playback_direction(), PlaybackDirection::Backward);
+        for tween in &seq.tweens {
+            assert_eq!(tween.playback_direction(), PlaybackDirection::Backward);
+        }
+
+        // Rewinding backward moves to the end of the sequence
+        seq.rewind();
+        assert_eq!(seq.elapsed(), Duration::from_secs(3));
+        assert_eq!(seq.index(), 1);
+
+        let (mut world, entity) = make_test_env();
+        let position = |world: &World| world.get::<Transform>(entity).unwrap().translation;
+
+        // (dt, state, position, index)
+        for (dt, state, pos, index) in [
+            (500, TweenState::Active, 2.5, 1),
+            // Cross into the first child of the nested sequence
+            (1000, TweenState::Active, 1.5, 1),
+            // Cross into the first child of the sequence
+            (1000, TweenState::Active, 0.5, 0),
+            (1000, TweenState::Completed, 0., 0),
+            (1000, TweenState::Completed, 0., 0),
+        ] {
+            let ret = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                &mut seq,
+                &mut world,
+                entity,
+            );
+            assert_eq!(ret, state);
+            assert_approx_eq!(position(&world), Vec3::splat(pos));
+            assert_eq!(seq.index(), index);
+        }
+        assert_eq!(seq.elapsed(), Duration::ZERO);
+
+        // Play forward again from the start
+        seq.set_playback_direction(PlaybackDirection::Forward);
+        let ret = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_millis(1500),
+            &mut seq,
+            &mut world,
+            entity,
+        );
+        assert_eq!(ret, TweenState::Active);
+        assert_approx_eq!(position(&world), Vec3::splat(1.5));
+        assert_eq!(seq.index(), 1);
+    }
+
+    /// Test ticking a parallel collection of tweens.
+    #[test]
+    fn par_tick() {
+        let tween1 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let tween2 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_millis(1600),
+            TransformRotationLens {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_x(80_f32.to_radians()),
+            },
+        );
+        let mut par = Parallel::new([tween1, tween2]);
+        assert_eq!(
+            par.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(1600))
+        );
+        assert_eq!(par.cycle_duration(), Duration::from_millis(1600));
+
+        let (mut world, entity) = make_test_env();
+
+        for i in 1..=10 {
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER, // unused in this test
+                Duration::from_millis(200),
+                &mut par,
+                &mut world,
+                entity,
+            );
+            assert_eq!(par.elapsed(), Duration::from_millis(200 * i.min(8) as u64));
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            let r = (i as f32 * 0.2).min(1.);
+            assert_approx_eq!(Vec3::splat(r), transform.translation);
+            let alpha_deg = (10 * i.min(8)) as f32;
+            assert_approx_eq!(
+                Quat::from_rotation_x(alpha_deg.to_radians()),
+                transform.rotation
+            );
+            if i < 8 {
+                assert_eq!(state, TweenState::Active);
+                assert_eq!(par.cycles_completed(), 0);
+            } else {
+                assert_eq!(state, TweenState::Completed);
+                assert_eq!(par.cycles_completed(), 1);
+            }
+        }
+
+        par.rewind();
+        assert_eq!(par.elapsed(), Duration::ZERO);
+        for tween in par.tweenables() {
+            assert_eq!(tween.elapsed(), Duration::ZERO);
+        }
+    }
+
+    /// Play a parallel collection backward, with children of different durations.
+    #[test]
+    fn par_backward() {
+        let tween1 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(2),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let tween2 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformScaleLens {
+                start: Vec3::ZERO,
+                end: Vec3::splat(2.),
+            },
+        );
+        let mut par =
+            Parallel::new([tween1, tween2]).with_playback_direction(PlaybackDirection::Backward);
+        par.rewind();
+        assert_eq!(par.elapsed(), Duration::from_secs(2));
 
-                // Clear event sending
-                tween.set_cycle_completed_event(false);
-                assert!(!tween.send_cycle_completed_event);
-            }
+        let (mut world, entity) = make_test_env();
+
+        // The shorter child waits at its end until the elapsed time reaches back its
+        // duration.
+        // (dt, state, position, scale)
+        for (dt, state, pos, scale) in [
+            (500, TweenState::Active, 0.75, 2.),
+            (1000, TweenState::Active, 0.25, 1.),
+            (1000, TweenState::Completed, 0., 0.),
+        ] {
+            let ret = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                &mut par,
+                &mut world,
+                entity,
+            );
+            assert_eq!(ret, state);
+            let transform = world.entity(entity).get::<Transform>().unwrap();
+            assert_approx_eq!(transform.translation, Vec3::splat(pos));
+            assert_approx_eq!(transform.scale, Vec3::splat(scale));
         }
+        assert_eq!(par.elapsed(), Duration::ZERO);
     }
 
+    /// A parallel collection of a child whose duration is only known once it
+    /// completes doesn't block a sequence.
     #[test]
-    fn tween_dir() {
-        let mut tween = make_test_tween();
+    fn par_resolved_duration() {
+        let spring = Spring::new(TransformScaleLens {
+            start: Vec3::ZERO,
+            end: Vec3::ONE,
+        })
+        .with_stiffness(500.);
+        let par = Parallel::new([spring]);
+        assert_eq!(par.total_duration(), TotalDuration::Infinite);
+        let mut seq = par.then(make_test_tween());
 
-        // Default
-        assert_eq!(tween.playback_direction(), PlaybackDirection::Forward);
-        assert_eq!(tween.elapsed(), Duration::ZERO);
+        let (mut world, entity) = make_test_env();
+        let mut frames = 0;
+        while seq.index() == 0 {
+            frames += 1;
+            assert!(frames < 200);
+            manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(16),
+                &mut seq,
+                &mut world,
+                entity,
+            );
+        }
+        assert_eq!(
+            seq.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(16 * frames + 1000))
+        );
+        let state = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_secs(1),
+            &mut seq,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Completed);
+    }
 
-        // no-op
-        tween.set_playback_direction(PlaybackDirection::Forward);
-        assert_eq!(tween.playback_direction(), PlaybackDirection::Forward);
-        assert_eq!(tween.elapsed(), Duration::ZERO);
+    /// Test seeking a parallel collection, and nesting it inside a sequence.
+    #[test]
+    fn par_elapsed_nested() {
+        let tween1 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformScaleLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut par = Parallel::with_capacity(2)
+            .and(tween1)
+            .and(Delay::new(Duration::from_secs(2)));
+        assert_eq!(par.tweenables().len(), 2);
+        assert_eq!(
+            par.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(2))
+        );
 
-        // Backward
-        tween.set_playback_direction(PlaybackDirection::Backward);
-        assert_eq!(tween.playback_direction(), PlaybackDirection::Backward);
-        assert_eq!(tween.elapsed(), Duration::ZERO);
+        // Shorter children are clamped to their own duration
+        par.set_elapsed(Duration::from_millis(1500));
+        assert_eq!(par.elapsed(), Duration::from_millis(1500));
+        assert_eq!(par.tweenables()[0].elapsed(), Duration::from_secs(1));
+        assert_eq!(par.tweenables()[1].elapsed(), Duration::from_millis(1500));
 
-        // Elapsed-invariant
-        let d300 = Duration::from_millis(300);
-        tween.set_playback_direction(PlaybackDirection::Forward);
-        tween.set_elapsed(d300);
-        assert_eq!(tween.elapsed(), d300);
-        tween.set_playback_direction(PlaybackDirection::Backward);
-        assert_eq!(tween.elapsed(), d300);
+        // Parallel is clamped to its total duration
+        par.set_elapsed(Duration::from_secs(3));
+        assert_eq!(par.elapsed(), Duration::from_secs(2));
+        par.set_elapsed(Duration::ZERO);
+
+        let tween2 = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut seq = par.then(tween2);
+        assert_eq!(
+            seq.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(3))
+        );
 
         let (mut world, entity) = make_test_env();
 
-        // Progress always increases alongside the current direction
-        tween.set_playback_direction(PlaybackDirection::Backward);
-        assert_eq!(tween.elapsed(), d300);
-        manual_tick_component(
+        // Cross the boundary between the parallel collection and the tween
+        let state = manual_tick_component(
             Entity::PLACEHOLDER, // unused in this test
-            Duration::from_millis(100),
-            &mut tween,
+            Duration::from_millis(2500),
+            &mut seq,
             &mut world,
             entity,
         );
-        assert_eq!(tween.elapsed(), Duration::from_millis(200)); // 300 - 100
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(seq.index(), 1);
         let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert_approx_eq!(Vec3::splat(0.2), transform.translation, 1e-5);
+        assert_approx_eq!(Vec3::ONE, transform.scale);
+        assert_approx_eq!(Vec3::splat(0.5), transform.translation);
     }
 
+    /// Timeline with overlapping children.
     #[test]
-    fn tween_elapsed() {
-        let mut tween = make_test_tween();
+    fn timeline_tick() {
+        let move_tween = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_cycle_completed_event(true);
+        let scale_tween = Tween::new(
+            EaseMethod::default(),
+            Duration::from_secs(1),
+            TransformScaleLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        )
+        .with_cycle_completed_event(true);
+        let mut timeline = Timeline::new().insert(Duration::ZERO, move_tween).insert(
+            TimelinePosition::BeforeEndOf(0, Duration::from_millis(200)),
+            scale_tween,
+        );
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline.start_of(1), Some(Duration::from_millis(800)));
+        assert_eq!(
+            timeline.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(1800))
+        );
 
-        let cycle_duration = tween.cycle_duration();
-        let elapsed = tween.elapsed();
+        let (mut world, entity) = make_test_env();
+        world
+            .entity_mut(entity)
+            .get_mut::<Transform>()
+            .unwrap()
+            .scale = Vec3::splat(42.);
 
-        assert_eq!(elapsed, Duration::ZERO);
-        assert_eq!(cycle_duration, Duration::from_secs(1));
+        // Only the first child started
+        let state = manual_tick_component(
+            Entity::PLACEHOLDER, // unused in this test
+            Duration::from_millis(500),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Active);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(Vec3::splat(0.5), transform.translation);
+        assert_approx_eq!(Vec3::splat(42.), transform.scale);
 
-        for ms in [0, 1, 500, 100, 300, 999, 847, 1000, 900] {
-            let elapsed = Duration::from_millis(ms);
-            tween.set_elapsed(elapsed);
-            assert_eq!(tween.elapsed(), elapsed);
+        // Both children overlap
+        let state = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_millis(400),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(timeline.cycles_completed(), 0);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(Vec3::splat(0.9), transform.translation);
+        assert_approx_eq!(Vec3::splat(0.1), transform.scale);
 
-            let times_completed = u32::from(ms == 1000);
-            assert_eq!(tween.cycles_completed(), times_completed);
-        }
+        // Complete the timeline
+        let state = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_secs(1),
+            &mut timeline,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(timeline.elapsed(), Duration::from_millis(1800));
+        assert_eq!(timeline.cycles_completed(), 1);
+        let transform = world.entity(entity).get::<Transform>().unwrap();
+        assert_approx_eq!(Vec3::ONE, transform.translation);
+        assert_approx_eq!(Vec3::ONE, transform.scale);
+        let events = world.resource::<Messages<CycleCompletedEvent>>();
+        assert_eq!(events.get_cursor().len(events), 2);
+
+        // Rewind
+        timeline.rewind();
+        assert_eq!(timeline.elapsed(), Duration::ZERO);
+        assert_eq!(timeline.cycles_completed(), 0);
+        assert!(timeline
+            .tweenables()
+            .iter()
+            .all(|tween| tween.elapsed() == Duration::ZERO));
     }
 
-    /// Test ticking a sequence of tweens.
+    /// Play a timeline backward.
     #[test]
-    fn seq_tick() {
-        let tween1 = Tween::new(
+    fn timeline_backward() {
+        let tween1 = make_test_tween();
+        let tween2 = Tween::new(
             EaseMethod::default(),
             Duration::from_secs(1),
-            TransformPositionLens {
+            TransformScaleLens {
                 start: Vec3::ZERO,
-                end: Vec3::ONE,
+                end: Vec3::splat(2.),
             },
         );
-        let tween2 = Tween::new(
-            EaseMethod::default(),
-            Duration::from_secs(1),
-            TransformRotationLens {
-                start: Quat::IDENTITY,
-                end: Quat::from_rotation_x(90_f32.to_radians()),
-            },
+        let mut timeline = Timeline::new()
+            .with_playback_direction(PlaybackDirection::Backward)
+            .insert(Duration::ZERO, tween1)
+            .insert(
+                TimelinePosition::AfterEndOf(0, Duration::from_millis(500)),
+                tween2,
+            );
+        assert_eq!(
+            timeline.tweenables()[1].playback_direction(),
+            PlaybackDirection::Backward
         );
-        let mut seq = tween1.then(tween2);
+        timeline.rewind();
+        assert_eq!(timeline.elapsed(), Duration::from_millis(2500));
 
         let (mut world, entity) = make_test_env();
 
-        for i in 1..=16 {
-            let state = manual_tick_component(
-                Entity::PLACEHOLDER, // unused in this test
-                Duration::from_millis(200),
-                &mut seq,
+        // (dt, state, position, scale)
+        for (dt, state, pos, scale) in [
+            (500, TweenState::Active, 1., 1.),
+            (1000, TweenState::Active, 1., 0.),
+            (500, TweenState::Active, 0.5, 0.),
+            (1000, TweenState::Completed, 0., 0.),
+        ] {
+            let ret = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                &mut timeline,
                 &mut world,
                 entity,
             );
+            assert_eq!(ret, state);
             let transform = world.entity(entity).get::<Transform>().unwrap();
-            if i < 5 {
-                assert_eq!(state, TweenState::Active);
-                let r = i as f32 * 0.2;
-                assert_approx_eq!(Transform::from_translation(Vec3::splat(r)), *transform);
-            } else if i < 10 {
-                assert_eq!(state, TweenState::Active);
-                let alpha_deg = (18 * (i - 5)) as f32;
-                assert_approx_eq!(Vec3::ONE, transform.translation);
-                assert_approx_eq!(
-                    Quat::from_rotation_x(alpha_deg.to_radians()),
-                    transform.rotation
-                );
-            } else {
-                assert_eq!(state, TweenState::Completed);
-                assert_approx_eq!(Vec3::ONE, transform.translation);
-                assert_approx_eq!(
-                    Quat::from_rotation_x(90_f32.to_radians()),
-                    transform.rotation
-                );
-            }
+            assert_approx_eq!(transform.translation, Vec3::splat(pos));
+            assert_approx_eq!(transform.scale, Vec3::splat(scale));
         }
     }
 
-    /// Test crossing tween boundaries in one tick.
+    /// Timeline::set_elapsed() and relative positions.
     #[test]
-    fn seq_tick_boundaries() {
-        let mut seq = Sequence::new((0..3).map(|i| {
+    fn timeline_elapsed() {
+        let make_tween = || {
             Tween::new(
                 EaseMethod::default(),
                 Duration::from_secs(1),
                 TransformPositionLens {
-                    start: Vec3::splat(i as f32),
-                    end: Vec3::splat((i + 1) as f32),
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
                 },
             )
-            .with_repeat_count(RepeatCount::Finite(1))
-        }));
+        };
+        let mut timeline = Timeline::with_capacity(4)
+            .insert(Duration::from_millis(500), make_tween())
+            .insert(
+                TimelinePosition::AfterStartOf(0, Duration::from_millis(200)),
+                make_tween(),
+            )
+            .insert(
+                TimelinePosition::BeforeStartOf(0, Duration::from_secs(1)),
+                make_tween(),
+            )
+            .insert(
+                TimelinePosition::AfterEndOf(1, Duration::from_millis(300)),
+                make_tween(),
+            );
+        let starts: Vec<_> = (0..4).map(|i| timeline.start_of(i).unwrap()).collect();
+        assert_eq!(
+            starts,
+            [
+                Duration::from_millis(500),
+                Duration::from_millis(700),
+                Duration::ZERO,
+                Duration::from_millis(2000),
+            ]
+        );
+        assert_eq!(timeline.start_of(4), None);
+        assert_eq!(
+            timeline.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(3))
+        );
+
+        // Seek in the middle; children are clamped to their own local time
+        timeline.set_elapsed(Duration::from_millis(1200));
+        assert_eq!(timeline.elapsed(), Duration::from_millis(1200));
+        let elapsed: Vec<_> = timeline.tweenables().iter().map(|t| t.elapsed()).collect();
+        assert_eq!(
+            elapsed,
+            [
+                Duration::from_millis(700),
+                Duration::from_millis(500),
+                Duration::from_secs(1),
+                Duration::ZERO,
+            ]
+        );
+        assert_eq!(timeline.cycles_completed(), 0);
 
+        // Seek past the end
+        timeline.set_elapsed(Duration::from_secs(5));
+        assert_eq!(timeline.elapsed(), Duration::from_secs(3));
+        assert_eq!(timeline.cycles_completed(), 1);
+
+        // Empty timeline completes immediately
+        let mut empty = Timeline::new();
+        assert!(empty.is_empty());
+        assert_eq!(
+            empty.total_duration(),
+            TotalDuration::Finite(Duration::ZERO)
+        );
+        assert_eq!(empty.cycles_completed(), 1);
         let (mut world, entity) = make_test_env();
+        let state = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::ZERO,
+            &mut empty,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Completed);
+    }
 
-        // Tick halfway through the first tween, then in one tick:
-        // - Finish the first tween
-        // - Start and finish the second tween
-        // - Start the third tween
-        for delta_ms in [500, 2000] {
+    /// A timeline with a child whose duration is only known once it completes
+    /// doesn't block a sequence.
+    #[test]
+    fn timeline_resolved_duration() {
+        let spring = Spring::new(TransformScaleLens {
+            start: Vec3::ZERO,
+            end: Vec3::ONE,
+        })
+        .with_stiffness(500.);
+        let timeline = Timeline::new()
+            .insert(Duration::ZERO, make_test_tween())
+            .insert(Duration::from_millis(500), spring);
+        assert_eq!(timeline.total_duration(), TotalDuration::Infinite);
+        let mut seq = timeline.then(make_test_tween());
+
+        let (mut world, entity) = make_test_env();
+        let mut frames = 0;
+        while seq.index() == 0 {
+            frames += 1;
+            assert!(frames < 200);
             manual_tick_component(
-                Entity::PLACEHOLDER, // unused in this test
-                Duration::from_millis(delta_ms),
+                Entity::PLACEHOLDER,
+                Duration::from_millis(16),
                 &mut seq,
                 &mut world,
                 entity,
             );
         }
-        assert_eq!(seq.index(), 2);
-        let transform = world.entity(entity).get::<Transform>().unwrap();
-        assert!(transform.translation.abs_diff_eq(Vec3::splat(2.5), 1e-5));
+        assert_eq!(
+            seq.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(16 * frames + 1000))
+        );
+        let state = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_secs(1),
+            &mut seq,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Completed);
     }
 
-    /// Sequence::new() and various Sequence-specific methods
     #[test]
-    fn seq_iter() {
-        let mut seq = Sequence::new((1..5).map(|i| {
-            Tween::new(
-                EaseMethod::default(),
-                Duration::from_millis(200 * i),
-                TransformPositionLens {
-                    start: Vec3::ZERO,
-                    end: Vec3::ONE,
-                },
-            )
-        }));
+    #[should_panic(expected = "Invalid timeline position: no child at index 1")]
+    fn timeline_invalid_position() {
+        let _ = Timeline::new().insert(
+            TimelinePosition::AfterEndOf(1, Duration::ZERO),
+            Delay::new(Duration::from_secs(1)),
+        );
+    }
 
-        let mut time = Duration::ZERO;
-        for i in 1..5 {
-            assert_eq!(seq.index(), i - 1);
-            assert_eq!(time, seq.elapsed());
-            let dt = Duration::from_millis(200 * i as u64);
-            assert_eq!(dt, seq.current().cycle_duration());
-            time += dt;
-            seq.set_elapsed(time);
-            assert_eq!(seq.cycles_completed(), u32::from(i == 4));
+    /// Repeat a sequence a finite number of times.
+    #[test]
+    fn repeat_finite() {
+        let seq = make_test_tween().then(Delay::new(Duration::from_secs(1)));
+        let mut repeat = Repeat::new(seq, 2).with_cycle_completed_event(true);
+        assert_eq!(repeat.cycle_duration(), Duration::from_secs(2));
+        assert_eq!(
+            repeat.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(4))
+        );
+
+        let (mut world, entity) = make_test_env();
+        let event_count = |world: &World| {
+            let events = world.resource::<Messages<CycleCompletedEvent>>();
+            events.get_cursor().len(events)
+        };
+        let position = |world: &World| world.get::<Transform>(entity).unwrap().translation;
+
+        // (dt, state, position, events, cycles_completed)
+        for (dt, state, pos, events, cycles) in [
+            (500, TweenState::Active, 0.5, 0, 0),
+            (1000, TweenState::Active, 1., 0, 0),
+            // Loop around, consuming the extra time in the next cycle
+            (700, TweenState::Active, 0.2, 1, 1),
+            (300, TweenState::Active, 0.5, 0, 1),
+            (5000, TweenState::Completed, 1., 1, 2),
+            (1000, TweenState::Completed, 1., 0, 2),
+        ] {
+            let ret = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                &mut repeat,
+                &mut world,
+                entity,
+            );
+            assert_eq!(ret, state);
+            assert_approx_eq!(position(&world), Vec3::splat(pos));
+            assert_eq!(event_count(&world), events);
+            assert_eq!(repeat.cycles_completed(), cycles);
+        }
+
+        // Seek and rewind
+        repeat.set_elapsed(Duration::from_millis(2500));
+        assert_eq!(repeat.elapsed(), Duration::from_millis(2500));
+        assert_eq!(repeat.tweenable().elapsed(), Duration::from_millis(500));
+        repeat.rewind();
+        assert_eq!(repeat.elapsed(), Duration::ZERO);
+        assert_eq!(repeat.tweenable().elapsed(), Duration::ZERO);
+    }
+
+    /// Repeat a sequence with mirroring.
+    #[test]
+    fn repeat_mirrored() {
+        let seq = make_test_tween().then(Delay::new(Duration::from_secs(1)));
+        let mut repeat = Repeat::new(seq, RepeatCount::Infinite)
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+            .with_cycle_completed_event(true);
+        assert_eq!(repeat.total_duration(), TotalDuration::Infinite);
+
+        let (mut world, entity) = make_test_env();
+        let position = |world: &World| world.get::<Transform>(entity).unwrap().translation;
+
+        // (dt, position, cycles_completed)
+        for (dt, pos, cycles) in [
+            (300, 0.3, 0),
+            (1200, 1., 0),
+            // Mirrored cycle, first in the delay, then back in the tween
+            (800, 1., 1),
+            (1000, 0.7, 1),
+            (300, 0.4, 1),
+            // Forward again; infinite mirrored repeat loops after 2 cycles
+            (600, 0.2, 0),
+            (300, 0.5, 0),
+        ] {
+            let ret = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                &mut repeat,
+                &mut world,
+                entity,
+            );
+            assert_eq!(ret, TweenState::Active);
+            assert_approx_eq!(position(&world), Vec3::splat(pos));
+            assert_eq!(repeat.cycles_completed(), cycles);
         }
-
-        seq.rewind();
-        assert_eq!(Duration::ZERO, seq.elapsed());
-        assert_eq!(0, seq.cycles_completed());
     }
 
-    /// Sequence::from_single()
+    /// Repeat a delay for a given duration.
     #[test]
-    fn seq_from_single() {
-        let dt = Duration::from_secs(1);
-        let tween = Tween::new(
-            EaseMethod::default(),
-            dt,
-            TransformPositionLens {
-                start: Vec3::ZERO,
-                end: Vec3::ONE,
-            },
+    fn repeat_for() {
+        let delay = Delay::new(Duration::from_secs(1));
+        let mut repeat = Repeat::new(delay, Duration::from_millis(2500));
+        assert_eq!(repeat.target_type_id(), None);
+        assert_eq!(
+            repeat.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(2500))
         );
-        let seq = Sequence::from_single(tween);
 
-        assert_eq!(1, seq.tweens.len());
-        assert_eq!(dt, seq.cycle_duration());
-        assert_eq!(TotalDuration::Finite(dt), seq.total_duration());
+        let (mut world, entity) = make_test_env();
+        let ret = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_millis(2000),
+            &mut repeat,
+            &mut world,
+            entity,
+        );
+        assert_eq!(ret, TweenState::Active);
+        assert_eq!(repeat.cycles_completed(), 2);
+        let ret = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_millis(1000),
+            &mut repeat,
+            &mut world,
+            entity,
+        );
+        assert_eq!(ret, TweenState::Completed);
+        assert_eq!(repeat.elapsed(), Duration::from_millis(2500));
+        assert_eq!(repeat.tweenable().elapsed(), Duration::from_millis(500));
     }
 
+    /// Play a repeated sequence backward.
     #[test]
-    fn seq_elapsed() {
-        let mut seq = Sequence::new((1..5).map(|i| {
-            Tween::new(
-                EaseMethod::default(),
-                Duration::from_millis(200 * i),
-                TransformPositionLens {
-                    start: Vec3::ZERO,
-                    end: Vec3::ONE,
-                },
-            )
-        }));
+    fn repeat_backward() {
+        let seq = Delay::new(Duration::from_secs(1)).then(make_test_tween());
+        let mut repeat = Repeat::new(seq, 2)
+            .with_cycle_completed_event(true)
+            .with_playback_direction(PlaybackDirection::Backward);
+        repeat.rewind();
+        assert_eq!(repeat.elapsed(), Duration::from_secs(4));
+        assert_eq!(repeat.tweenable().elapsed(), Duration::from_secs(2));
 
-        let mut elapsed = Duration::ZERO;
-        for i in 1..5 {
-            assert_eq!(seq.index(), i - 1);
-            assert_eq!(seq.elapsed(), elapsed);
-            let duration = Duration::from_millis(200 * i as u64);
-            assert_eq!(seq.current().cycle_duration(), duration);
-            elapsed += duration;
-            seq.set_elapsed(elapsed);
-            assert_eq!(seq.cycles_completed(), u32::from(i == 4));
+        let (mut world, entity) = make_test_env();
+        let event_count = |world: &World| {
+            let events = world.resource::<Messages<CycleCompletedEvent>>();
+            events.get_cursor().len(events)
+        };
+        let position = |world: &World| world.get::<Transform>(entity).unwrap().translation;
+
+        // (dt, state, position, events)
+        for (dt, state, pos, events) in [
+            (500, TweenState::Active, 0.5, 0),
+            (1000, TweenState::Active, 0., 0),
+            // Loop around to the end of the previous cycle
+            (1000, TweenState::Active, 0.5, 1),
+            (2000, TweenState::Completed, 0., 1),
+            (1000, TweenState::Completed, 0., 0),
+        ] {
+            let ret = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                &mut repeat,
+                &mut world,
+                entity,
+            );
+            assert_eq!(ret, state);
+            assert_approx_eq!(position(&world), Vec3::splat(pos));
+            assert_eq!(event_count(&world), events);
         }
+        assert_eq!(repeat.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn repeat_infinite_child() {
+        let tween = make_test_tween().with_repeat_count(RepeatCount::Infinite);
+        let seq = Sequence::from_single(tween);
+        assert_eq!(seq.cycle_duration(), Duration::MAX);
+        let repeat = Repeat::new(seq, 2);
+        assert_eq!(repeat.total_duration(), TotalDuration::Infinite);
+        assert_eq!(repeat.cycle_duration(), Duration::MAX);
+
+        let repeat = Repeat::new(WaitUntil::new(|_| false), RepeatCount::Infinite);
+        assert_eq!(repeat.total_duration(), TotalDuration::Infinite);
+    }
+
+    #[test]
+    fn repeat_wait_until() {
+        #[derive(Resource)]
+        struct Ready;
+
+        let seq = WaitUntil::resource_matches(|_: &Ready| true).then(make_test_tween());
+        let mut repeat = Repeat::new(seq, 2);
+        assert_eq!(repeat.total_duration(), TotalDuration::Infinite);
+
+        let (mut world, entity) = make_test_env();
+        let tick = |world: &mut World, repeat: &mut Repeat<Sequence>, dt: u64| {
+            repeat.poll_conditions(world);
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                repeat,
+                world,
+                entity,
+            );
+            let translation = world.entity(entity).get::<Transform>().unwrap().translation;
+            (state, translation)
+        };
+
+        // Wait for the condition, then play the tween
+        assert_eq!(
+            tick(&mut world, &mut repeat, 500),
+            (TweenState::Active, Vec3::ZERO)
+        );
+        world.insert_resource(Ready);
+        let (state, translation) = tick(&mut world, &mut repeat, 300);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::splat(0.3), 1e-5);
+        assert_eq!(repeat.elapsed(), Duration::from_millis(800));
+
+        // Complete the first cycle. The second one waits again, until the condition is
+        // polled on the next step.
+        let (state, translation) = tick(&mut world, &mut repeat, 900);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::ONE, 1e-5);
+        assert_eq!(repeat.cycles_completed(), 1);
+        assert_eq!(repeat.elapsed(), Duration::from_millis(1700));
+        assert_eq!(repeat.total_duration(), TotalDuration::Infinite);
+
+        let (state, translation) = tick(&mut world, &mut repeat, 100);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::splat(0.1), 1e-5);
+
+        // Complete the second and last cycle
+        let (state, translation) = tick(&mut world, &mut repeat, 1000);
+        assert_eq!(state, TweenState::Completed);
+        assert_approx_eq!(translation, Vec3::ONE, 1e-5);
+        assert_eq!(repeat.cycles_completed(), 2);
+        assert_eq!(repeat.elapsed(), Duration::from_millis(2700));
+        assert_eq!(
+            repeat.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(2700))
+        );
+
+        // Rewinding waits for the condition again
+        repeat.rewind();
+        assert_eq!(repeat.elapsed(), Duration::ZERO);
+        assert_eq!(repeat.cycles_completed(), 0);
+        assert_eq!(repeat.total_duration(), TotalDuration::Infinite);
     }
 
     /// Delay::then()
@@ -2345,6 +6949,590 @@ mod tests {
         }
     }
 
+    #[test]
+    fn time_warp() {
+        let (mut world, entity) = make_test_env();
+        let mut tick = |tweenable: &mut dyn Tweenable, dt: u64| {
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                tweenable,
+                &mut world,
+                entity,
+            );
+            let translation = world.entity(entity).get::<Transform>().unwrap().translation;
+            (state, translation)
+        };
+
+        // Ease the clock of the inner tweenable
+        let mut warp = TimeWarp::new(
+            make_test_tween().with_marker("impact", Duration::from_millis(250)),
+            EaseFunction::QuadraticIn,
+        );
+        assert_eq!(
+            warp.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(1))
+        );
+        let (state, translation) = tick(&mut warp, 500);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::splat(0.25), 1e-5);
+        assert_eq!(warp.elapsed(), Duration::from_millis(500));
+        assert_eq!(warp.local_elapsed(), Duration::from_millis(250));
+        assert_eq!(warp.tweenable().elapsed(), Duration::from_millis(250));
+        let impact = warp.marker_time("impact").unwrap();
+        assert!(impact.abs_diff(Duration::from_millis(500)) < Duration::from_micros(1));
+
+        // Seeking keeps both times consistent
+        warp.set_elapsed(Duration::from_millis(800));
+        assert_eq!(warp.elapsed(), Duration::from_millis(800));
+        assert_eq!(warp.tweenable().elapsed(), warp.local_elapsed());
+        assert!(
+            warp.local_elapsed().abs_diff(Duration::from_millis(640)) < Duration::from_micros(1)
+        );
+        let (state, translation) = tick(&mut warp, 300);
+        assert_eq!(state, TweenState::Completed);
+        assert_approx_eq!(translation, Vec3::ONE, 1e-5);
+
+        // Play a sequence backward through the warp
+        let mut warp = TimeWarp::new(
+            make_test_tween().then(make_test_tween()),
+            EaseFunction::QuadraticIn,
+        )
+        .with_playback_direction(PlaybackDirection::Backward);
+        warp.rewind();
+        assert_eq!(warp.local_elapsed(), Duration::from_secs(2));
+        let (state, _) = tick(&mut warp, 1000);
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(warp.tweenable().elapsed(), Duration::from_millis(500));
+        let (state, translation) = tick(&mut warp, 1000);
+        assert_eq!(state, TweenState::Completed);
+        assert_approx_eq!(translation, Vec3::ZERO, 1e-5);
+
+        // A decreasing warp plays the inner tweenable backward
+        let mut warp = TimeWarp::new(
+            make_test_tween(),
+            EaseMethod::CustomFunction(|t| 1. - (2. * t - 1.).abs()),
+        );
+        let (state, translation) = tick(&mut warp, 250);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::splat(0.5), 1e-5);
+        let (state, translation) = tick(&mut warp, 250);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::ONE, 1e-5);
+        let (state, translation) = tick(&mut warp, 250);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::splat(0.5), 1e-5);
+        assert_eq!(
+            warp.tweenable().playback_direction(),
+            PlaybackDirection::Backward
+        );
+        let (state, translation) = tick(&mut warp, 250);
+        assert_eq!(state, TweenState::Completed);
+        assert_approx_eq!(translation, Vec3::ZERO, 1e-5);
+    }
+
+    #[test]
+    fn tween_from_current() {
+        let (mut world, entity) = make_test_env();
+        world
+            .entity_mut(entity)
+            .get_mut::<Transform>()
+            .unwrap()
+            .translation = Vec3::X * 2.;
+        let mut tween = Tween::from_current(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        let mut tick = |tween: &mut Tween, dt: u64| {
+            manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                tween,
+                &mut world,
+                entity,
+            );
+            world.entity(entity).get::<Transform>().unwrap().translation
+        };
+
+        // The start value is captured from the target, ignoring the lens one
+        assert_approx_eq!(tick(&mut tween, 0), Vec3::X * 2., 1e-5);
+        assert_approx_eq!(tick(&mut tween, 500), Vec3::new(1.5, 0.5, 0.5), 1e-5);
+        let lens = tween.lens::<TransformPositionLens>().unwrap();
+        assert_approx_eq!(lens.start, Vec3::X * 2., 1e-5);
+
+        // Rewinding doesn't capture again
+        tween.rewind();
+        assert_approx_eq!(tick(&mut tween, 0), Vec3::X * 2., 1e-5);
+    }
+
+    #[test]
+    fn tween_retarget() {
+        let (mut world, entity) = make_test_env();
+        let mut tick = |tween: &mut Tween, dt: u64| {
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                tween,
+                &mut world,
+                entity,
+            );
+            let translation = world.entity(entity).get::<Transform>().unwrap().translation;
+            (state, translation)
+        };
+        let mut tween = Tween::new(
+            EaseFunction::QuadraticInOut,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+
+        // Wrong lens type
+        assert!(matches!(
+            tween.retarget::<Transform, TransformScaleLens>(Vec3::ZERO),
+            Err(TweeningError::MismatchingLens)
+        ));
+
+        let (_, p0) = tick(&mut tween, 400);
+        let (_, p1) = tick(&mut tween, 1);
+        let v0 = (p1 - p0) / 0.001;
+
+        // The value and velocity are continuous across a retarget
+        let end = Vec3::new(-1., 2., 0.);
+        tween
+            .retarget::<Transform, TransformPositionLens>(end)
+            .unwrap();
+        assert!(tween.lens::<TransformPositionLens>().is_none());
+        assert_eq!(tween.elapsed(), Duration::ZERO);
+        let (_, p) = tick(&mut tween, 0);
+        assert_approx_eq!(p, p1, 1e-5);
+        let (_, p2) = tick(&mut tween, 1);
+        let v1 = (p2 - p1) / 0.001;
+        assert_approx_eq!(v1, v0, 0.05);
+
+        // Same when retargeting again
+        tick(&mut tween, 10);
+        let (_, p2) = tick(&mut tween, 0);
+        let (_, p3) = tick(&mut tween, 1);
+        let v2 = (p3 - p2) / 0.001;
+        tween
+            .retarget::<Transform, TransformPositionLens>(Vec3::Z)
+            .unwrap();
+        let (_, p) = tick(&mut tween, 0);
+        assert_approx_eq!(p, p3, 1e-5);
+        let (_, p4) = tick(&mut tween, 1);
+        let v3 = (p4 - p3) / 0.001;
+        assert_approx_eq!(v3, v2, 0.05);
+
+        // The new segment lasts one cycle and comes to rest at the new end value
+        let (state, p) = tick(&mut tween, 989);
+        assert_eq!(state, TweenState::Active);
+        let (state, p5) = tick(&mut tween, 10);
+        assert_eq!(state, TweenState::Completed);
+        assert_approx_eq!(p5, Vec3::Z, 1e-5);
+        assert!((p5 - p).length() < 1e-3);
+
+        // Retargeting a completed tween starts at rest from the end value
+        tween
+            .retarget::<Transform, TransformPositionLens>(Vec3::ZERO)
+            .unwrap();
+        let (_, p) = tick(&mut tween, 0);
+        assert_approx_eq!(p, Vec3::Z, 1e-5);
+        let (_, p6) = tick(&mut tween, 10);
+        assert!((p6 - p).length() < 1e-3);
+        let (state, p) = tick(&mut tween, 990);
+        assert_eq!(state, TweenState::Completed);
+        assert_approx_eq!(p, Vec3::ZERO, 1e-5);
+    }
+
+    #[test]
+    fn tween_retarget_from_current() {
+        let (mut world, entity) = make_test_env();
+        world
+            .entity_mut(entity)
+            .get_mut::<Transform>()
+            .unwrap()
+            .translation = Vec3::X * 2.;
+        let mut tick = |tween: &mut Tween, dt: u64| {
+            manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                tween,
+                &mut world,
+                entity,
+            );
+            world.entity(entity).get::<Transform>().unwrap().translation
+        };
+        let mut tween = Tween::from_current(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+
+        // Retargeting before the start value is captured still starts from the
+        // current value of the target, at rest
+        tween
+            .retarget::<Transform, TransformPositionLens>(Vec3::Y)
+            .unwrap();
+        assert_approx_eq!(tick(&mut tween, 0), Vec3::X * 2., 1e-5);
+        let p = tick(&mut tween, 1);
+        assert!((p - Vec3::X * 2.).length() < 1e-4);
+        assert_approx_eq!(tick(&mut tween, 499), Vec3::new(1., 0.5, 0.), 1e-5);
+
+        // Same when retargeting twice before the capture
+        let mut tween = Tween::from_current(
+            EaseFunction::Linear,
+            Duration::from_secs(1),
+            TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            },
+        );
+        tween
+            .retarget::<Transform, TransformPositionLens>(Vec3::Y)
+            .unwrap();
+        tween
+            .retarget::<Transform, TransformPositionLens>(Vec3::Z)
+            .unwrap();
+        assert_approx_eq!(tick(&mut tween, 0), Vec3::new(1., 0.5, 0.), 1e-5);
+        assert_approx_eq!(tick(&mut tween, 500), Vec3::new(0.5, 0.25, 0.5), 1e-5);
+        assert_approx_eq!(tick(&mut tween, 500), Vec3::Z, 1e-5);
+    }
+
+    #[test]
+    fn spring() {
+        let (mut world, entity) = make_test_env();
+        let mut tick = |tweenable: &mut dyn Tweenable, dt: u64| {
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                tweenable,
+                &mut world,
+                entity,
+            );
+            let translation = world.entity(entity).get::<Transform>().unwrap().translation;
+            (state, translation)
+        };
+        let make_spring = || {
+            Spring::new(TransformPositionLens {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+            })
+        };
+
+        // Settle at the target
+        let mut spring = make_spring();
+        assert_eq!(spring.total_duration(), TotalDuration::Infinite);
+        let mut frames = 0;
+        let (state, translation) = loop {
+            frames += 1;
+            assert!(frames < 200);
+            let (state, translation) = tick(&mut spring, 16);
+            if state == TweenState::Completed {
+                break (state, translation);
+            }
+        };
+        assert_eq!(state, TweenState::Completed);
+        assert_eq!(translation, Vec3::ONE);
+        assert!(spring.is_settled());
+        assert_eq!(spring.velocity(), 0.);
+        assert_eq!(
+            spring.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(16 * frames))
+        );
+
+        // The motion doesn't depend on the frame rate, and seeking simulates it
+        let mut spring1 = make_spring();
+        let mut spring2 = make_spring();
+        for _ in 0..10 {
+            tick(&mut spring1, 10);
+        }
+        tick(&mut spring2, 100);
+        assert_approx_eq!(spring1.position(), spring2.position(), 1e-4);
+        assert_approx_eq!(spring1.velocity(), spring2.velocity(), 1e-3);
+        let mut spring3 = make_spring();
+        spring3.set_elapsed(Duration::from_millis(100));
+        assert_approx_eq!(spring3.position(), spring2.position(), 1e-4);
+
+        // Retargeting keeps the current position and velocity
+        let (position, velocity) = (spring2.position(), spring2.velocity());
+        assert!(velocity > 0.);
+        spring2.set_target(0.);
+        assert_eq!(spring2.position(), position);
+        assert_eq!(spring2.velocity(), velocity);
+        let (_, translation) = tick(&mut spring2, 1);
+        assert!(translation.x > position);
+        while tick(&mut spring2, 16).0 == TweenState::Active {}
+        assert_eq!(spring2.position(), 0.);
+
+        // An under-damped spring overshoots, an over-damped one doesn't
+        for (damping, overshoots) in [(5., true), (100., false)] {
+            let mut spring = make_spring().with_damping(damping);
+            let mut max = 0_f32;
+            for _ in 0..100 {
+                tick(&mut spring, 16);
+                max = max.max(spring.position());
+            }
+            assert_eq!(max > 1. + 1e-3, overshoots);
+        }
+
+        // Once settled, a sequence continues with its next child
+        let mut seq = make_spring().with_stiffness(500.).then(make_test_tween());
+        let mut frames = 0;
+        while seq.index() == 0 {
+            frames += 1;
+            assert!(frames < 200);
+            assert_eq!(tick(&mut seq, 16).0, TweenState::Active);
+        }
+        let (state, _) = tick(&mut seq, 1000);
+        assert_eq!(state, TweenState::Completed);
+    }
+
+    #[test]
+    fn decay() {
+        let (mut world, entity) = make_test_env();
+        let mut tick = |tweenable: &mut dyn Tweenable, dt: u64| {
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                tweenable,
+                &mut world,
+                entity,
+            );
+            let translation = world.entity(entity).get::<Transform>().unwrap().translation;
+            (state, translation)
+        };
+        let make_decay = |velocity: f32| {
+            Decay::new(
+                TransformPositionLens {
+                    start: Vec3::ZERO,
+                    end: Vec3::ONE,
+                },
+                velocity,
+            )
+            .with_friction(2.)
+        };
+
+        // Without bounds, stop at velocity / friction
+        let mut decay = make_decay(2.);
+        assert_eq!(decay.total_duration(), TotalDuration::Infinite);
+        let mut frames = 0;
+        let translation = loop {
+            frames += 1;
+            assert!(frames < 1000);
+            let (state, translation) = tick(&mut decay, 16);
+            if state == TweenState::Completed {
+                break translation;
+            }
+        };
+        assert!(decay.is_stopped());
+        assert_eq!(decay.velocity(), 0.);
+        assert_approx_eq!(translation, Vec3::ONE, 1e-3);
+        assert_eq!(
+            decay.total_duration(),
+            TotalDuration::Finite(Duration::from_millis(16 * frames))
+        );
+
+        // The motion doesn't depend on the frame rate, and seeking simulates it
+        let mut decay1 = make_decay(2.);
+        let mut decay2 = make_decay(2.);
+        for _ in 0..10 {
+            tick(&mut decay1, 10);
+        }
+        tick(&mut decay2, 100);
+        assert_approx_eq!(decay1.position(), decay2.position(), 1e-5);
+        assert_approx_eq!(decay1.velocity(), decay2.velocity(), 1e-5);
+        let mut decay3 = make_decay(2.);
+        decay3.set_elapsed(Duration::from_millis(100));
+        assert_approx_eq!(decay3.position(), decay2.position(), 1e-5);
+
+        // Overshoot the bound, and get pulled back to it
+        for (velocity, bound) in [(4., 1.), (-4., -0.5)] {
+            let mut decay = make_decay(velocity).with_bounds(-0.5, 1.);
+            let mut max_overshoot = 0_f32;
+            while tick(&mut decay, 16).0 == TweenState::Active {
+                max_overshoot = max_overshoot.max((decay.position() - bound) * velocity.signum());
+            }
+            assert!(max_overshoot > 0.01);
+            assert_eq!(decay.position(), bound);
+            assert_eq!(decay.velocity(), 0.);
+        }
+
+        // Crossing a bound doesn't depend on the frame rate either
+        let mut decay1 = make_decay(2.).with_bounds(0., 0.5);
+        let mut decay2 = make_decay(2.).with_bounds(0., 0.5);
+        for _ in 0..40 {
+            tick(&mut decay1, 10);
+        }
+        for _ in 0..4 {
+            tick(&mut decay2, 100);
+        }
+        assert!(decay1.position() > 0.5);
+        assert_approx_eq!(decay1.position(), decay2.position(), 1e-4);
+        assert_approx_eq!(decay1.velocity(), decay2.velocity(), 1e-3);
+        let mut decay3 = make_decay(2.).with_bounds(0., 0.5);
+        tick(&mut decay3, 400);
+        assert_approx_eq!(decay3.position(), decay1.position(), 1e-4);
+        assert_approx_eq!(decay3.velocity(), decay1.velocity(), 1e-3);
+    }
+
+    #[test]
+    fn delay_backward() {
+        let mut delay =
+            Delay::new(Duration::from_secs(1)).with_playback_direction(PlaybackDirection::Backward);
+        assert!(delay.is_completed());
+        delay.rewind();
+        assert_eq!(delay.elapsed(), Duration::from_secs(1));
+        assert!(!delay.is_completed());
+
+        let (mut world, entity) = make_test_env();
+        for (dt, state, elapsed) in [
+            (400, TweenState::Active, 600),
+            (700, TweenState::Completed, 0),
+        ] {
+            let ret = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                &mut delay,
+                &mut world,
+                entity,
+            );
+            assert_eq!(ret, state);
+            assert_eq!(delay.elapsed(), Duration::from_millis(elapsed));
+        }
+
+        delay.set_playback_direction(PlaybackDirection::Forward);
+        assert!(!delay.is_completed());
+    }
+
+    #[test]
+    fn wait_until_seq() {
+        #[derive(Resource)]
+        struct Ready(bool);
+
+        let mut seq = WaitUntil::resource_matches(|ready: &Ready| ready.0).then(make_test_tween());
+        assert_eq!(seq.total_duration(), TotalDuration::Infinite);
+
+        let (mut world, entity) = make_test_env();
+        let tick = |world: &mut World, seq: &mut Sequence, dt: u64| {
+            seq.poll_conditions(world);
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(dt),
+                seq,
+                world,
+                entity,
+            );
+            let translation = world.entity(entity).get::<Transform>().unwrap().translation;
+            (state, translation)
+        };
+
+        // Wait while the condition is not met
+        assert_eq!(
+            tick(&mut world, &mut seq, 500),
+            (TweenState::Active, Vec3::ZERO)
+        );
+        world.insert_resource(Ready(false));
+        assert_eq!(
+            tick(&mut world, &mut seq, 500),
+            (TweenState::Active, Vec3::ZERO)
+        );
+        assert_eq!(seq.elapsed(), Duration::from_secs(1));
+
+        // Resolve the wait, and use the entire step for the next tween
+        world.insert_resource(Ready(true));
+        let (state, translation) = tick(&mut world, &mut seq, 300);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::splat(0.3), 1e-5);
+        assert_eq!(seq.elapsed(), Duration::from_millis(1300));
+        assert_eq!(
+            seq.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(2))
+        );
+        let (state, translation) = tick(&mut world, &mut seq, 800);
+        assert_eq!(state, TweenState::Completed);
+        assert_approx_eq!(translation, Vec3::ONE, 1e-5);
+
+        // Rewinding waits again on the condition, which is immediately met
+        seq.rewind();
+        assert_eq!(seq.total_duration(), TotalDuration::Infinite);
+        let (state, translation) = tick(&mut world, &mut seq, 200);
+        assert_eq!(state, TweenState::Active);
+        assert_approx_eq!(translation, Vec3::splat(0.2), 1e-5);
+        assert_eq!(
+            seq.total_duration(),
+            TotalDuration::Finite(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn wait_until_conditions() {
+        #[derive(Component)]
+        struct Spawned;
+
+        #[derive(Message)]
+        struct Go;
+
+        let (mut world, entity) = make_test_env();
+        world.init_resource::<Messages<Go>>();
+        let mut waits = [
+            WaitUntil::component_added::<Spawned>(entity),
+            WaitUntil::message_received::<Go>(),
+        ];
+        for wait in &mut waits {
+            wait.poll_conditions(&world);
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(100),
+                wait,
+                &mut world,
+                entity,
+            );
+            assert_eq!(state, TweenState::Active);
+            assert!(!wait.is_resolved());
+        }
+
+        world.entity_mut(entity).insert(Spawned);
+        world.resource_mut::<Messages<Go>>().write(Go);
+        for wait in &mut waits {
+            wait.poll_conditions(&world);
+            let state = manual_tick_component(
+                Entity::PLACEHOLDER,
+                Duration::from_millis(100),
+                wait,
+                &mut world,
+                entity,
+            );
+            assert_eq!(state, TweenState::Completed);
+            assert!(wait.is_resolved());
+            assert_eq!(
+                wait.total_duration(),
+                TotalDuration::Finite(Duration::from_millis(100))
+            );
+        }
+
+        // Once resolved, the wait plays backward like a delay
+        let wait = &mut waits[1];
+        wait.set_playback_direction(PlaybackDirection::Backward);
+        let state = manual_tick_component(
+            Entity::PLACEHOLDER,
+            Duration::from_millis(60),
+            wait,
+            &mut world,
+            entity,
+        );
+        assert_eq!(state, TweenState::Active);
+        assert_eq!(wait.elapsed(), Duration::from_millis(40));
+    }
+
     #[test]
     #[should_panic]
     fn delay_zero_duration_panics() {